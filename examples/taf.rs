@@ -7,7 +7,7 @@ use std::io::Read;
 use weather_reports::taf::parse::taf;
 
 fn main() {
-    let filename = std::env::args().skip(1).next().expect("specify a filename");
+    let filename = std::env::args().nth(1).expect("specify a filename");
 
     let report: String = match filename.as_ref() {
         "-" => {
@@ -16,7 +16,7 @@ fn main() {
             std::io::stdin().read_to_string(&mut acc).unwrap();
             acc
         }
-        filename => std::fs::read_to_string(&filename).expect("file isn't readable"),
+        filename => std::fs::read_to_string(filename).expect("file isn't readable"),
     };
 
     if cfg!(feature = "trace") {
@@ -0,0 +1,87 @@
+#!/usr/bin/env python3
+"""Generates src/stations/table.rs from the mwgg/Airports dataset.
+
+The dataset (https://github.com/mwgg/Airports, MIT licensed) is a JSON object keyed by ICAO
+identifier, with the name, country, coordinates, elevation in feet and IANA time zone of each airport.
+
+Usage:
+    scripts/generate_stations.py [airports.json] > src/stations/table.rs
+
+Without an argument, the latest airports.json is downloaded from GitHub.
+"""
+
+import json
+import re
+import sys
+import urllib.request
+
+SOURCE_URL = "https://raw.githubusercontent.com/mwgg/Airports/master/airports.json"
+ICAO = re.compile(r"^[A-Z]{4}$")
+FEET_TO_METERS = 0.3048
+
+
+def load(path):
+    if path is None:
+        with urllib.request.urlopen(SOURCE_URL) as response:
+            return json.load(response)
+    with open(path, encoding="utf-8") as file:
+        return json.load(file)
+
+
+def tz_variant(name):
+    """Mirrors the naming of chrono-tz variants, i.e. America/Port-au-Prince is America__PortauPrince"""
+    name = name.replace("/", "__").replace("+", "Plus")
+    position = name.find("-")
+    if position != -1:
+        if name[position + 1 : position + 2].isdigit():
+            return name.replace("-", "Minus")
+        return name.replace("-", "")
+    return name
+
+
+def rust_string(value):
+    return json.dumps(value, ensure_ascii=False)
+
+
+def main():
+    airports = load(sys.argv[1] if len(sys.argv) > 1 else None)
+    # Entries keyed by a local code can repeat the ICAO identifier of another entry.
+    # Identifiers must be unique for station(), so the entry keyed by its ICAO identifier wins.
+    rows = {}
+    for key, airport in airports.items():
+        icao = (airport.get("icao") or key).strip().upper()
+        country = (airport.get("country") or "").strip().upper()
+        tz = (airport.get("tz") or "").strip()
+        if not ICAO.match(icao) or len(country) != 2 or not tz:
+            continue
+        try:
+            latitude = float(airport["lat"])
+            longitude = float(airport["lon"])
+            elevation = float(airport.get("elevation") or 0) * FEET_TO_METERS
+        except (KeyError, TypeError, ValueError):
+            continue
+        if icao in rows and key.strip().upper() != icao:
+            continue
+        name = " ".join((airport.get("name") or icao).split())
+        rows[icao] = (
+            f"    Station::new({rust_string(icao)}, {rust_string(name)}, {rust_string(country)}, "
+            f"{latitude:.4f}, {longitude:.4f}, {elevation:.1f}, Tz::{tz_variant(tz)}),"
+        )
+
+    print("//! Stations known to [super::station], sorted by ICAO identifier")
+    print("//!")
+    print("//! Generated by `scripts/generate_stations.py` from the mwgg/Airports dataset, do not edit by hand.")
+    print()
+    print("use chrono_tz::Tz;")
+    print()
+    print("use super::Station;")
+    print()
+    print("#[rustfmt::skip]")
+    print("pub(super) const STATIONS: &[Station] = &[")
+    for icao in sorted(rows):
+        print(rows[icao])
+    print("];")
+
+
+if __name__ == "__main__":
+    main()
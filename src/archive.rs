@@ -0,0 +1,390 @@
+use std::io::{self, BufRead};
+use std::ops::Range;
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+
+use crate::tokens::{MetarReport, ZuluDateTime};
+
+/// Layout of an archive file
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ArchiveFormat {
+    /// [Ogimet](https://www.ogimet.com) dumps: each report is prefixed with `YYYYMMDDHHMM ` and terminated by `=`,
+    /// possibly spanning several lines
+    Ogimet,
+    /// NOAA `cycles/NNZ.TXT` files: a `YYYY/MM/DD HH:MM` line followed by the report and a blank line
+    NoaaCycle,
+    /// One report per line without timestamps
+    OnePerLine,
+}
+
+impl ArchiveFormat {
+    /// Guesses the format from the first non-empty line of a file
+    pub fn detect(first_line: &str) -> Self {
+        let first_line = first_line.trim();
+        if ogimet_timestamp(first_line).is_some() {
+            Self::Ogimet
+        } else if noaa_timestamp(first_line).is_some() {
+            Self::NoaaCycle
+        } else {
+            Self::OnePerLine
+        }
+    }
+}
+
+/// A single report read from an archive
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ArchivedReport {
+    /// When the archive says the report was issued, if it says so
+    pub timestamp: Option<DateTime<Utc>>,
+    /// Report text without the archive timestamp or the `=` terminator
+    pub text: String,
+    /// Byte range of [ArchivedReport::text] in the archive
+    ///
+    /// Reports spanning several lines keep their line breaks, so this maps directly back onto the file.
+    pub span: Range<usize>,
+    /// Zero-based range of the lines containing the report
+    pub lines: Range<usize>,
+}
+
+impl ArchivedReport {
+    /// Resolves the full date of a day/time group in this report against the archive timestamp
+    pub fn resolve(&self, time: &ZuluDateTime) -> Option<DateTime<Utc>> {
+        time.resolve(self.timestamp?)
+    }
+
+    /// Resolves the full observation time of a METAR parsed from [ArchivedReport::text]
+    pub fn observation_time(&self, report: &MetarReport) -> Option<DateTime<Utc>> {
+        self.resolve(report.observation_time.as_ref()?)
+    }
+}
+
+/// Reads reports one at a time from an archive, so arbitrarily large files can be processed
+///
+/// ```
+/// use weather_reports::archive::{ArchiveFormat, ArchiveReader};
+///
+/// let archive = "202008010053 METAR KTPA 010053Z 35005KT 10SM SCT055 29/21 A2999=\n";
+/// for report in ArchiveReader::new(archive.as_bytes(), ArchiveFormat::Ogimet) {
+///     let report = report.unwrap();
+///     let metar = weather_reports::metar::parse::metar(&report.text).unwrap();
+///     assert_eq!(
+///         report.observation_time(&metar).unwrap().to_rfc3339(),
+///         "2020-08-01T00:53:00+00:00"
+///     );
+/// }
+/// ```
+pub struct ArchiveReader<R> {
+    reader: R,
+    format: ArchiveFormat,
+    /// Byte offset of the next line
+    offset: usize,
+    /// Index of the next line
+    line: usize,
+    /// A line that was read but belongs to the next report
+    pending: Option<Line>,
+    /// Timestamp waiting for its report in a NOAA cycle file
+    noaa_timestamp: Option<DateTime<Utc>>,
+}
+
+impl<R: BufRead> ArchiveReader<R> {
+    pub fn new(reader: R, format: ArchiveFormat) -> Self {
+        Self {
+            reader,
+            format,
+            offset: 0,
+            line: 0,
+            pending: None,
+            noaa_timestamp: None,
+        }
+    }
+
+    fn next_line(&mut self) -> io::Result<Option<Line>> {
+        if let Some(pending) = self.pending.take() {
+            return Ok(Some(pending));
+        }
+        let mut buf = String::new();
+        let read = self.reader.read_line(&mut buf)?;
+        if read == 0 {
+            return Ok(None);
+        }
+        let (offset, index) = (self.offset, self.line);
+        self.offset += read;
+        self.line += 1;
+        let trimmed_len = buf.trim_end_matches(&['\r', '\n'][..]).len();
+        let line_break = buf.split_off(trimmed_len);
+        Ok(Some(Line {
+            offset,
+            index,
+            text: buf,
+            line_break,
+        }))
+    }
+
+    fn next_ogimet(&mut self) -> io::Result<Option<ArchivedReport>> {
+        let mut current: Option<ArchivedReport> = None;
+        // Line break ending the previous line of the current report
+        let mut line_break = String::new();
+        while let Some(next) = self.next_line()? {
+            let (offset, line, text) = (next.offset, next.index, &next.text);
+            let started = match (current.is_some(), ogimet_timestamp(text)) {
+                (true, Some(_)) => {
+                    // Unterminated report, the next one starts here
+                    self.pending = Some(next);
+                    break;
+                }
+                (false, Some(timestamp)) => {
+                    current = Some(ArchivedReport {
+                        timestamp: Some(timestamp),
+                        text: String::new(),
+                        span: offset + OGIMET_PREFIX_LEN..offset + OGIMET_PREFIX_LEN,
+                        lines: line..line,
+                    });
+                    OGIMET_PREFIX_LEN
+                }
+                (true, None) => 0,
+                // Text outside of a report, i.e. a header
+                (false, None) => continue,
+            };
+            let report = current.as_mut().unwrap();
+            if started == 0 {
+                report.text += &line_break;
+            }
+            let body = &text[started..];
+            let terminator = body.find('=');
+            report.text += &body[..terminator.unwrap_or(body.len())];
+            report.span.end = offset + started + terminator.unwrap_or(body.len());
+            report.lines.end = line + 1;
+            if terminator.is_some() {
+                break;
+            }
+            line_break = next.line_break;
+        }
+        Ok(current.map(trim_report))
+    }
+
+    fn next_noaa_cycle(&mut self) -> io::Result<Option<ArchivedReport>> {
+        let mut current: Option<ArchivedReport> = None;
+        let mut line_break = String::new();
+        while let Some(next) = self.next_line()? {
+            let Line {
+                offset,
+                index: line,
+                text,
+                ..
+            } = &next;
+            let (offset, line) = (*offset, *line);
+            if let Some(timestamp) = noaa_timestamp(text.trim()) {
+                if current.is_some() {
+                    self.pending = Some(next);
+                    break;
+                }
+                self.noaa_timestamp = Some(timestamp);
+                continue;
+            }
+            if text.trim().is_empty() {
+                if current.is_some() {
+                    break;
+                }
+                continue;
+            }
+            match &mut current {
+                Some(report) => {
+                    report.text += &line_break;
+                    report.text += text;
+                    report.span.end = offset + text.len();
+                    report.lines.end = line + 1;
+                }
+                None => {
+                    current = Some(ArchivedReport {
+                        timestamp: self.noaa_timestamp.take(),
+                        span: offset..offset + text.len(),
+                        lines: line..line + 1,
+                        text: text.clone(),
+                    })
+                }
+            }
+            line_break = next.line_break;
+        }
+        Ok(current.map(trim_report))
+    }
+
+    fn next_one_per_line(&mut self) -> io::Result<Option<ArchivedReport>> {
+        while let Some(Line {
+            offset,
+            index: line,
+            text,
+            ..
+        }) = self.next_line()?
+        {
+            let text = match text.find('=') {
+                Some(terminator) => text[..terminator].to_string(),
+                None => text,
+            };
+            if text.trim().is_empty() {
+                continue;
+            }
+            return Ok(Some(trim_report(ArchivedReport {
+                timestamp: None,
+                span: offset..offset + text.len(),
+                lines: line..line + 1,
+                text,
+            })));
+        }
+        Ok(None)
+    }
+}
+
+impl<R: BufRead> Iterator for ArchiveReader<R> {
+    type Item = io::Result<ArchivedReport>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.format {
+            ArchiveFormat::Ogimet => self.next_ogimet(),
+            ArchiveFormat::NoaaCycle => self.next_noaa_cycle(),
+            ArchiveFormat::OnePerLine => self.next_one_per_line(),
+        }
+        .transpose()
+    }
+}
+
+/// A line of an archive, split from its line break
+struct Line {
+    /// Byte offset in the archive
+    offset: usize,
+    /// Zero-based line number
+    index: usize,
+    text: String,
+    /// `\n`, `\r\n`, or empty at the end of the archive
+    line_break: String,
+}
+
+/// Length of `YYYYMMDDHHMM `
+const OGIMET_PREFIX_LEN: usize = 13;
+
+fn ogimet_timestamp(line: &str) -> Option<DateTime<Utc>> {
+    let prefix = line.get(..OGIMET_PREFIX_LEN)?;
+    if !prefix[..12].bytes().all(|b| b.is_ascii_digit()) || !prefix.ends_with(' ') {
+        return None;
+    }
+    let field = |range: Range<usize>| prefix[range].parse::<u32>().ok();
+    timestamp(
+        field(0..4)? as i32,
+        field(4..6)?,
+        field(6..8)?,
+        field(8..10)?,
+        field(10..12)?,
+    )
+}
+
+fn noaa_timestamp(line: &str) -> Option<DateTime<Utc>> {
+    let bytes = line.as_bytes();
+    if bytes.len() != 16
+        || bytes[4] != b'/'
+        || bytes[7] != b'/'
+        || bytes[10] != b' '
+        || bytes[13] != b':'
+    {
+        return None;
+    }
+    let field = |range: Range<usize>| line[range].parse::<u32>().ok();
+    timestamp(
+        field(0..4)? as i32,
+        field(5..7)?,
+        field(8..10)?,
+        field(11..13)?,
+        field(14..16)?,
+    )
+}
+
+fn timestamp(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> Option<DateTime<Utc>> {
+    Some(Utc.from_utc_datetime(
+        &NaiveDate::from_ymd_opt(year, month, day)?.and_hms_opt(hour, minute, 0)?,
+    ))
+}
+
+/// Drops surrounding whitespace from the text, shrinking the span to match
+fn trim_report(mut report: ArchivedReport) -> ArchivedReport {
+    let leading = report.text.len() - report.text.trim_start().len();
+    let trailing = report.text.len() - report.text.trim_end().len();
+    report.span = report.span.start + leading..report.span.end - trailing;
+    report.text = report.text.trim().to_string();
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(archive: &str, format: ArchiveFormat) -> Vec<ArchivedReport> {
+        ArchiveReader::new(archive.as_bytes(), format)
+            .collect::<io::Result<Vec<_>>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn read_ogimet() {
+        let archive = "# header\n202008010053 METAR KTPA 010053Z 35005KT 10SM\n    SCT055 29/21 A2999 $=\n202008010153 METAR KTPA 010153Z 01006KT 10SM FEW055 29/22 A3000=\n";
+        let reports = read(archive, ArchiveFormat::detect("202008010053 METAR"));
+        assert_eq!(reports.len(), 2);
+        assert_eq!(
+            reports[0].text,
+            "METAR KTPA 010053Z 35005KT 10SM\n    SCT055 29/21 A2999 $"
+        );
+        assert_eq!(&archive[reports[0].span.clone()], reports[0].text);
+        assert_eq!(reports[0].lines, 1..3);
+        assert_eq!(&archive[reports[1].span.clone()], reports[1].text);
+        assert_eq!(reports[1].lines, 3..4);
+        assert_eq!(
+            reports[1].timestamp,
+            Some(Utc.with_ymd_and_hms(2020, 8, 1, 1, 53, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn read_multiline_reports_with_crlf() {
+        let archive = "202008010053 METAR KTPA 010053Z 35005KT 10SM\r\n    SCT055 29/21 A2999=\r\n";
+        let reports = read(archive, ArchiveFormat::Ogimet);
+        assert_eq!(
+            reports[0].text,
+            "METAR KTPA 010053Z 35005KT 10SM\r\n    SCT055 29/21 A2999"
+        );
+        assert_eq!(&archive[reports[0].span.clone()], reports[0].text);
+
+        let archive =
+            "2021/08/31 23:53\r\nKSEA 312353Z 01008KT 10SM\r\n     SCT200 19/09 A3004\r\n\r\n";
+        let reports = read(archive, ArchiveFormat::NoaaCycle);
+        assert_eq!(reports[0].lines, 1..3);
+        assert_eq!(&archive[reports[0].span.clone()], reports[0].text);
+        crate::metar::parse::metar(&reports[0].text).unwrap();
+    }
+
+    #[test]
+    fn read_noaa_cycle() {
+        let archive = "2021/08/31 23:53\r\nKSEA 312353Z 01008KT 10SM SCT200 19/09 A3004\r\n\r\n2021/09/01 00:53\r\nKSEA 010053Z 01008KT 10SM SCT200 18/09 A3005\r\n\r\n";
+        let reports = read(archive, ArchiveFormat::detect("2021/08/31 23:53"));
+        assert_eq!(reports.len(), 2);
+        for report in &reports {
+            assert_eq!(&archive[report.span.clone()], report.text);
+        }
+        let metar = crate::metar::parse::metar(&reports[1].text).unwrap();
+        assert_eq!(
+            reports[1].observation_time(&metar),
+            Some(Utc.with_ymd_and_hms(2021, 9, 1, 0, 53, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn read_one_per_line() {
+        let archive = "KSEA 290353Z 01008KT 10SM SCT200 19/09 A3004=\n\nKSEA 290453Z 01008KT 10SM SCT200 19/09 A3004\n";
+        let reports = read(archive, ArchiveFormat::detect("KSEA 290353Z"));
+        assert_eq!(
+            reports
+                .iter()
+                .map(|report| (report.lines.start, report.timestamp))
+                .collect::<Vec<_>>(),
+            vec![(0, None), (2, None)]
+        );
+        assert_eq!(&archive[reports[0].span.clone()], reports[0].text);
+        assert!(!reports[0].text.ends_with('='));
+    }
+}
@@ -0,0 +1,648 @@
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+
+use clap::{Parser, Subcommand, ValueEnum};
+use codespan_reporting::term::{
+    emit,
+    termcolor::{ColorChoice, StandardStream},
+};
+use uom::si::{
+    angle::degree, length::foot, length::mile, pressure::hectopascal,
+    thermodynamic_temperature::degree_celsius, velocity::knot,
+};
+
+use weather_reports::archive::{ArchiveFormat, ArchiveReader};
+use weather_reports::error::{into_diagnostic, ParseError};
+use weather_reports::flight_category::{FlightConditions, LimitingFactor};
+use weather_reports::metar::parse::metar_lenient;
+use weather_reports::tokens::*;
+
+/// Decode, convert and validate METAR and TAF reports
+#[derive(Parser)]
+#[command(name = "weather-reports", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Decode reports from ogimet or NOAA cycle archives, or one per line
+    Decode {
+        #[arg(long, value_enum, default_value = "text")]
+        format: Format,
+        #[arg(long, value_enum, default_value = "auto")]
+        kind: Kind,
+        /// Skip malformed METAR groups instead of rejecting the whole report
+        #[arg(long)]
+        lenient: bool,
+        /// Files to read, or `-` for stdin
+        #[arg(default_value = "-")]
+        files: Vec<String>,
+    },
+    /// Check that every report parses, printing diagnostics for failures and a summary
+    Validate {
+        #[arg(long, value_enum, default_value = "auto")]
+        kind: Kind,
+        /// Only print the summary
+        #[arg(long)]
+        quiet: bool,
+        /// Files to read, or `-` for stdin
+        #[arg(default_value = "-")]
+        files: Vec<String>,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum Format {
+    /// Human-readable summary
+    Text,
+    /// One JSON object per line
+    Json,
+    /// One row per observation or forecast period
+    Csv,
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum Kind {
+    /// TAF if the line starts with `TAF`, METAR otherwise
+    Auto,
+    Metar,
+    Taf,
+}
+
+enum Report<'input> {
+    Metar {
+        report: Box<MetarReport<'input>>,
+        skipped: Vec<ParseError>,
+    },
+    Taf(Box<TafReport<'input>>),
+}
+
+/// A report line along with where it came from, for diagnostics
+struct Line {
+    name: String,
+    text: String,
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Decode {
+            format,
+            kind,
+            lenient,
+            files,
+        } => read_lines(&files).map(|lines| decode(&lines, format, kind, lenient)),
+        Command::Validate { kind, quiet, files } => {
+            read_lines(&files).map(|lines| validate(&lines, kind, quiet))
+        }
+    };
+    std::process::exit(match result {
+        Ok(true) => 0,
+        Ok(false) => 1,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            2
+        }
+    })
+}
+
+fn read_lines(files: &[String]) -> std::io::Result<Vec<Line>> {
+    let mut lines = vec![];
+    for file in files {
+        let (name, contents) = if file == "-" {
+            let mut acc = String::default();
+            std::io::stdin().read_to_string(&mut acc)?;
+            ("<stdin>", acc)
+        } else {
+            (file.as_str(), std::fs::read_to_string(file)?)
+        };
+        let format = ArchiveFormat::detect(
+            contents
+                .lines()
+                .find(|line| !line.trim().is_empty())
+                .unwrap_or_default(),
+        );
+        for report in ArchiveReader::new(contents.as_bytes(), format) {
+            let report = report?;
+            lines.push(Line {
+                name: format!("{}:{}", name, report.lines.start + 1),
+                text: report.text,
+            });
+        }
+    }
+    Ok(lines)
+}
+
+fn parse(text: &str, kind: Kind, lenient: bool) -> Result<Report<'_>, ParseError> {
+    let is_taf = match kind {
+        Kind::Auto => text.trim_start().starts_with("TAF"),
+        Kind::Metar => false,
+        Kind::Taf => true,
+    };
+    if is_taf {
+        weather_reports::taf::parse::taf(text).map(|report| Report::Taf(Box::new(report)))
+    } else if lenient {
+        metar_lenient(text).map(|partial| Report::Metar {
+            report: Box::new(partial.report),
+            skipped: partial.skipped,
+        })
+    } else {
+        weather_reports::metar::parse::metar(text).map(|report| Report::Metar {
+            report: Box::new(report),
+            skipped: vec![],
+        })
+    }
+}
+
+fn emit_diagnostic(line: &Line, err: &ParseError) {
+    let mut writer = StandardStream::stderr(ColorChoice::Auto);
+    let config = codespan_reporting::term::Config::default();
+    emit(
+        &mut writer,
+        &config,
+        &codespan_reporting::files::SimpleFile::new(&line.name, &line.text),
+        &into_diagnostic(err),
+    )
+    .unwrap();
+}
+
+fn decode(lines: &[Line], format: Format, kind: Kind, lenient: bool) -> bool {
+    let stdout = std::io::stdout();
+    let mut out = stdout.lock();
+    if format == Format::Csv {
+        writeln!(out, "{}", CSV_HEADER.join(",")).unwrap();
+    }
+
+    let mut all_parsed = true;
+    for line in lines {
+        let report = match parse(&line.text, kind, lenient) {
+            Ok(report) => report,
+            Err(err) => {
+                emit_diagnostic(line, &err);
+                all_parsed = false;
+                continue;
+            }
+        };
+        if let Report::Metar { skipped, .. } = &report {
+            skipped.iter().for_each(|err| emit_diagnostic(line, err));
+        }
+        let written = match format {
+            Format::Text => writeln!(out, "{}", describe(&report)),
+            Format::Json => writeln!(out, "{}", to_json(&report)),
+            Format::Csv => csv_rows(&report)
+                .iter()
+                .try_for_each(|row| writeln!(out, "{}", row)),
+        };
+        // Stop quietly if the reader went away, i.e. piping into `head`
+        if written.is_err() {
+            break;
+        }
+    }
+    all_parsed
+}
+
+fn validate(lines: &[Line], kind: Kind, quiet: bool) -> bool {
+    let mut failures: BTreeMap<&'static str, (&'static str, usize)> = BTreeMap::new();
+    let mut failed = 0;
+    for line in lines {
+        if let Err(err) = parse(&line.text, kind, false) {
+            if !quiet {
+                emit_diagnostic(line, &err);
+            }
+            failures
+                .entry(err.code())
+                .or_insert((err.kind.description(), 0))
+                .1 += 1;
+            failed += 1;
+        }
+    }
+
+    let total = lines.len();
+    println!(
+        "checked {} reports: {} parsed, {} failed ({:.2}% coverage)",
+        total,
+        total - failed,
+        failed,
+        if total == 0 {
+            100.
+        } else {
+            100. - failed as f64 / total as f64 * 100.
+        }
+    );
+    for (code, (description, count)) in failures {
+        println!("  {} {:<24} {}", code, description, count);
+    }
+    failed == 0
+}
+
+fn flight_category(conditions: Option<FlightConditions>) -> String {
+    match conditions {
+        Some(conditions) => format!(
+            "{} ({})",
+            <&str>::from(conditions.category),
+            match conditions.limited_by {
+                LimitingFactor::Ceiling => "ceiling",
+                LimitingFactor::Visibility => "visibility",
+                LimitingFactor::CeilingAndVisibility => "ceiling and visibility",
+                LimitingFactor::Unrestricted => "unrestricted",
+                LimitingFactor::Cavok => "CAVOK",
+            }
+        ),
+        None => "unknown".to_string(),
+    }
+}
+
+fn describe_wind(wind: &Wind) -> String {
+    if wind.is_calm() == Some(true) {
+        return "calm".to_string();
+    }
+    let mut acc = match wind.direction {
+        Some(direction) => format!("{:03.0}°", direction.get::<degree>()),
+        None => "variable".to_string(),
+    };
+    if let Some(speed) = wind.speed {
+        acc += &format!(" at {:.0} kt", speed.get::<knot>());
+    }
+    if let Some(gust) = wind.peak_gust {
+        acc += &format!(" gusting {:.0} kt", gust.get::<knot>());
+    }
+    if let Some((from, to)) = wind.variance {
+        acc += &format!(
+            ", varying {:03.0}° to {:03.0}°",
+            from.get::<degree>(),
+            to.get::<degree>()
+        );
+    }
+    acc
+}
+
+fn describe_visibility(visibility: &Visibility) -> String {
+    match visibility.prevailing {
+        Some(prevailing) => format!(
+            "{}{:.2} SM",
+            match prevailing.out_of_range {
+                Some(OutOfRange::Above) => "more than ",
+                Some(OutOfRange::Below) => "less than ",
+                None => "",
+            },
+            prevailing.distance.get::<mile>()
+        ),
+        None => "not reported".to_string(),
+    }
+}
+
+fn describe_clouds(cloud_cover: &[CloudCover]) -> String {
+    cloud_cover
+        .iter()
+        .map(|cloud| {
+            let mut acc = <&str>::from(cloud.coverage).to_string();
+            if let Some(base) = cloud.base {
+                acc += &format!(" {:.0} ft", base.get::<foot>());
+            }
+            if let Some(cloud_type) = cloud.cloud_type {
+                acc += &format!(" {}", <&str>::from(cloud_type));
+            }
+            acc
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn describe_conditions(
+    acc: &mut Vec<(&'static str, String)>,
+    wind: Option<&Wind>,
+    visibility: Option<&Visibility>,
+    weather: &[Weather],
+    cloud_cover: &[CloudCover],
+    cavok: bool,
+) {
+    if let Some(wind) = wind {
+        acc.push(("Wind", describe_wind(wind)));
+    }
+    if cavok {
+        acc.push(("Visibility", "CAVOK".to_string()));
+    } else if let Some(visibility) = visibility {
+        acc.push(("Visibility", describe_visibility(visibility)));
+    }
+    if !weather.is_empty() {
+        acc.push((
+            "Weather",
+            weather
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(" "),
+        ));
+    }
+    if !cloud_cover.is_empty() {
+        acc.push(("Clouds", describe_clouds(cloud_cover)));
+    }
+}
+
+fn describe(report: &Report) -> String {
+    let mut title;
+    let mut fields = vec![];
+    match report {
+        Report::Metar { report, skipped } => {
+            title = format!("METAR {}", report.identifier);
+            if let Some(time) = report.observation_time {
+                title += &format!(
+                    " observed day {} {:02}:{:02}Z",
+                    time.day_of_month, time.time.hour, time.time.minute
+                );
+            }
+            describe_conditions(
+                &mut fields,
+                report.wind.as_ref(),
+                report.visibility.as_ref(),
+                &report.weather,
+                &report.cloud_cover,
+                report.cavok,
+            );
+            if let Some(ceiling) = report.ceiling() {
+                fields.push(("Ceiling", format!("{:.0} ft", ceiling.get::<foot>())));
+            }
+            if let Some(temperatures) = report.temperatures {
+                let mut acc = format!("{:.0}°C", temperatures.air.get::<degree_celsius>());
+                if let Some(dewpoint) = temperatures.dewpoint {
+                    acc += &format!(", dewpoint {:.0}°C", dewpoint.get::<degree_celsius>());
+                }
+                fields.push(("Temperature", acc));
+            }
+            if let Some(pressure) = report.pressure {
+                fields.push((
+                    "Pressure",
+                    format!("{:.1} hPa", pressure.get::<hectopascal>()),
+                ));
+            }
+            fields.push(("Flight rules", flight_category(report.flight_category())));
+            if let Some(remark) = &report.remark {
+                fields.push(("Remarks", remark.to_string()));
+            }
+            if !skipped.is_empty() {
+                fields.push((
+                    "Skipped",
+                    skipped
+                        .iter()
+                        .map(|err| err.token.as_str())
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                ));
+            }
+        }
+        Report::Taf(report) => {
+            title = format!("TAF {}", report.identifier);
+            if let Some(validity) = report.validity {
+                title += &format!(" valid {}", validity);
+            }
+            describe_conditions(
+                &mut fields,
+                report.forecast.wind.as_ref(),
+                report.forecast.visibility.as_ref(),
+                &report.forecast.weather,
+                &report.forecast.cloud_cover,
+                report.forecast.cavok,
+            );
+            fields.push((
+                "Flight rules",
+                flight_category(report.forecast.flight_category()),
+            ));
+            for change in &report.changes {
+                let mut change_fields = vec![];
+                describe_conditions(
+                    &mut change_fields,
+                    change.forecast.wind.as_ref(),
+                    change.forecast.visibility.as_ref(),
+                    &change.forecast.weather,
+                    &change.forecast.cloud_cover,
+                    change.forecast.cavok,
+                );
+                fields.push((
+                    "Change",
+                    format!(
+                        "{}: {}",
+                        change.indicator,
+                        change_fields
+                            .iter()
+                            .map(|(name, value)| format!("{} {}", name.to_lowercase(), value))
+                            .collect::<Vec<_>>()
+                            .join("; ")
+                    ),
+                ));
+            }
+            if let Some(remark) = &report.remark {
+                fields.push(("Remarks", remark.to_string()));
+            }
+        }
+    }
+    let mut acc = title;
+    for (name, value) in fields {
+        acc += &format!("\n  {:<14}{}", name, value);
+    }
+    acc
+}
+
+fn to_json(report: &Report) -> serde_json::Value {
+    match report {
+        Report::Metar { report, skipped } => {
+            let mut value = serde_json::json!({ "type": "METAR", "report": report });
+            if !skipped.is_empty() {
+                value["skipped"] = skipped
+                    .iter()
+                    .map(|err| {
+                        serde_json::json!({
+                            "token": err.token,
+                            "start": err.span.start,
+                            "end": err.span.end,
+                            "code": err.code(),
+                            "reason": err.to_string(),
+                        })
+                    })
+                    .collect();
+            }
+            value
+        }
+        Report::Taf(report) => serde_json::json!({ "type": "TAF", "report": report }),
+    }
+}
+
+const CSV_HEADER: [&str; 13] = [
+    "type",
+    "station",
+    "time",
+    "period",
+    "wind_direction_deg",
+    "wind_speed_kt",
+    "wind_gust_kt",
+    "visibility_sm",
+    "ceiling_ft",
+    "temperature_c",
+    "dewpoint_c",
+    "pressure_hpa",
+    "flight_category",
+];
+
+fn csv_field(value: impl ToString) -> String {
+    let value = value.to_string();
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+fn optional<T>(value: Option<T>, f: impl FnOnce(T) -> f64) -> String {
+    value
+        .map(|value| format!("{:.0}", f(value)))
+        .unwrap_or_default()
+}
+
+fn conditions_columns(
+    wind: Option<&Wind>,
+    visibility: Option<&Visibility>,
+    ceiling: Option<uom::si::f64::Length>,
+) -> Vec<String> {
+    vec![
+        optional(wind.and_then(|wind| wind.direction), |direction| {
+            direction.get::<degree>()
+        }),
+        optional(wind.and_then(|wind| wind.speed), |speed| {
+            speed.get::<knot>()
+        }),
+        optional(wind.and_then(|wind| wind.peak_gust), |gust| {
+            gust.get::<knot>()
+        }),
+        visibility
+            .and_then(|visibility| visibility.prevailing)
+            .map(|prevailing| format!("{:.2}", prevailing.distance.get::<mile>()))
+            .unwrap_or_default(),
+        optional(ceiling, |ceiling| ceiling.get::<foot>()),
+    ]
+}
+
+fn csv_rows(report: &Report) -> Vec<String> {
+    let mut rows: Vec<Vec<String>> = vec![];
+    match report {
+        Report::Metar { report, .. } => {
+            let mut row = vec![
+                "METAR".to_string(),
+                csv_field(&report.identifier),
+                report
+                    .observation_time
+                    .map(|time| time.to_string())
+                    .unwrap_or_default(),
+                String::new(),
+            ];
+            row.extend(conditions_columns(
+                report.wind.as_ref(),
+                report.visibility.as_ref(),
+                report.ceiling(),
+            ));
+            row.push(optional(report.temperatures, |temperatures| {
+                temperatures.air.get::<degree_celsius>()
+            }));
+            row.push(optional(
+                report
+                    .temperatures
+                    .and_then(|temperatures| temperatures.dewpoint),
+                |dewpoint| dewpoint.get::<degree_celsius>(),
+            ));
+            row.push(
+                report
+                    .pressure
+                    .map(|pressure| format!("{:.1}", pressure.get::<hectopascal>()))
+                    .unwrap_or_default(),
+            );
+            row.push(
+                report
+                    .flight_category()
+                    .map(|conditions| <&str>::from(conditions.category).to_string())
+                    .unwrap_or_default(),
+            );
+            rows.push(row);
+        }
+        Report::Taf(report) => {
+            let issue_time = report
+                .issue_time
+                .map(|time| time.to_string())
+                .unwrap_or_default();
+            let periods = std::iter::once((
+                report
+                    .validity
+                    .map(|validity| validity.to_string())
+                    .unwrap_or_default(),
+                &report.forecast,
+            ))
+            .chain(
+                report
+                    .changes
+                    .iter()
+                    .map(|change| (change.indicator.to_string(), &change.forecast)),
+            );
+            for (period, forecast) in periods {
+                let mut row = vec![
+                    "TAF".to_string(),
+                    csv_field(&report.identifier),
+                    issue_time.clone(),
+                    csv_field(period),
+                ];
+                row.extend(conditions_columns(
+                    forecast.wind.as_ref(),
+                    forecast.visibility.as_ref(),
+                    forecast.ceiling(),
+                ));
+                row.extend([String::new(), String::new(), String::new()]);
+                row.push(
+                    forecast
+                        .flight_category()
+                        .map(|conditions| <&str>::from(conditions.category).to_string())
+                        .unwrap_or_default(),
+                );
+                rows.push(row);
+            }
+        }
+    }
+    rows.iter().map(|row| row.join(",")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_rows_for_each_period() {
+        let report = parse(
+            "TAF KSEA 251720Z 2518/2624 20008KT P6SM BKN250 TEMPO 2522/2602 3SM -SHRA BKN015",
+            Kind::Auto,
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            csv_rows(&report),
+            vec![
+                "TAF,KSEA,251720Z,2518/2624,200,8,,6.00,25000,,,,VFR",
+                "TAF,KSEA,251720Z,TEMPO 2522/2602,,,,3.00,1500,,,,MVFR",
+            ]
+        );
+        assert_eq!(csv_field("a,\"b\""), "\"a,\"\"b\"\"\"");
+    }
+
+    #[test]
+    fn describe_lenient_metar() {
+        let report = parse(
+            "KSEA 290353Z 01008KT 10SM BKN0X0 19/09 A3004",
+            Kind::Auto,
+            true,
+        )
+        .unwrap();
+        let description = describe(&report);
+        assert!(description.starts_with("METAR KSEA observed day 29 03:53Z"));
+        assert!(
+            description.contains("Skipped       BKN0X0"),
+            "{}",
+            description
+        );
+    }
+}
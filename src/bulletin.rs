@@ -0,0 +1,306 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
+use crate::error::{ParseError, ParseErrorKind};
+use crate::tokens::*;
+
+/// Start of heading, sent before a bulletin on the GTS
+const START_OF_HEADING: char = '\u{1}';
+/// End of text, sent after a bulletin on the GTS
+const END_OF_TEXT: char = '\u{3}';
+/// Some switches terminate messages with this line
+const END_OF_MESSAGE: &str = "NNNN";
+
+/// `BBB` group of a [AbbreviatedHeading]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BulletinIndicator {
+    /// `RRx`: delayed, where `x` is the sequence letter
+    Delayed(char),
+    /// `CCx`: corrects a previous bulletin
+    Correction(char),
+    /// `AAx`: amends a previous bulletin
+    Amendment(char),
+    /// `Pxx`: one segment of a bulletin split into parts
+    Segment(char, char),
+}
+
+/// [WMO abbreviated heading](https://community.wmo.int/en/activity-areas/wis/wis-manual) of a bulletin, i.e. `SAUS70 KWBC 251400 RRA`
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AbbreviatedHeading<'input> {
+    /// `TTAAii`: data type, geographical area, and bulletin number, i.e. `SAUS70`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub data_designator: Cow<'input, str>,
+    /// `CCCC`: ICAO identifier of the originating center
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub originator: Cow<'input, str>,
+    /// `YYGGgg`: nominal issue time
+    pub time: ZuluDateTime,
+    pub indicator: Option<BulletinIndicator>,
+}
+
+impl<'input> AbbreviatedHeading<'input> {
+    /// Kind of reports in the bulletin according to the `TT` data type designator
+    pub fn report_kind(&self) -> Option<BulletinReportKind> {
+        match self.data_designator.get(..2)? {
+            "SA" => Some(BulletinReportKind::Metar),
+            "SP" => Some(BulletinReportKind::Speci),
+            "FC" | "FT" => Some(BulletinReportKind::Taf),
+            _ => None,
+        }
+    }
+
+    pub fn into_owned(self) -> AbbreviatedHeading<'static> {
+        AbbreviatedHeading {
+            data_designator: Cow::Owned(self.data_designator.into_owned()),
+            originator: Cow::Owned(self.originator.into_owned()),
+            time: self.time,
+            indicator: self.indicator,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BulletinReportKind {
+    Metar,
+    Speci,
+    Taf,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BulletinReport<'input> {
+    /// A METAR or SPECI
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    Metar(Box<MetarReport<'input>>),
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    Taf(Box<TafReport<'input>>),
+}
+
+/// One `=`-terminated report of a bulletin
+#[derive(Clone, PartialEq, Debug)]
+pub struct BulletinEntry<'input> {
+    /// Byte range of the report in the bulletin, excluding the `=` terminator
+    pub span: Range<usize>,
+    /// Error spans are relative to the whole bulletin
+    pub report: Result<BulletinReport<'input>, ParseError>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Bulletin<'input> {
+    pub heading: AbbreviatedHeading<'input>,
+    /// Kind given by the collective header line (i.e. `METAR` or `TAF AMD`), or by the heading if there is none
+    pub report_kind: Option<BulletinReportKind>,
+    /// Set by a `TAF AMD` or `TAF COR` collective header and applied to every TAF in the bulletin
+    pub collective_flag: Option<TafFlag>,
+    /// Reports in order of appearance; a malformed report does not affect the others
+    pub entries: Vec<BulletinEntry<'input>>,
+}
+
+peg::parser! {
+    grammar bulletin_parser() for str {
+        rule letter() -> &'input str = quiet!{$(['A'..='Z'])} / expected!("letter");
+        rule digit() -> &'input str = quiet!{$(['0'..='9'])} / expected!("digit");
+        rule space() = quiet!{" "+} / expected!("whitespace");
+
+        rule time() -> ZuluDateTime = day_of_month:$(digit()*<2>) hour:$(digit()*<2>) minute:$(digit()*<2>) {
+            ZuluDateTime {
+                day_of_month: day_of_month.parse().unwrap(),
+                time: ZuluTime {
+                    hour: hour.parse().unwrap(),
+                    minute: minute.parse().unwrap(),
+                },
+                // Headings are always UTC but are not suffixed with a Z
+                is_zulu: false,
+            }
+        }
+
+        rule indicator() -> BulletinIndicator =
+            "RR" sequence:letter() { BulletinIndicator::Delayed(first_char(sequence)) }
+            / "CC" sequence:letter() { BulletinIndicator::Correction(first_char(sequence)) }
+            / "AA" sequence:letter() { BulletinIndicator::Amendment(first_char(sequence)) }
+            / "P" first:letter() second:letter() { BulletinIndicator::Segment(first_char(first), first_char(second)) }
+            / expected!("RRx, CCx, AAx, or Pxx indicator");
+
+        pub rule heading() -> AbbreviatedHeading<'input> =
+            data_designator:$(letter()*<4> digit()*<2>) space()
+            originator:$(letter() (letter() / digit())*<3>) space()
+            time:time()
+            indicator:(space() indicator:indicator() { indicator })?
+            " "*
+            {
+                AbbreviatedHeading {
+                    data_designator: data_designator.into(),
+                    originator: originator.into(),
+                    time,
+                    indicator,
+                }
+            }
+    }
+}
+
+fn first_char(val: &str) -> char {
+    val.chars().next().unwrap()
+}
+
+/// Lines of `input` with their byte ranges, excluding line breaks
+fn lines(input: &str) -> impl Iterator<Item = (Range<usize>, &str)> {
+    input.split('\n').scan(0, |offset, line| {
+        let start = *offset;
+        *offset += line.len() + 1;
+        let line = line.trim_end_matches('\r');
+        Some((start..start + line.len(), line))
+    })
+}
+
+fn collective_header(line: &str) -> Option<(BulletinReportKind, Option<TafFlag>)> {
+    match line.split_whitespace().collect::<Vec<_>>().as_slice() {
+        ["METAR"] => Some((BulletinReportKind::Metar, None)),
+        ["SPECI"] => Some((BulletinReportKind::Speci, None)),
+        ["TAF"] => Some((BulletinReportKind::Taf, None)),
+        ["TAF", "AMD"] => Some((BulletinReportKind::Taf, Some(TafFlag::Amended))),
+        ["TAF", "COR"] => Some((BulletinReportKind::Taf, Some(TafFlag::Correction))),
+        _ => None,
+    }
+}
+
+fn shift(mut err: ParseError, offset: usize) -> ParseError {
+    err.span = err.span.start + offset..err.span.end + offset;
+    err
+}
+
+/// Parses a WMO bulletin containing a collective of METAR, SPECI, or TAF reports
+///
+/// The bulletin may be wrapped in SOH/ETX control characters and preceded by a channel sequence number.
+/// Only a malformed abbreviated heading fails the whole bulletin; each report is parsed independently.
+pub fn bulletin(input: &str) -> Result<Bulletin<'_>, ParseError> {
+    let start = input
+        .find(START_OF_HEADING)
+        .map(|soh| soh + START_OF_HEADING.len_utf8())
+        .unwrap_or(0);
+    let end = input[start..]
+        .find(END_OF_TEXT)
+        .map(|etx| start + etx)
+        .unwrap_or(input.len());
+
+    let mut lines = lines(&input[start..end])
+        .map(|(span, line)| (span.start + start..span.end + start, line))
+        .filter(|(_, line)| !line.trim().is_empty())
+        .peekable();
+
+    // Channel sequence number
+    if let Some((_, line)) = lines.peek() {
+        if line.trim().bytes().all(|b| b.is_ascii_digit()) {
+            lines.next();
+        }
+    }
+
+    let (heading_span, heading_line) = lines.next().unwrap_or((end..end, ""));
+    let heading = bulletin_parser::heading(heading_line).map_err(|err| {
+        let mut err = shift(ParseError::new(heading_line, err), heading_span.start);
+        err.kind = ParseErrorKind::BulletinHeading;
+        err
+    })?;
+
+    let mut body_start = heading_span.end;
+    let mut report_kind = heading.report_kind();
+    let mut collective_flag = None;
+    if let Some((span, line)) = lines.peek() {
+        if let Some((kind, flag)) = collective_header(line) {
+            report_kind = Some(kind);
+            collective_flag = flag;
+            body_start = span.end;
+        }
+    }
+
+    let mut entries = vec![];
+    let mut offset = body_start;
+    for text in input[body_start..end].split('=') {
+        let span_start = offset + (text.len() - text.trim_start().len());
+        let span = span_start..span_start + text.trim().len();
+        offset += text.len() + 1;
+
+        let text = &input[span.clone()];
+        if text.is_empty() || text == END_OF_MESSAGE {
+            continue;
+        }
+        let report = match report_kind {
+            Some(BulletinReportKind::Taf) => crate::taf::parse::taf(text).map(|mut report| {
+                if let Some(flag) = collective_flag {
+                    if !report.flags.contains(&flag) {
+                        report.flags.insert(0, flag);
+                    }
+                }
+                BulletinReport::Taf(Box::new(report))
+            }),
+            _ => crate::metar::parse::metar(text)
+                .map(|report| BulletinReport::Metar(Box::new(report))),
+        };
+        entries.push(BulletinEntry {
+            report: report.map_err(|err| shift(err, span.start)),
+            span,
+        });
+    }
+
+    Ok(Bulletin {
+        heading,
+        report_kind,
+        collective_flag,
+        entries,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_metar_bulletin() {
+        let input = "\u{1}\r\r\n123\r\r\nSAUS70 KWBC 251400 RRA\r\r\nMETAR\r\r\nKSEA 251353Z 01008KT 10SM SCT200 19/09 A3004=\r\r\nKPDX 251353Z 00000KT 10SM BKN0X0 19/09 A3004=\r\r\nSPECI KBFI 251358Z 01008KT 2SM BR OVC008 19/19\r\r\n     A3004 RMK AO2=\r\r\n\u{3}";
+        let bulletin = bulletin(input).unwrap();
+        assert_eq!(bulletin.heading.data_designator, "SAUS70");
+        assert_eq!(bulletin.heading.originator, "KWBC");
+        assert_eq!(bulletin.heading.time.to_string(), "251400");
+        assert_eq!(
+            bulletin.heading.indicator,
+            Some(BulletinIndicator::Delayed('A'))
+        );
+        assert_eq!(bulletin.report_kind, Some(BulletinReportKind::Metar));
+        assert_eq!(bulletin.entries.len(), 3);
+
+        match &bulletin.entries[0].report {
+            Ok(BulletinReport::Metar(report)) => assert_eq!(report.identifier, "KSEA"),
+            other => panic!("{:?}", other),
+        }
+        let err = bulletin.entries[1].report.as_ref().unwrap_err();
+        assert_eq!(&input[err.span.clone()], "BKN0X0");
+        assert!(bulletin.entries[2].report.is_ok());
+        assert!(input[bulletin.entries[2].span.clone()].ends_with("RMK AO2"));
+    }
+
+    #[test]
+    fn parse_taf_bulletin() {
+        let input = "FTUS80 KWBC 251720 AAA\nTAF AMD\nKSEA 251720Z 2518/2624 20008KT P6SM FEW040 BKN250=\nKPDX 251720Z 2518/2624 NIL=\nNNNN\n";
+        let bulletin = bulletin(input).unwrap();
+        assert_eq!(bulletin.collective_flag, Some(TafFlag::Amended));
+        assert_eq!(bulletin.entries.len(), 2);
+        for entry in &bulletin.entries {
+            match &entry.report {
+                Ok(BulletinReport::Taf(report)) => {
+                    assert_eq!(report.flags[0], TafFlag::Amended)
+                }
+                other => panic!("{:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn reject_malformed_heading() {
+        let input = "SAUS70 KWBC 25X400\nMETAR\nKSEA 251353Z 01008KT 10SM SCT200 19/09 A3004=";
+        let err = bulletin(input).unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::BulletinHeading);
+        assert_eq!(&input[err.span.clone()], "25X400");
+    }
+}
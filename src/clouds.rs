@@ -0,0 +1,183 @@
+use uom::si::f64::Length;
+use uom::si::length::foot;
+
+use crate::tokens::*;
+
+/// Lowest layer matching `is_layer`
+///
+/// Layers without a base are skipped, except for vertical visibility of unknown height (`VV///`),
+/// which is treated as being at ground level since the sky is totally obscured.
+pub(crate) fn lowest_layer(
+    cloud_cover: &[CloudCover],
+    is_layer: impl Fn(CloudCoverage) -> bool,
+) -> Option<&CloudCover> {
+    cloud_cover
+        .iter()
+        .filter(|cloud| is_layer(cloud.coverage))
+        .filter(|cloud| cloud.base.is_some() || cloud.coverage == CloudCoverage::VerticalVisibility)
+        .min_by(|a, b| match (a.base, b.base) {
+            (Some(a), Some(b)) => a.partial_cmp(&b).unwrap(),
+            (a, b) => a.is_some().cmp(&b.is_some()),
+        })
+}
+
+/// Base of a layer picked by [lowest_layer], where vertical visibility of unknown height is at ground level
+fn layer_base(layer: &CloudCover) -> Length {
+    layer.base.unwrap_or_else(|| Length::new::<foot>(0.))
+}
+
+pub(crate) fn is_ceiling(coverage: CloudCoverage) -> bool {
+    matches!(
+        coverage,
+        CloudCoverage::Broken | CloudCoverage::Overcast | CloudCoverage::VerticalVisibility
+    )
+}
+
+pub(crate) fn is_cloud(coverage: CloudCoverage) -> bool {
+    matches!(
+        coverage,
+        CloudCoverage::Few
+            | CloudCoverage::Scattered
+            | CloudCoverage::Broken
+            | CloudCoverage::Overcast
+            | CloudCoverage::VerticalVisibility
+    )
+}
+
+fn is_clear(coverage: CloudCoverage) -> bool {
+    matches!(
+        coverage,
+        CloudCoverage::NoCloud | CloudCoverage::Clear | CloudCoverage::NilCloud
+    )
+}
+
+fn is_convective(cloud_type: CloudType) -> bool {
+    matches!(
+        cloud_type,
+        CloudType::Cumulonimbus | CloudType::ToweringCumulus
+    )
+}
+
+/// Implements the cloud helpers for anything with a `cloud_cover` field
+macro_rules! impl_cloud_helpers {
+    ($ty: ty) => {
+        impl $ty {
+            /// The lowest broken, overcast, or vertical visibility layer
+            ///
+            /// Broken and overcast layers without a base are ignored.
+            pub fn ceiling_layer(&self) -> Option<&CloudCover> {
+                lowest_layer(&self.cloud_cover, is_ceiling)
+            }
+
+            /// Height of the [ceiling layer](Self::ceiling_layer)
+            ///
+            /// [None] when there is no ceiling. An indefinite ceiling of unknown height (`VV///`) is at ground level,
+            /// see [has_indefinite_ceiling](Self::has_indefinite_ceiling) to tell it apart.
+            pub fn ceiling(&self) -> Option<Length> {
+                self.ceiling_layer().map(layer_base)
+            }
+
+            /// Whether the ceiling is a vertical visibility into an obscuration rather than a cloud layer
+            pub fn has_indefinite_ceiling(&self) -> bool {
+                self.ceiling_layer()
+                    .map(|layer| layer.coverage == CloudCoverage::VerticalVisibility)
+                    .unwrap_or(false)
+            }
+
+            /// Base of the lowest layer of any coverage, including vertical visibility
+            ///
+            /// As with [ceiling](Self::ceiling), vertical visibility of unknown height (`VV///`) is at ground level.
+            pub fn lowest_cloud_base(&self) -> Option<Length> {
+                lowest_layer(&self.cloud_cover, is_cloud).map(layer_base)
+            }
+
+            /// Whether no clouds at all were reported (`SKC`, `CLR` or `NCD`)
+            ///
+            /// `CAVOK` and `NSC` only rule out clouds of operational significance, so they are not clear skies.
+            pub fn is_sky_clear(&self) -> bool {
+                !self.cloud_cover.is_empty()
+                    && self
+                        .cloud_cover
+                        .iter()
+                        .all(|cloud| is_clear(cloud.coverage))
+            }
+
+            /// Whether any layer is cumulonimbus (`CB`) or towering cumulus (`TCU`)
+            pub fn has_convective_clouds(&self) -> bool {
+                self.cloud_cover
+                    .iter()
+                    .filter_map(|cloud| cloud.cloud_type)
+                    .any(is_convective)
+            }
+        }
+    };
+}
+
+impl_cloud_helpers!(MetarReport<'_>);
+impl_cloud_helpers!(TafForecast<'_>);
+impl_cloud_helpers!(TrendReport);
+
+#[cfg(test)]
+mod tests {
+    use uom::si::length::foot;
+
+    use crate::metar::parse::metar;
+
+    #[test]
+    fn ceilings() {
+        let report =
+            metar("KSEA 290353Z 01008KT 10SM FEW008 BKN///CB OVC040 BKN025TCU 19/09 A3004")
+                .unwrap();
+        assert_eq!(report.ceiling().unwrap().get::<foot>().round(), 2500.);
+        assert!(!report.has_indefinite_ceiling());
+        assert_eq!(
+            report.lowest_cloud_base().unwrap().get::<foot>().round(),
+            800.
+        );
+        assert!(report.has_convective_clouds());
+        assert!(!report.is_sky_clear());
+
+        let report = metar("KSEA 290353Z 01008KT 1/4SM FG VV002 19/19 A3004").unwrap();
+        assert_eq!(report.ceiling().unwrap().get::<foot>().round(), 200.);
+        assert!(report.has_indefinite_ceiling());
+
+        let report = metar("KSEA 290353Z 01008KT 1/4SM FG VV/// 19/19 A3004").unwrap();
+        assert_eq!(report.ceiling().unwrap().get::<foot>(), 0.);
+        assert_eq!(report.lowest_cloud_base().unwrap().get::<foot>(), 0.);
+        assert!(report.has_indefinite_ceiling());
+
+        let report = metar("KSEA 290353Z 01008KT 1/4SM FG FEW020 VV/// 19/19 A3004").unwrap();
+        assert_eq!(report.lowest_cloud_base().unwrap().get::<foot>(), 0.);
+
+        let report = metar("KSEA 290353Z 01008KT 10SM FEW020 SCT200 19/09 A3004").unwrap();
+        assert_eq!(report.ceiling(), None);
+        assert!(!report.has_convective_clouds());
+    }
+
+    #[test]
+    fn clear_skies() {
+        for (val, clear) in [
+            ("KSEA 290353Z 01008KT 10SM CLR 19/09 A3004", true),
+            ("KSEA 290353Z 01008KT 10SM SKC 19/09 A3004", true),
+            ("EGLL 290350Z 24010KT 9999 NCD 19/09 Q1012", true),
+            ("EGLL 290350Z 24010KT 9999 NSC 19/09 Q1012", false),
+            ("EGLL 290350Z 24010KT CAVOK 19/09 Q1012", false),
+            ("KSEA 290353Z 01008KT 10SM 19/09 A3004", false),
+        ] {
+            assert_eq!(metar(val).unwrap().is_sky_clear(), clear, "{}", val);
+        }
+    }
+
+    #[test]
+    fn trend_ceilings() {
+        let report =
+            metar("EGLL 290350Z 24010KT 9999 SCT030 19/09 Q1012 TEMPO 4000 SHRA BKN012CB").unwrap();
+        let trend = match &report.trends[0] {
+            crate::tokens::Trend::Temporarily(trend) => trend,
+            other => panic!("{:?}", other),
+        };
+        assert_eq!(trend.ceiling().unwrap().get::<foot>().round(), 1200.);
+        assert!(trend.has_convective_clouds());
+        assert_eq!(report.ceiling(), None);
+    }
+}
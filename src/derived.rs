@@ -0,0 +1,243 @@
+//! Quantities derived from reported observations
+//!
+//! Pressure and density altitude depend on the station elevation, which is not part of a report and must be supplied by the caller.
+
+use uom::si::f64::{
+    Length, Pressure, Ratio, TemperatureInterval, ThermodynamicTemperature, Velocity,
+};
+use uom::si::{
+    length::{foot, meter},
+    pressure::{hectopascal, inch_of_mercury},
+    ratio::{percent, ratio},
+    temperature_interval,
+    thermodynamic_temperature::{degree_celsius, degree_fahrenheit},
+    velocity::kilometer_per_hour,
+};
+
+use crate::tokens::*;
+
+impl Temperatures {
+    /// Relative humidity from the air temperature and dewpoint, using the Magnus formula
+    ///
+    /// Returns [None] if the dewpoint was not reported.
+    pub fn relative_humidity(&self) -> Option<Ratio> {
+        Some(relative_humidity(self.air, self.dewpoint?))
+    }
+
+    /// Difference between the air temperature and dewpoint, where a small spread indicates fog is likely
+    pub fn dewpoint_spread(&self) -> Option<TemperatureInterval> {
+        let spread = self.air.get::<degree_celsius>() - self.dewpoint?.get::<degree_celsius>();
+        Some(TemperatureInterval::new::<
+            temperature_interval::degree_celsius,
+        >(spread))
+    }
+}
+
+impl<'input> MetarReport<'input> {
+    /// See [Temperatures::relative_humidity]
+    pub fn relative_humidity(&self) -> Option<Ratio> {
+        self.temperatures?.relative_humidity()
+    }
+
+    /// See [Temperatures::dewpoint_spread]
+    pub fn dewpoint_spread(&self) -> Option<TemperatureInterval> {
+        self.temperatures?.dewpoint_spread()
+    }
+
+    /// See [heat_index]
+    pub fn heat_index(&self) -> Option<ThermodynamicTemperature> {
+        let temperatures = self.temperatures?;
+        Some(heat_index(
+            temperatures.air,
+            temperatures.relative_humidity()?,
+        ))
+    }
+
+    /// See [wind_chill]
+    pub fn wind_chill(&self) -> Option<ThermodynamicTemperature> {
+        wind_chill(self.temperatures?.air, self.wind?.speed?)
+    }
+
+    /// See [pressure_altitude]
+    pub fn pressure_altitude(&self, elevation: Length) -> Option<Length> {
+        Some(pressure_altitude(elevation, self.pressure?))
+    }
+
+    /// See [density_altitude]
+    pub fn density_altitude(&self, elevation: Length) -> Option<Length> {
+        Some(density_altitude(
+            elevation,
+            self.pressure?,
+            self.temperatures?.air,
+        ))
+    }
+}
+
+/// Saturation vapor pressure over water in hectopascals
+fn saturation_vapor_pressure(temperature: ThermodynamicTemperature) -> f64 {
+    let celsius = temperature.get::<degree_celsius>();
+    6.112 * (17.62 * celsius / (243.12 + celsius)).exp()
+}
+
+/// Relative humidity using the Magnus formula with the WMO coefficients
+pub fn relative_humidity(
+    air: ThermodynamicTemperature,
+    dewpoint: ThermodynamicTemperature,
+) -> Ratio {
+    Ratio::new::<ratio>(saturation_vapor_pressure(dewpoint) / saturation_vapor_pressure(air))
+}
+
+/// Apparent temperature due to humidity, using the [NWS algorithm](https://www.wpc.ncep.noaa.gov/html/heatindex_equation.shtml)
+///
+/// The heat index is only meaningful at air temperatures of roughly 27°C (80°F) and above, below which it stays close to the air temperature.
+pub fn heat_index(
+    air: ThermodynamicTemperature,
+    relative_humidity: Ratio,
+) -> ThermodynamicTemperature {
+    let t = air.get::<degree_fahrenheit>();
+    let rh = relative_humidity.get::<percent>();
+    let simple = 0.5 * (t + 61. + (t - 68.) * 1.2 + rh * 0.094);
+    let fahrenheit = if (simple + t) / 2. < 80. {
+        simple
+    } else {
+        let regression = -42.379 + 2.049_015_23 * t + 10.143_331_27 * rh
+            - 0.224_755_41 * t * rh
+            - 0.006_837_83 * t * t
+            - 0.054_817_17 * rh * rh
+            + 0.001_228_74 * t * t * rh
+            + 0.000_852_82 * t * rh * rh
+            - 0.000_001_99 * t * t * rh * rh;
+        if rh < 13. && (80. ..=112.).contains(&t) {
+            regression - (13. - rh) / 4. * ((17. - (t - 95.).abs()) / 17.).sqrt()
+        } else if rh > 85. && (80. ..=87.).contains(&t) {
+            regression + (rh - 85.) / 10. * (87. - t) / 5.
+        } else {
+            regression
+        }
+    };
+    ThermodynamicTemperature::new::<degree_fahrenheit>(fahrenheit)
+}
+
+/// Apparent temperature due to wind, using the [North American wind chill index](https://en.wikipedia.org/wiki/Wind_chill#North_American_and_United_Kingdom_wind_chill_index)
+///
+/// Returns [None] outside of where the index is defined: air temperatures above 10°C or wind speeds below 4.8km/h.
+pub fn wind_chill(
+    air: ThermodynamicTemperature,
+    wind_speed: Velocity,
+) -> Option<ThermodynamicTemperature> {
+    let t = air.get::<degree_celsius>();
+    let v = wind_speed.get::<kilometer_per_hour>();
+    if t > 10. || v < 4.8 {
+        return None;
+    }
+    let v = v.powf(0.16);
+    Some(ThermodynamicTemperature::new::<degree_celsius>(
+        13.12 + 0.6215 * t - 11.37 * v + 0.3965 * t * v,
+    ))
+}
+
+/// Altitude in the standard atmosphere at which the pressure equals the station pressure
+///
+/// The `altimeter` setting is the QNH or `A` group of a report.
+pub fn pressure_altitude(elevation: Length, altimeter: Pressure) -> Length {
+    let pressure_ratio = altimeter.get::<hectopascal>() / 1013.25;
+    elevation + Length::new::<foot>(145_366.45 * (1. - pressure_ratio.powf(0.190_284)))
+}
+
+/// Altitude in the standard atmosphere at which the air density equals the density at the station
+///
+/// Uses the [NWS formulas](https://www.weather.gov/media/epz/wxcalc/densityAltitude.pdf) for dry air,
+/// which underestimate density altitude slightly in humid conditions.
+pub fn density_altitude(
+    elevation: Length,
+    altimeter: Pressure,
+    air: ThermodynamicTemperature,
+) -> Length {
+    let station_pressure = altimeter.get::<inch_of_mercury>()
+        * ((288. - 0.0065 * elevation.get::<meter>()) / 288.).powf(5.2561);
+    let rankine = air.get::<degree_fahrenheit>() + 459.67;
+    Length::new::<foot>(145_442.16 * (1. - (17.326 * station_pressure / rankine).powf(0.235)))
+}
+
+#[cfg(test)]
+mod tests {
+    use uom::si::{
+        f64::{Length, Pressure, Ratio, ThermodynamicTemperature, Velocity},
+        length::foot,
+        pressure::inch_of_mercury,
+        ratio::percent,
+        temperature_interval,
+        thermodynamic_temperature::{degree_celsius, degree_fahrenheit},
+        velocity::kilometer_per_hour,
+    };
+
+    use super::*;
+    use crate::metar::parse::metar;
+
+    fn celsius(value: f64) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(value)
+    }
+
+    #[test]
+    fn humidity_and_spread() {
+        let report = metar("KSEA 290353Z 01008KT 10SM SCT200 20/10 A2992").unwrap();
+        assert_eq!(
+            report.relative_humidity().unwrap().get::<percent>().round(),
+            53.
+        );
+        assert_eq!(
+            report
+                .dewpoint_spread()
+                .unwrap()
+                .get::<temperature_interval::degree_celsius>()
+                .round(),
+            10.
+        );
+        let saturated = Temperatures {
+            air: celsius(4.),
+            dewpoint: Some(celsius(4.)),
+        };
+        assert!((saturated.relative_humidity().unwrap().get::<percent>() - 100.).abs() < 1e-9);
+        assert_eq!(
+            metar("KSEA 290353Z 01008KT 10SM SCT200 20/ A2992")
+                .unwrap()
+                .relative_humidity(),
+            None
+        );
+    }
+
+    #[test]
+    fn apparent_temperatures() {
+        // NWS heat index table: 90°F at 70% humidity feels like 106°F
+        let heat_index = heat_index(
+            ThermodynamicTemperature::new::<degree_fahrenheit>(90.),
+            Ratio::new::<percent>(70.),
+        );
+        assert_eq!(heat_index.get::<degree_fahrenheit>().round(), 106.);
+
+        let wind_chill =
+            wind_chill(celsius(-10.), Velocity::new::<kilometer_per_hour>(20.)).unwrap();
+        assert_eq!(wind_chill.get::<degree_celsius>().round(), -18.);
+        assert_eq!(
+            super::wind_chill(celsius(15.), Velocity::new::<kilometer_per_hour>(20.)),
+            None
+        );
+    }
+
+    #[test]
+    fn pressure_and_density_altitude() {
+        let elevation = Length::new::<foot>(5431.);
+        let standard = pressure_altitude(elevation, Pressure::new::<inch_of_mercury>(29.92));
+        assert!((standard.get::<foot>() - 5431.).abs() < 5.);
+        let high = pressure_altitude(elevation, Pressure::new::<inch_of_mercury>(30.12));
+        assert!((high.get::<foot>() - 5245.).abs() < 10.);
+
+        let report = metar("KDEN 252153Z 18010KT 10SM FEW100 30/02 A2992").unwrap();
+        let density_altitude = report.density_altitude(elevation).unwrap();
+        assert!(
+            (density_altitude.get::<foot>() - 8344.).abs() < 10.,
+            "{:?}",
+            density_altitude.get::<foot>()
+        );
+    }
+}
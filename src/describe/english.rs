@@ -0,0 +1,459 @@
+use super::{LengthUnit, Message, MessageCatalog, PressureUnit, SpeedUnit, TemperatureUnit, Unit};
+use crate::tokens::*;
+
+/// English texts, using the terms of the [FAA Aeronautical Information Manual](https://www.faa.gov/air_traffic/publications/atpubs/aim_html/)
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct English;
+
+impl MessageCatalog for English {
+    fn message(&self, message: Message<'_>) -> String {
+        use Message::*;
+        match message {
+            Station(station) => format!("report from {}", station),
+            ObservationTime { day_of_month, time } => {
+                format!("observed on day {} at {}", day_of_month, time)
+            }
+            ValidityRange { begin, end } => format!("valid from {} to {}", begin, end),
+            ObservationFlag(flag) => observation_flag(flag).into(),
+            MaintenanceNeeded => "station requires maintenance".into(),
+
+            Calm => "calm wind".into(),
+            Wind {
+                direction,
+                speed,
+                gust,
+            } => {
+                let mut wind = match direction {
+                    Some(direction) => format!("wind from {} at {}", direction, speed),
+                    None => format!("variable wind at {}", speed),
+                };
+                if let Some(gust) = gust {
+                    wind.push_str(&format!(" gusting {}", gust));
+                }
+                wind
+            }
+            WindNotReported => "wind not reported".into(),
+            WindVariance { from, to } => format!("varying between {} and {}", from, to),
+
+            Cavok => "ceiling and visibility OK".into(),
+            Visibility(distance) => format!("visibility {}", distance),
+            DirectionalVisibility {
+                minimum,
+                direction,
+                distance,
+            } => format!(
+                "{} visibility {} to the {}",
+                if minimum { "minimum" } else { "maximum" },
+                distance,
+                direction
+            ),
+            CompassDirection(direction) => compass_direction(direction).into(),
+            MoreThan(quantity) => format!("more than {}", quantity),
+            LessThan(quantity) => format!("less than {}", quantity),
+            AtLeast(quantity) => format!("at least {}", quantity),
+            Between { lower, upper } => format!("between {} and {}", lower, upper),
+            RunwayVisualRange {
+                runway,
+                range,
+                trend,
+            } => match trend {
+                Some(trend) => format!("visual range on {} {} ({})", runway, range, trend),
+                None => format!("visual range on {} {}", runway, range),
+            },
+            VisibilityTrend(trend) => visibility_trend(trend).into(),
+
+            Runway(designator) => format!("runway {}", designator),
+            AllRunways => "all runways".into(),
+            PreviousRunwayReport => "runways as previously reported".into(),
+            RunwayState { runway, state } => format!("{}: {}", runway, state),
+            RunwayStateNotReported => "state not reported".into(),
+            RunwayCleared => "cleared".into(),
+            RunwayDeposit(deposit) => runway_deposit(deposit).into(),
+            RunwayContaminationExtent(extent) => contamination_extent(extent).into(),
+            DepositDepth(depth) => format!("{} deep", depth),
+            RunwayNotOperational => "not operational".into(),
+            FrictionCoefficient(coefficient) => format!("friction coefficient {}", coefficient),
+            BrakingAction(action) => format!("braking action {}", braking_action(action)),
+            FrictionUnreliable => "friction unreliable".into(),
+            ClosedBySnow => "aerodrome closed due to snow".into(),
+
+            Weather {
+                intensity,
+                descriptor,
+                phenomena,
+                vicinity,
+            } => {
+                let mut weather = weather(descriptor, phenomena);
+                match intensity {
+                    Intensity::Light => weather.insert_str(0, "light "),
+                    Intensity::Heavy => weather.insert_str(0, "heavy "),
+                    Intensity::Moderate => {}
+                }
+                if vicinity {
+                    weather.push_str(" in the vicinity");
+                }
+                weather
+            }
+            Precipitation(kind) => precipitation(kind).into(),
+            Obscuration(kind) => obscuration(kind).into(),
+            OtherPhenomenon(other) => other_phenomenon(other).into(),
+            RecentWeather(weather) => format!("recent {}", weather),
+
+            Clouds {
+                coverage,
+                base,
+                cloud_type,
+            } => {
+                let mut clouds = match (coverage, base) {
+                    (CloudCoverage::NoCloud, _) => "sky clear".to_string(),
+                    (CloudCoverage::NilCloud, _) => "no cloud detected".to_string(),
+                    (CloudCoverage::Clear, _) => "no clouds below 12,000 ft".to_string(),
+                    (CloudCoverage::NoSignificantCloud, _) => "no significant cloud".to_string(),
+                    (CloudCoverage::VerticalVisibility, Some(base)) => {
+                        format!("vertical visibility {}", base)
+                    }
+                    (CloudCoverage::VerticalVisibility, None) => "sky obscured".to_string(),
+                    (coverage, base) => {
+                        let coverage = match coverage {
+                            CloudCoverage::Few => "few clouds",
+                            CloudCoverage::Scattered => "scattered clouds",
+                            CloudCoverage::Broken => "broken clouds",
+                            _ => "overcast",
+                        };
+                        match base {
+                            Some(base) => format!("{} at {}", coverage, base),
+                            None => coverage.to_string(),
+                        }
+                    }
+                };
+                if let Some(cloud_type) = cloud_type {
+                    clouds.push_str(&format!(" ({})", cloud_type));
+                }
+                clouds
+            }
+            CloudType(kind) => cloud_type(kind).into(),
+
+            Temperature(temperature) => format!("temperature {}", temperature),
+            Dewpoint(dewpoint) => format!("dewpoint {}", dewpoint),
+            Pressure {
+                pressure,
+                unit: PressureUnit::Hectopascal,
+            } => format!("QNH {}", pressure),
+            Pressure { pressure, .. } => format!("altimeter {}", pressure),
+            AccumulatedRainfall { recent, past } => format!(
+                "rainfall of {} in the last 10 minutes and {} since 09:00 local time",
+                recent, past
+            ),
+            AirfieldClosed => "airfield closed".into(),
+            Color { current, next } => match next {
+                Some(next) => format!("color state {} becoming {}", current, next),
+                None => format!("color state {}", current),
+            },
+            ColorState(state) => color_state(state).into(),
+            WindShear(runway) => format!("wind shear on {}", runway),
+            LowLevelWindShear { height, wind } => {
+                format!("wind shear up to {} with {}", height, wind)
+            }
+            WaterTemperature(temperature) => format!("water temperature {}", temperature),
+            WaterSurfaceState(state) => water_surface_state(state).into(),
+            WaveHeight(height) => format!("significant wave height {}", height),
+
+            NoSignificantChange => "no significant change expected".into(),
+            Becoming => "becoming".into(),
+            Temporarily => "temporarily".into(),
+            TrendTime { time_type, time } => match time_type {
+                TrendTimeType::At => format!("at {}", time),
+                TrendTimeType::From => format!("from {}", time),
+                TrendTimeType::Until => format!("until {}", time),
+            },
+
+            Remarks => "remarks".into(),
+            StationType(kind) => station_type(kind).into(),
+            PeakWind {
+                direction,
+                speed,
+                time,
+            } => format!("peak wind from {} at {} {}", direction, speed, time),
+            AtTime(time) => format!("at {}", time),
+            MinutesPastTheHour(minute) => format!("at {} minutes past the hour", minute),
+            WindShift {
+                time,
+                frontal_passage,
+            } => {
+                if frontal_passage {
+                    format!("wind shift {} due to a frontal passage", time)
+                } else {
+                    format!("wind shift {}", time)
+                }
+            }
+            PressureChange(change) => pressure_change(change).into(),
+            SeaLevelPressure(pressure) => format!("sea level pressure {}", pressure),
+            HourlyPrecipitation(amount) => format!("{} of precipitation in the past hour", amount),
+            Precipitation3Or6Hour(amount) => {
+                format!("{} of precipitation in the past 3 or 6 hours", amount)
+            }
+            Precipitation24Hour(amount) => {
+                format!("{} of precipitation in the past 24 hours", amount)
+            }
+            MaximumTemperature6Hour(temperature) => {
+                format!("6-hour maximum temperature {}", temperature)
+            }
+            MinimumTemperature6Hour(temperature) => {
+                format!("6-hour minimum temperature {}", temperature)
+            }
+            TemperatureExtremes24Hour { maximum, minimum } => format!(
+                "24-hour maximum temperature {} and minimum temperature {}",
+                maximum, minimum
+            ),
+            PressureTendency {
+                characteristic,
+                change,
+            } => format!(
+                "pressure {}, {} over 3 hours",
+                pressure_tendency(characteristic),
+                change
+            ),
+            SensorNotAvailable(sensor) => sensor_status(sensor).into(),
+            UnparsedRemarks(remarks) => remarks.into(),
+        }
+    }
+
+    fn unit(&self, unit: Unit, value: f64) -> &'static str {
+        let singular = (value - 1.).abs() < f64::EPSILON;
+        match unit {
+            Unit::Degree => "°",
+            Unit::Speed(SpeedUnit::Knot) if singular => "knot",
+            Unit::Speed(SpeedUnit::Knot) => "knots",
+            Unit::Speed(SpeedUnit::MeterPerSecond) => "m/s",
+            Unit::Speed(SpeedUnit::KilometerPerHour) => "km/h",
+            Unit::Length(LengthUnit::Millimeter) => "mm",
+            Unit::Length(LengthUnit::Centimeter) => "cm",
+            Unit::Length(LengthUnit::Meter) => "m",
+            Unit::Length(LengthUnit::Kilometer) => "km",
+            Unit::Length(LengthUnit::Inch) => "in",
+            Unit::Length(LengthUnit::Foot) => "ft",
+            // Fractions of a mile are singular, i.e. 1/2 statute mile
+            Unit::Length(LengthUnit::StatuteMile) if singular || value < 1. => "statute mile",
+            Unit::Length(LengthUnit::StatuteMile) => "statute miles",
+            Unit::Pressure(PressureUnit::Hectopascal) => "hPa",
+            Unit::Pressure(PressureUnit::InchOfMercury) => "inHg",
+            Unit::Temperature(TemperatureUnit::Celsius) => "°C",
+            Unit::Temperature(TemperatureUnit::Fahrenheit) => "°F",
+        }
+    }
+
+    fn list(&self, items: &[String]) -> String {
+        match items {
+            [] => String::new(),
+            [item] => item.clone(),
+            [init @ .., last] => format!("{} and {}", init.join(", "), last),
+        }
+    }
+}
+
+fn observation_flag(flag: ObservationFlag) -> &'static str {
+    match flag {
+        ObservationFlag::Auto => "automated observation",
+        ObservationFlag::Nil => "missing report",
+        ObservationFlag::Correction => "corrected report",
+        ObservationFlag::CorrectionA => "first correction",
+        ObservationFlag::CorrectionB => "second correction",
+        ObservationFlag::CorrectionC => "third correction",
+        ObservationFlag::Delayed => "delayed report",
+    }
+}
+
+fn compass_direction(direction: CompassDirection) -> &'static str {
+    match direction {
+        CompassDirection::NorthEast => "north-east",
+        CompassDirection::NorthWest => "north-west",
+        CompassDirection::North => "north",
+        CompassDirection::SouthEast => "south-east",
+        CompassDirection::SouthWest => "south-west",
+        CompassDirection::South => "south",
+        CompassDirection::East => "east",
+        CompassDirection::West => "west",
+    }
+}
+
+fn runway_deposit(deposit: RunwayDeposit) -> &'static str {
+    match deposit {
+        RunwayDeposit::ClearAndDry => "clear and dry",
+        RunwayDeposit::Damp => "damp",
+        RunwayDeposit::Wet => "wet",
+        RunwayDeposit::RimeOrFrost => "rime or frost",
+        RunwayDeposit::DrySnow => "dry snow",
+        RunwayDeposit::WetSnow => "wet snow",
+        RunwayDeposit::Slush => "slush",
+        RunwayDeposit::Ice => "ice",
+        RunwayDeposit::CompactedSnow => "compacted snow",
+        RunwayDeposit::FrozenRuts => "frozen ruts",
+    }
+}
+
+fn weather(descriptor: Option<Descriptor>, phenomena: &str) -> String {
+    match (descriptor, phenomena) {
+        (Some(Descriptor::Showers), "") => "showers".into(),
+        (Some(Descriptor::Thunderstorm), "") => "thunderstorm".into(),
+        (Some(Descriptor::Showers), phenomena) => format!("{} showers", phenomena),
+        (Some(Descriptor::Thunderstorm), phenomena) => format!("thunderstorm with {}", phenomena),
+        (Some(Descriptor::Shallow), phenomena) => format!("shallow {}", phenomena),
+        (Some(Descriptor::Partial), phenomena) => format!("partial {}", phenomena),
+        (Some(Descriptor::Patches), phenomena) => format!("patches of {}", phenomena),
+        (Some(Descriptor::LowDrifting), phenomena) => format!("low drifting {}", phenomena),
+        (Some(Descriptor::Blowing), phenomena) => format!("blowing {}", phenomena),
+        (Some(Descriptor::Freezing), phenomena) => format!("freezing {}", phenomena),
+        (None, phenomena) => phenomena.into(),
+    }
+}
+
+fn pressure_tendency(characteristic: PressureTendencyCharacteristic) -> &'static str {
+    use PressureTendencyCharacteristic::*;
+    match characteristic {
+        IncreasingThenDecreasing => "increasing then decreasing",
+        IncreasingThenSteady => "increasing then steady",
+        Increasing => "increasing",
+        DecreasingOrSteadyThenIncreasing => "decreasing or steady then increasing",
+        Steady => "steady",
+        DecreasingThenIncreasing => "decreasing then increasing",
+        DecreasingThenSteady => "decreasing then steady",
+        Decreasing => "decreasing",
+        SteadyOrIncreasingThenDecreasing => "steady or increasing then decreasing",
+    }
+}
+
+fn visibility_trend(trend: VisibilityTrend) -> &'static str {
+    match trend {
+        VisibilityTrend::Up => "increasing",
+        VisibilityTrend::Down => "decreasing",
+        VisibilityTrend::NoChange => "no change",
+    }
+}
+
+fn contamination_extent(extent: RunwayContaminationExtent) -> &'static str {
+    match extent {
+        RunwayContaminationExtent::TenPercentOrLess => "10% or less covered",
+        RunwayContaminationExtent::ElevenToTwentyFivePercent => "11 to 25% covered",
+        RunwayContaminationExtent::TwentySixToFiftyPercent => "26 to 50% covered",
+        RunwayContaminationExtent::FiftyOneToOneHundredPercent => "51 to 100% covered",
+    }
+}
+
+fn precipitation(precipitation: Precipitation) -> &'static str {
+    match precipitation {
+        Precipitation::Rain => "rain",
+        Precipitation::Drizzle => "drizzle",
+        Precipitation::Snow => "snow",
+        Precipitation::SnowGrains => "snow grains",
+        Precipitation::IceCrystals => "ice crystals",
+        Precipitation::IcePellets => "ice pellets",
+        Precipitation::Hail => "hail",
+        Precipitation::Graupel => "small hail",
+        Precipitation::Unknown => "unknown precipitation",
+    }
+}
+
+fn obscuration(obscuration: Obscuration) -> &'static str {
+    match obscuration {
+        Obscuration::Fog => "fog",
+        Obscuration::Mist => "mist",
+        Obscuration::Haze => "haze",
+        Obscuration::VolcanicAsh => "volcanic ash",
+        Obscuration::WidespreadDust => "widespread dust",
+        Obscuration::Smoke => "smoke",
+        Obscuration::Sand => "sand",
+        Obscuration::Spray => "spray",
+    }
+}
+
+fn other_phenomenon(other: Other) -> &'static str {
+    match other {
+        Other::Squall => "squalls",
+        Other::SandWhirls => "dust whirls",
+        Other::Duststorm => "duststorm",
+        Other::Sandstorm => "sandstorm",
+        Other::FunnelCloud => "funnel cloud",
+    }
+}
+
+fn cloud_type(cloud_type: CloudType) -> &'static str {
+    match cloud_type {
+        CloudType::Cumulonimbus => "cumulonimbus",
+        CloudType::ToweringCumulus => "towering cumulus",
+        CloudType::Cumulus => "cumulus",
+        CloudType::Cirrus => "cirrus",
+        CloudType::Altocumulus => "altocumulus",
+        CloudType::Stratus => "stratus",
+    }
+}
+
+fn color_state(color_state: ColorState) -> &'static str {
+    match color_state {
+        ColorState::BluePlus => "blue+",
+        ColorState::Blue => "blue",
+        ColorState::White => "white",
+        ColorState::Green => "green",
+        ColorState::YellowOne => "yellow 1",
+        ColorState::YellowTwo => "yellow 2",
+        ColorState::Amber => "amber",
+        ColorState::Red => "red",
+    }
+}
+
+fn water_surface_state(state: WaterSurfaceState) -> &'static str {
+    match state {
+        WaterSurfaceState::GlassyCalm => "glassy calm sea",
+        WaterSurfaceState::RippledCalm => "rippled calm sea",
+        WaterSurfaceState::Smooth => "smooth sea",
+        WaterSurfaceState::Slight => "slight sea",
+        WaterSurfaceState::Moderate => "moderate sea",
+        WaterSurfaceState::Rough => "rough sea",
+        WaterSurfaceState::VeryRough => "very rough sea",
+        WaterSurfaceState::High => "high sea",
+        WaterSurfaceState::VeryHigh => "very high sea",
+        WaterSurfaceState::Phenomenal => "phenomenal sea",
+    }
+}
+
+fn station_type(station_type: AutomatedStationType) -> &'static str {
+    match station_type {
+        AutomatedStationType::WithoutPrecipitationDiscriminator => {
+            "automated station without precipitation discriminator"
+        }
+        AutomatedStationType::WithPrecipitationDiscriminator => {
+            "automated station with precipitation discriminator"
+        }
+    }
+}
+
+fn pressure_change(change: PressureChange) -> &'static str {
+    match change {
+        PressureChange::RisingRapidly => "pressure rising rapidly",
+        PressureChange::FallingRapidly => "pressure falling rapidly",
+    }
+}
+
+fn sensor_status(sensor: SensorStatus) -> &'static str {
+    match sensor {
+        SensorStatus::PresentWeatherIdentifierNotAvailable => {
+            "present weather identifier not available"
+        }
+        SensorStatus::PrecipitationAmountNotAvailable => "precipitation amount not available",
+        SensorStatus::FreezingRainNotAvailable => "freezing rain sensor not available",
+        SensorStatus::LightningNotAvailable => "lightning detector not available",
+        SensorStatus::RunwayVisualRangeNotAvailable => "runway visual range not available",
+        SensorStatus::VisibilityNotAvailable => "secondary visibility not available",
+        SensorStatus::CeilingNotAvailable => "secondary ceiling not available",
+        SensorStatus::SeaLevelPressureNotAvailable => "sea level pressure not available",
+    }
+}
+
+fn braking_action(braking_action: BrakingAction) -> &'static str {
+    match braking_action {
+        BrakingAction::Poor => "poor",
+        BrakingAction::MediumToPoor => "medium to poor",
+        BrakingAction::Medium => "medium",
+        BrakingAction::MediumToGood => "medium to good",
+        BrakingAction::Good => "good",
+    }
+}
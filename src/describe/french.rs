@@ -0,0 +1,481 @@
+use super::{LengthUnit, Message, MessageCatalog, PressureUnit, SpeedUnit, TemperatureUnit, Unit};
+use crate::tokens::*;
+
+/// French texts
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct French;
+
+impl MessageCatalog for French {
+    fn message(&self, message: Message<'_>) -> String {
+        use Message::*;
+        match message {
+            Station(station) => format!("message de {}", station),
+            ObservationTime { day_of_month, time } => {
+                format!("observé le {} à {}", day_of_month, time)
+            }
+            ValidityRange { begin, end } => format!("valide de {} à {}", begin, end),
+            ObservationFlag(flag) => observation_flag(flag).into(),
+            MaintenanceNeeded => "station nécessitant une maintenance".into(),
+
+            Calm => "vent calme".into(),
+            Wind {
+                direction,
+                speed,
+                gust,
+            } => {
+                let mut wind = match direction {
+                    Some(direction) => format!("vent du {} à {}", direction, speed),
+                    None => format!("vent variable à {}", speed),
+                };
+                if let Some(gust) = gust {
+                    wind.push_str(&format!(" avec rafales à {}", gust));
+                }
+                wind
+            }
+            WindNotReported => "vent non communiqué".into(),
+            WindVariance { from, to } => format!("variant entre {} et {}", from, to),
+
+            Cavok => "plafond et visibilité OK".into(),
+            Visibility(distance) => format!("visibilité {}", distance),
+            DirectionalVisibility {
+                minimum,
+                direction,
+                distance,
+            } => format!(
+                "visibilité {} {} vers {}",
+                if minimum { "minimale" } else { "maximale" },
+                distance,
+                direction
+            ),
+            CompassDirection(direction) => compass_direction(direction).into(),
+            MoreThan(quantity) => format!("supérieure à {}", quantity),
+            LessThan(quantity) => format!("inférieure à {}", quantity),
+            AtLeast(quantity) => format!("supérieure ou égale à {}", quantity),
+            Between { lower, upper } => format!("entre {} et {}", lower, upper),
+            RunwayVisualRange {
+                runway,
+                range,
+                trend,
+            } => match trend {
+                Some(trend) => format!("portée visuelle sur {} : {} ({})", runway, range, trend),
+                None => format!("portée visuelle sur {} : {}", runway, range),
+            },
+            VisibilityTrend(trend) => visibility_trend(trend).into(),
+
+            Runway(designator) => format!("la piste {}", designator),
+            AllRunways => "toutes les pistes".into(),
+            PreviousRunwayReport => "pistes comme précédemment signalé".into(),
+            RunwayState { runway, state } => format!("{} : {}", runway, state),
+            RunwayStateNotReported => "état non communiqué".into(),
+            RunwayCleared => "dégagée".into(),
+            RunwayDeposit(deposit) => runway_deposit(deposit).into(),
+            RunwayContaminationExtent(extent) => contamination_extent(extent).into(),
+            DepositDepth(depth) => format!("épaisseur {}", depth),
+            RunwayNotOperational => "hors service".into(),
+            FrictionCoefficient(coefficient) => {
+                format!("coefficient de frottement {}", coefficient)
+            }
+            BrakingAction(action) => format!("efficacité de freinage {}", braking_action(action)),
+            FrictionUnreliable => "frottement non fiable".into(),
+            ClosedBySnow => "aérodrome fermé pour cause de neige".into(),
+
+            Weather {
+                intensity,
+                descriptor,
+                phenomena,
+                vicinity,
+            } => {
+                let mut weather = weather(descriptor, phenomena);
+                match intensity {
+                    Intensity::Light => weather.push_str(" faible"),
+                    Intensity::Heavy => weather.push_str(" de forte intensité"),
+                    Intensity::Moderate => {}
+                }
+                if vicinity {
+                    weather.push_str(" au voisinage");
+                }
+                weather
+            }
+            Precipitation(kind) => precipitation(kind).into(),
+            Obscuration(kind) => obscuration(kind).into(),
+            OtherPhenomenon(other) => other_phenomenon(other).into(),
+            RecentWeather(weather) => format!("temps récent : {}", weather),
+
+            Clouds {
+                coverage,
+                base,
+                cloud_type,
+            } => {
+                let mut clouds = match (coverage, base) {
+                    (CloudCoverage::NoCloud, _) => "ciel clair".to_string(),
+                    (CloudCoverage::NilCloud, _) => "aucun nuage détecté".to_string(),
+                    (CloudCoverage::Clear, _) => "aucun nuage sous 12\u{a0}000 ft".to_string(),
+                    (CloudCoverage::NoSignificantCloud, _) => {
+                        "pas de nuages significatifs".to_string()
+                    }
+                    (CloudCoverage::VerticalVisibility, Some(base)) => {
+                        format!("visibilité verticale {}", base)
+                    }
+                    (CloudCoverage::VerticalVisibility, None) => "ciel invisible".to_string(),
+                    (coverage, base) => {
+                        let coverage = match coverage {
+                            CloudCoverage::Few => "quelques nuages",
+                            CloudCoverage::Scattered => "nuages épars",
+                            CloudCoverage::Broken => "nuages fragmentés",
+                            _ => "ciel couvert",
+                        };
+                        match base {
+                            Some(base) => format!("{} à {}", coverage, base),
+                            None => coverage.to_string(),
+                        }
+                    }
+                };
+                if let Some(cloud_type) = cloud_type {
+                    clouds.push_str(&format!(" ({})", cloud_type));
+                }
+                clouds
+            }
+            CloudType(kind) => cloud_type(kind).into(),
+
+            Temperature(temperature) => format!("température {}", temperature),
+            Dewpoint(dewpoint) => format!("point de rosée {}", dewpoint),
+            Pressure {
+                pressure,
+                unit: PressureUnit::Hectopascal,
+            } => format!("QNH {}", pressure),
+            Pressure { pressure, .. } => format!("calage altimétrique {}", pressure),
+            AccumulatedRainfall { recent, past } => format!(
+                "cumul de pluie de {} sur les 10 dernières minutes et de {} depuis 09:00 heure locale",
+                recent, past
+            ),
+            AirfieldClosed => "terrain fermé".into(),
+            Color { current, next } => match next {
+                Some(next) => format!("code couleur {} devenant {}", current, next),
+                None => format!("code couleur {}", current),
+            },
+            ColorState(state) => color_state(state).into(),
+            WindShear(runway) => format!("cisaillement du vent sur {}", runway),
+            LowLevelWindShear { height, wind } => {
+                format!("cisaillement du vent jusqu'à {} avec {}", height, wind)
+            }
+            WaterTemperature(temperature) => format!("température de l'eau {}", temperature),
+            WaterSurfaceState(state) => water_surface_state(state).into(),
+            WaveHeight(height) => format!("hauteur significative des vagues {}", height),
+
+            NoSignificantChange => "pas de changement significatif prévu".into(),
+            Becoming => "devenant".into(),
+            Temporarily => "temporairement".into(),
+            TrendTime { time_type, time } => match time_type {
+                TrendTimeType::At => format!("à {}", time),
+                TrendTimeType::From => format!("à partir de {}", time),
+                TrendTimeType::Until => format!("jusqu'à {}", time),
+            },
+
+            Remarks => "remarques".into(),
+            StationType(kind) => station_type(kind).into(),
+            PeakWind {
+                direction,
+                speed,
+                time,
+            } => format!("vent maximal du {} à {} {}", direction, speed, time),
+            AtTime(time) => format!("à {}", time),
+            MinutesPastTheHour(minute) => format!("à la {}e minute de l'heure", minute),
+            WindShift {
+                time,
+                frontal_passage,
+            } => {
+                if frontal_passage {
+                    format!(
+                        "changement de direction du vent {} dû au passage d'un front",
+                        time
+                    )
+                } else {
+                    format!("changement de direction du vent {}", time)
+                }
+            }
+            PressureChange(change) => pressure_change(change).into(),
+            SeaLevelPressure(pressure) => format!("pression au niveau de la mer {}", pressure),
+            HourlyPrecipitation(amount) => {
+                format!("{} de précipitations au cours de la dernière heure", amount)
+            }
+            Precipitation3Or6Hour(amount) => format!(
+                "{} de précipitations au cours des 3 ou 6 dernières heures",
+                amount
+            ),
+            Precipitation24Hour(amount) => format!(
+                "{} de précipitations au cours des 24 dernières heures",
+                amount
+            ),
+            MaximumTemperature6Hour(temperature) => {
+                format!("température maximale sur 6 heures {}", temperature)
+            }
+            MinimumTemperature6Hour(temperature) => {
+                format!("température minimale sur 6 heures {}", temperature)
+            }
+            TemperatureExtremes24Hour { maximum, minimum } => format!(
+                "température maximale sur 24 heures {} et minimale {}",
+                maximum, minimum
+            ),
+            PressureTendency {
+                characteristic,
+                change,
+            } => format!(
+                "pression {}, {} en 3 heures",
+                pressure_tendency(characteristic),
+                change
+            ),
+            SensorNotAvailable(sensor) => sensor_status(sensor).into(),
+            UnparsedRemarks(remarks) => remarks.into(),
+        }
+    }
+
+    /// Values below 2 are singular in French
+    fn unit(&self, unit: Unit, value: f64) -> &'static str {
+        let singular = value < 2.;
+        match unit {
+            Unit::Degree => "°",
+            Unit::Speed(SpeedUnit::Knot) if singular => "nœud",
+            Unit::Speed(SpeedUnit::Knot) => "nœuds",
+            Unit::Speed(SpeedUnit::MeterPerSecond) => "m/s",
+            Unit::Speed(SpeedUnit::KilometerPerHour) => "km/h",
+            Unit::Length(LengthUnit::Millimeter) => "mm",
+            Unit::Length(LengthUnit::Centimeter) => "cm",
+            Unit::Length(LengthUnit::Meter) => "m",
+            Unit::Length(LengthUnit::Kilometer) => "km",
+            Unit::Length(LengthUnit::Inch) => "po",
+            Unit::Length(LengthUnit::Foot) => "ft",
+            Unit::Length(LengthUnit::StatuteMile) if singular => "mille terrestre",
+            Unit::Length(LengthUnit::StatuteMile) => "milles terrestres",
+            Unit::Pressure(PressureUnit::Hectopascal) => "hPa",
+            Unit::Pressure(PressureUnit::InchOfMercury) => "inHg",
+            Unit::Temperature(TemperatureUnit::Celsius) => "°C",
+            Unit::Temperature(TemperatureUnit::Fahrenheit) => "°F",
+        }
+    }
+
+    fn list(&self, items: &[String]) -> String {
+        match items {
+            [] => String::new(),
+            [item] => item.clone(),
+            [init @ .., last] => format!("{} et {}", init.join(", "), last),
+        }
+    }
+
+    fn decimal_separator(&self) -> char {
+        ','
+    }
+
+    fn thousands_separator(&self) -> char {
+        '\u{a0}'
+    }
+}
+
+fn observation_flag(flag: ObservationFlag) -> &'static str {
+    match flag {
+        ObservationFlag::Auto => "observation automatique",
+        ObservationFlag::Nil => "message manquant",
+        ObservationFlag::Correction => "message corrigé",
+        ObservationFlag::CorrectionA => "première correction",
+        ObservationFlag::CorrectionB => "deuxième correction",
+        ObservationFlag::CorrectionC => "troisième correction",
+        ObservationFlag::Delayed => "message retardé",
+    }
+}
+
+/// Includes the article, i.e. `l'est`
+fn compass_direction(direction: CompassDirection) -> &'static str {
+    match direction {
+        CompassDirection::NorthEast => "le nord-est",
+        CompassDirection::NorthWest => "le nord-ouest",
+        CompassDirection::North => "le nord",
+        CompassDirection::SouthEast => "le sud-est",
+        CompassDirection::SouthWest => "le sud-ouest",
+        CompassDirection::South => "le sud",
+        CompassDirection::East => "l'est",
+        CompassDirection::West => "l'ouest",
+    }
+}
+
+/// Adjectives agree with `piste`
+fn runway_deposit(deposit: RunwayDeposit) -> &'static str {
+    match deposit {
+        RunwayDeposit::ClearAndDry => "sèche et dégagée",
+        RunwayDeposit::Damp => "humide",
+        RunwayDeposit::Wet => "mouillée",
+        RunwayDeposit::RimeOrFrost => "givre ou gelée blanche",
+        RunwayDeposit::DrySnow => "neige sèche",
+        RunwayDeposit::WetSnow => "neige mouillée",
+        RunwayDeposit::Slush => "neige fondante",
+        RunwayDeposit::Ice => "glace",
+        RunwayDeposit::CompactedSnow => "neige compactée",
+        RunwayDeposit::FrozenRuts => "ornières gelées",
+    }
+}
+
+fn weather(descriptor: Option<Descriptor>, phenomena: &str) -> String {
+    match (descriptor, phenomena) {
+        (Some(Descriptor::Showers), "") => "averses".into(),
+        (Some(Descriptor::Thunderstorm), "") => "orage".into(),
+        (Some(Descriptor::Showers), phenomena) => format!("averses de {}", phenomena),
+        (Some(Descriptor::Thunderstorm), phenomena) => format!("orage avec {}", phenomena),
+        (Some(Descriptor::Shallow), phenomena) => format!("{} mince", phenomena),
+        (Some(Descriptor::Partial), phenomena) => format!("{} partiel", phenomena),
+        (Some(Descriptor::Patches), phenomena) => format!("bancs de {}", phenomena),
+        (Some(Descriptor::LowDrifting), phenomena) => format!("chasse-{} basse", phenomena),
+        (Some(Descriptor::Blowing), phenomena) => format!("chasse-{} élevée", phenomena),
+        (Some(Descriptor::Freezing), "brouillard") => "brouillard givrant".into(),
+        (Some(Descriptor::Freezing), phenomena) => format!("{} verglaçante", phenomena),
+        (None, phenomena) => phenomena.into(),
+    }
+}
+
+fn pressure_tendency(characteristic: PressureTendencyCharacteristic) -> &'static str {
+    use PressureTendencyCharacteristic::*;
+    match characteristic {
+        IncreasingThenDecreasing => "en hausse puis en baisse",
+        IncreasingThenSteady => "en hausse puis stable",
+        Increasing => "en hausse",
+        DecreasingOrSteadyThenIncreasing => "en baisse ou stable puis en hausse",
+        Steady => "stable",
+        DecreasingThenIncreasing => "en baisse puis en hausse",
+        DecreasingThenSteady => "en baisse puis stable",
+        Decreasing => "en baisse",
+        SteadyOrIncreasingThenDecreasing => "stable ou en hausse puis en baisse",
+    }
+}
+
+fn visibility_trend(trend: VisibilityTrend) -> &'static str {
+    match trend {
+        VisibilityTrend::Up => "en augmentation",
+        VisibilityTrend::Down => "en diminution",
+        VisibilityTrend::NoChange => "sans changement",
+    }
+}
+
+fn contamination_extent(extent: RunwayContaminationExtent) -> &'static str {
+    match extent {
+        RunwayContaminationExtent::TenPercentOrLess => "couverte à 10\u{a0}% ou moins",
+        RunwayContaminationExtent::ElevenToTwentyFivePercent => "couverte de 11 à 25\u{a0}%",
+        RunwayContaminationExtent::TwentySixToFiftyPercent => "couverte de 26 à 50\u{a0}%",
+        RunwayContaminationExtent::FiftyOneToOneHundredPercent => "couverte de 51 à 100\u{a0}%",
+    }
+}
+
+fn precipitation(precipitation: Precipitation) -> &'static str {
+    match precipitation {
+        Precipitation::Rain => "pluie",
+        Precipitation::Drizzle => "bruine",
+        Precipitation::Snow => "neige",
+        Precipitation::SnowGrains => "neige en grains",
+        Precipitation::IceCrystals => "cristaux de glace",
+        Precipitation::IcePellets => "granules de glace",
+        Precipitation::Hail => "grêle",
+        Precipitation::Graupel => "grésil",
+        Precipitation::Unknown => "précipitations inconnues",
+    }
+}
+
+fn obscuration(obscuration: Obscuration) -> &'static str {
+    match obscuration {
+        Obscuration::Fog => "brouillard",
+        Obscuration::Mist => "brume",
+        Obscuration::Haze => "brume sèche",
+        Obscuration::VolcanicAsh => "cendres volcaniques",
+        Obscuration::WidespreadDust => "poussière généralisée",
+        Obscuration::Smoke => "fumée",
+        Obscuration::Sand => "sable",
+        Obscuration::Spray => "embruns",
+    }
+}
+
+fn other_phenomenon(other: Other) -> &'static str {
+    match other {
+        Other::Squall => "grains",
+        Other::SandWhirls => "tourbillons de poussière",
+        Other::Duststorm => "tempête de poussière",
+        Other::Sandstorm => "tempête de sable",
+        Other::FunnelCloud => "nuage en entonnoir",
+    }
+}
+
+fn cloud_type(cloud_type: CloudType) -> &'static str {
+    match cloud_type {
+        CloudType::Cumulonimbus => "cumulonimbus",
+        CloudType::ToweringCumulus => "cumulus bourgeonnant",
+        CloudType::Cumulus => "cumulus",
+        CloudType::Cirrus => "cirrus",
+        CloudType::Altocumulus => "altocumulus",
+        CloudType::Stratus => "stratus",
+    }
+}
+
+fn color_state(color_state: ColorState) -> &'static str {
+    match color_state {
+        ColorState::BluePlus => "bleu+",
+        ColorState::Blue => "bleu",
+        ColorState::White => "blanc",
+        ColorState::Green => "vert",
+        ColorState::YellowOne => "jaune 1",
+        ColorState::YellowTwo => "jaune 2",
+        ColorState::Amber => "ambre",
+        ColorState::Red => "rouge",
+    }
+}
+
+fn water_surface_state(state: WaterSurfaceState) -> &'static str {
+    match state {
+        WaterSurfaceState::GlassyCalm => "mer calme sans rides",
+        WaterSurfaceState::RippledCalm => "mer calme ridée",
+        WaterSurfaceState::Smooth => "mer belle",
+        WaterSurfaceState::Slight => "mer peu agitée",
+        WaterSurfaceState::Moderate => "mer agitée",
+        WaterSurfaceState::Rough => "mer forte",
+        WaterSurfaceState::VeryRough => "mer très forte",
+        WaterSurfaceState::High => "mer grosse",
+        WaterSurfaceState::VeryHigh => "mer très grosse",
+        WaterSurfaceState::Phenomenal => "mer énorme",
+    }
+}
+
+fn station_type(station_type: AutomatedStationType) -> &'static str {
+    match station_type {
+        AutomatedStationType::WithoutPrecipitationDiscriminator => {
+            "station automatique sans discriminateur de précipitations"
+        }
+        AutomatedStationType::WithPrecipitationDiscriminator => {
+            "station automatique avec discriminateur de précipitations"
+        }
+    }
+}
+
+fn pressure_change(change: PressureChange) -> &'static str {
+    match change {
+        PressureChange::RisingRapidly => "pression en hausse rapide",
+        PressureChange::FallingRapidly => "pression en baisse rapide",
+    }
+}
+
+fn sensor_status(sensor: SensorStatus) -> &'static str {
+    match sensor {
+        SensorStatus::PresentWeatherIdentifierNotAvailable => {
+            "identificateur de temps présent indisponible"
+        }
+        SensorStatus::PrecipitationAmountNotAvailable => "quantité de précipitations indisponible",
+        SensorStatus::FreezingRainNotAvailable => "capteur de pluie verglaçante indisponible",
+        SensorStatus::LightningNotAvailable => "détecteur de foudre indisponible",
+        SensorStatus::RunwayVisualRangeNotAvailable => "portée visuelle de piste indisponible",
+        SensorStatus::VisibilityNotAvailable => "visibilité secondaire indisponible",
+        SensorStatus::CeilingNotAvailable => "plafond secondaire indisponible",
+        SensorStatus::SeaLevelPressureNotAvailable => "pression au niveau de la mer indisponible",
+    }
+}
+
+fn braking_action(braking_action: BrakingAction) -> &'static str {
+    match braking_action {
+        BrakingAction::Poor => "mauvaise",
+        BrakingAction::MediumToPoor => "moyenne à mauvaise",
+        BrakingAction::Medium => "moyenne",
+        BrakingAction::MediumToGood => "moyenne à bonne",
+        BrakingAction::Good => "bonne",
+    }
+}
@@ -0,0 +1,1183 @@
+//! Plain-language descriptions of reports, i.e. for pilots and non-specialists
+//!
+//! Reports are broken down into [Message]s, which a [MessageCatalog] renders in a particular language.
+//! Catalogs for [English], [French] and [Spanish] are provided, and others can be supplied by implementing the trait.
+
+mod english;
+mod french;
+mod spanish;
+
+pub use english::English;
+pub use french::French;
+pub use spanish::Spanish;
+
+use uom::si::f64::{Angle, Length, Pressure, ThermodynamicTemperature, Velocity};
+use uom::si::{
+    angle::degree,
+    length::{centimeter, foot, inch, kilometer, meter, mile, millimeter},
+    pressure::{hectopascal, inch_of_mercury},
+    thermodynamic_temperature::{degree_celsius, degree_fahrenheit},
+    velocity::{kilometer_per_hour, knot, meter_per_second},
+};
+
+use crate::tokens::*;
+
+/// A phrase to be rendered by a [MessageCatalog]
+///
+/// Quantities, times and nested phrases are rendered before being passed in, so a catalog only has to arrange words around them.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Message<'a> {
+    /// Station identifier, possibly followed by its name
+    Station(&'a str),
+    ObservationTime {
+        day_of_month: u8,
+        time: &'a str,
+    },
+    ValidityRange {
+        begin: &'a str,
+        end: &'a str,
+    },
+    ObservationFlag(ObservationFlag),
+    MaintenanceNeeded,
+
+    Calm,
+    /// A lack of direction indicates variable
+    Wind {
+        direction: Option<&'a str>,
+        speed: &'a str,
+        gust: Option<&'a str>,
+    },
+    WindNotReported,
+    WindVariance {
+        from: &'a str,
+        to: &'a str,
+    },
+
+    Cavok,
+    Visibility(&'a str),
+    DirectionalVisibility {
+        /// Otherwise the maximum
+        minimum: bool,
+        direction: &'a str,
+        distance: &'a str,
+    },
+    CompassDirection(CompassDirection),
+    MoreThan(&'a str),
+    LessThan(&'a str),
+    AtLeast(&'a str),
+    Between {
+        lower: &'a str,
+        upper: &'a str,
+    },
+    RunwayVisualRange {
+        runway: &'a str,
+        range: &'a str,
+        trend: Option<&'a str>,
+    },
+    VisibilityTrend(VisibilityTrend),
+
+    Runway(&'a str),
+    AllRunways,
+    /// `99` in place of a runway designator
+    PreviousRunwayReport,
+    RunwayState {
+        runway: &'a str,
+        state: &'a str,
+    },
+    RunwayStateNotReported,
+    RunwayCleared,
+    RunwayDeposit(RunwayDeposit),
+    RunwayContaminationExtent(RunwayContaminationExtent),
+    DepositDepth(&'a str),
+    RunwayNotOperational,
+    FrictionCoefficient(&'a str),
+    BrakingAction(BrakingAction),
+    FrictionUnreliable,
+    ClosedBySnow,
+
+    /// `phenomena` is empty for `VCSH` and `VCTS`
+    Weather {
+        intensity: Intensity,
+        descriptor: Option<Descriptor>,
+        phenomena: &'a str,
+        vicinity: bool,
+    },
+    Precipitation(Precipitation),
+    Obscuration(Obscuration),
+    OtherPhenomenon(Other),
+    RecentWeather(&'a str),
+
+    /// `base` is absent if it was not reported or for coverages without a base, like `NSC`
+    Clouds {
+        coverage: CloudCoverage,
+        base: Option<&'a str>,
+        cloud_type: Option<&'a str>,
+    },
+    CloudType(CloudType),
+
+    Temperature(&'a str),
+    Dewpoint(&'a str),
+    /// QNH or altimeter setting
+    Pressure {
+        pressure: &'a str,
+        unit: PressureUnit,
+    },
+    AccumulatedRainfall {
+        recent: &'a str,
+        past: &'a str,
+    },
+    /// `BLACK`
+    AirfieldClosed,
+    Color {
+        current: &'a str,
+        next: Option<&'a str>,
+    },
+    ColorState(ColorState),
+    WindShear(&'a str),
+    LowLevelWindShear {
+        height: &'a str,
+        wind: &'a str,
+    },
+    WaterTemperature(&'a str),
+    WaterSurfaceState(WaterSurfaceState),
+    WaveHeight(&'a str),
+
+    NoSignificantChange,
+    Becoming,
+    Temporarily,
+    TrendTime {
+        time_type: TrendTimeType,
+        time: &'a str,
+    },
+
+    Remarks,
+    StationType(AutomatedStationType),
+    PeakWind {
+        direction: &'a str,
+        speed: &'a str,
+        time: &'a str,
+    },
+    /// Time of an event, i.e. `at 15:35 UTC`
+    AtTime(&'a str),
+    /// Time of an event reported without an hour because it is the same as the report
+    MinutesPastTheHour(u8),
+    WindShift {
+        time: &'a str,
+        frontal_passage: bool,
+    },
+    PressureChange(PressureChange),
+    SeaLevelPressure(&'a str),
+    HourlyPrecipitation(&'a str),
+    Precipitation3Or6Hour(&'a str),
+    Precipitation24Hour(&'a str),
+    MaximumTemperature6Hour(&'a str),
+    MinimumTemperature6Hour(&'a str),
+    TemperatureExtremes24Hour {
+        maximum: &'a str,
+        minimum: &'a str,
+    },
+    /// `change` is signed
+    PressureTendency {
+        characteristic: PressureTendencyCharacteristic,
+        change: &'a str,
+    },
+    SensorNotAvailable(SensorStatus),
+    UnparsedRemarks(&'a str),
+}
+
+/// Unit a quantity is rendered in
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Unit {
+    Degree,
+    Speed(SpeedUnit),
+    Length(LengthUnit),
+    Pressure(PressureUnit),
+    Temperature(TemperatureUnit),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpeedUnit {
+    Knot,
+    MeterPerSecond,
+    KilometerPerHour,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LengthUnit {
+    Millimeter,
+    Centimeter,
+    Meter,
+    Kilometer,
+    Inch,
+    Foot,
+    /// Written as a fraction where possible, i.e. `1 1/2`
+    StatuteMile,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PressureUnit {
+    Hectopascal,
+    InchOfMercury,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TemperatureUnit {
+    Celsius,
+    Fahrenheit,
+}
+
+/// Units used to describe each kind of quantity
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Units {
+    pub speed: SpeedUnit,
+    /// Visibilities of 5km or more are described in kilometers when this is [LengthUnit::Meter]
+    pub visibility: LengthUnit,
+    pub runway_visual_range: LengthUnit,
+    /// Cloud bases and wind shear heights
+    pub height: LengthUnit,
+    /// Precipitation amounts and runway deposit depths
+    pub precipitation: LengthUnit,
+    pub wave_height: LengthUnit,
+    pub pressure: PressureUnit,
+    pub temperature: TemperatureUnit,
+}
+
+impl Units {
+    /// Units used by most ICAO member states
+    pub const fn metric() -> Self {
+        Self {
+            speed: SpeedUnit::Knot,
+            visibility: LengthUnit::Meter,
+            runway_visual_range: LengthUnit::Meter,
+            height: LengthUnit::Foot,
+            precipitation: LengthUnit::Millimeter,
+            wave_height: LengthUnit::Meter,
+            pressure: PressureUnit::Hectopascal,
+            temperature: TemperatureUnit::Celsius,
+        }
+    }
+
+    /// Units used in the United States
+    pub const fn us() -> Self {
+        Self {
+            speed: SpeedUnit::Knot,
+            visibility: LengthUnit::StatuteMile,
+            runway_visual_range: LengthUnit::Foot,
+            height: LengthUnit::Foot,
+            precipitation: LengthUnit::Inch,
+            wave_height: LengthUnit::Foot,
+            pressure: PressureUnit::InchOfMercury,
+            temperature: TemperatureUnit::Celsius,
+        }
+    }
+
+    /// [Units::us] for stations in the United States and its territories, otherwise [Units::metric]
+    ///
+    /// Uses the country of the station when the `stations` feature knows it, falling back to the
+    /// identifier prefixes of the contiguous US (`K`), Alaska (`PA`), Hawaii (`PH`),
+    /// Guam and the Northern Mariana Islands (`PG`), Puerto Rico (`TJ`) and the US Virgin Islands (`TI`).
+    pub fn for_station(identifier: &str) -> Self {
+        #[cfg(feature = "stations")]
+        if let Some(station) = crate::stations::station(identifier) {
+            return Self::for_country(station.country);
+        }
+        if identifier.starts_with('K')
+            || ["PA", "PH", "PG", "TJ", "TI"]
+                .iter()
+                .any(|prefix| identifier.starts_with(prefix))
+        {
+            Self::us()
+        } else {
+            Self::metric()
+        }
+    }
+
+    /// [Units::us] for the United States and its territories, otherwise [Units::metric]
+    ///
+    /// Takes an [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) country code. Guam (`GU`), Puerto Rico (`PR`),
+    /// the US Virgin Islands (`VI`), American Samoa (`AS`) and the Northern Mariana Islands (`MP`) have codes of their own.
+    pub fn for_country(country: &str) -> Self {
+        if matches!(country, "US" | "GU" | "PR" | "VI" | "AS" | "MP") {
+            Self::us()
+        } else {
+            Self::metric()
+        }
+    }
+}
+
+impl Default for Units {
+    fn default() -> Self {
+        Self::metric()
+    }
+}
+
+/// Texts for describing reports in a particular language
+pub trait MessageCatalog {
+    fn message(&self, message: Message<'_>) -> String;
+
+    /// Name or symbol of a unit, where `value` can be used to pick a plural form
+    fn unit(&self, unit: Unit, value: f64) -> &'static str;
+
+    /// Joins items into a list, i.e. `rain, snow and hail`
+    fn list(&self, items: &[String]) -> String;
+
+    fn decimal_separator(&self) -> char {
+        '.'
+    }
+
+    /// Placed between groups of three digits in numbers of 1000 or more
+    fn thousands_separator(&self) -> char {
+        ','
+    }
+}
+
+/// Catalog for a language tag like `en`, `fr-CA` or `es_ES`
+pub fn catalog(language: &str) -> Option<&'static dyn MessageCatalog> {
+    let primary = language.split(&['-', '_'][..]).next()?;
+    match primary.to_ascii_lowercase().as_str() {
+        "en" => Some(&English),
+        "fr" => Some(&French),
+        "es" => Some(&Spanish),
+        _ => None,
+    }
+}
+
+impl<'input> MetarReport<'input> {
+    /// Describes the report in plain language, in units customary for the station
+    ///
+    /// See [Units::for_station].
+    pub fn describe(&self, catalog: &dyn MessageCatalog) -> String {
+        self.describe_with_units(catalog, &Units::for_station(&self.identifier))
+    }
+
+    /// Describes the report in plain language
+    ///
+    /// Each part of the report is a sentence: the header, the current conditions, supplementary information, each trend and the remarks.
+    pub fn describe_with_units(&self, catalog: &dyn MessageCatalog, units: &Units) -> String {
+        let describer = Describer { catalog, units };
+        describer.metar(self).join(" ")
+    }
+}
+
+struct Describer<'a> {
+    catalog: &'a dyn MessageCatalog,
+    units: &'a Units,
+}
+
+impl<'a> Describer<'a> {
+    fn say(&self, message: Message<'_>) -> String {
+        self.catalog.message(message)
+    }
+
+    fn metar(&self, report: &MetarReport) -> Vec<String> {
+        let mut sentences = vec![];
+        sentences.extend(self.sentence(self.header(report)));
+
+        let precise_temperatures = report
+            .remark_section
+            .as_ref()
+            .and_then(|remarks| remarks.precise_temperatures);
+        let mut conditions = self.conditions(
+            report.wind.as_ref(),
+            report.visibility.as_ref(),
+            report.cavok,
+            &report.weather,
+            &report.cloud_cover,
+        );
+        for runway_visibility in &report.runway_visibilities {
+            conditions.push(self.runway_visibility(runway_visibility));
+        }
+        match (precise_temperatures, report.temperatures) {
+            (Some(temperatures), _) => conditions.extend(self.temperatures(&temperatures, 1)),
+            (None, Some(temperatures)) => conditions.extend(self.temperatures(&temperatures, 0)),
+            (None, None) => {}
+        }
+        if let Some(pressure) = report.pressure {
+            conditions.push(self.say(Message::Pressure {
+                pressure: &self.pressure(pressure),
+                unit: self.units.pressure,
+            }));
+        }
+        sentences.extend(self.sentence(conditions));
+
+        let mut supplementary = vec![];
+        if !report.recent_weather.is_empty() {
+            let weather = report
+                .recent_weather
+                .iter()
+                .map(|weather| self.weather(weather))
+                .collect::<Vec<_>>();
+            supplementary.push(self.say(Message::RecentWeather(&self.catalog.list(&weather))));
+        }
+        for wind_shear in &report.wind_shear {
+            supplementary.push(self.wind_shear(wind_shear));
+        }
+        for runway_report in &report.runway_reports {
+            supplementary.push(self.runway_report(runway_report));
+        }
+        if let Some(rainfall) = report.accumulated_rainfall {
+            supplementary.push(self.say(Message::AccumulatedRainfall {
+                recent: &self.precipitation(rainfall.recent),
+                past: &self.precipitation(rainfall.past),
+            }));
+        }
+        if let Some(color) = report.color {
+            if color.is_black {
+                supplementary.push(self.say(Message::AirfieldClosed));
+            }
+            supplementary.push(self.color(color.current_color, color.next_color));
+        }
+        if let Some(water_conditions) = report.water_conditions {
+            supplementary.extend(self.water_conditions(&water_conditions));
+        }
+        sentences.extend(self.sentence(supplementary));
+
+        for trend in &report.trends {
+            sentences.extend(self.trend(trend));
+        }
+
+        match (&report.remark_section, &report.remark) {
+            (Some(remark_section), _) => sentences.extend(self.remarks(remark_section)),
+            (None, Some(remark)) => {
+                let remark = [":RMK", "R MK", "RMK", "REMARK"]
+                    .iter()
+                    .find_map(|marker| remark.strip_prefix(marker))
+                    .unwrap_or(remark)
+                    .trim();
+                if !remark.is_empty() {
+                    sentences.extend(self.prefixed_sentence(
+                        vec![self.say(Message::Remarks)],
+                        vec![self.say(Message::UnparsedRemarks(remark))],
+                    ));
+                }
+            }
+            (None, None) => {}
+        }
+        sentences
+    }
+
+    fn header(&self, report: &MetarReport) -> Vec<String> {
+        let mut header = vec![self.say(Message::Station(&station_name(&report.identifier)))];
+        if let Some(observation_time) = report.observation_time {
+            header.push(self.say(Message::ObservationTime {
+                day_of_month: observation_time.day_of_month,
+                time: &self.time(observation_time.time),
+            }));
+        }
+        if let Some(range) = report.observation_validity_range {
+            header.push(self.say(Message::ValidityRange {
+                begin: &self.time(range.begin),
+                end: &self.time(range.end),
+            }));
+        }
+        for flag in &report.observation_flags {
+            header.push(self.say(Message::ObservationFlag(*flag)));
+        }
+        if report.maintenance_needed {
+            header.push(self.say(Message::MaintenanceNeeded));
+        }
+        header
+    }
+
+    /// Elements shared by the body of a report and its trends
+    fn conditions(
+        &self,
+        wind: Option<&Wind>,
+        visibility: Option<&Visibility>,
+        cavok: bool,
+        weather: &[Weather],
+        cloud_cover: &[CloudCover],
+    ) -> Vec<String> {
+        let mut conditions = vec![];
+        if let Some(wind) = wind {
+            conditions.push(self.wind(wind));
+            if let Some((from, to)) = wind.variance {
+                conditions.push(self.say(Message::WindVariance {
+                    from: &self.angle(from),
+                    to: &self.angle(to),
+                }));
+            }
+        }
+        if cavok {
+            conditions.push(self.say(Message::Cavok));
+        }
+        if let Some(visibility) = visibility {
+            conditions.extend(self.visibility(visibility));
+        }
+        conditions.extend(weather.iter().map(|weather| self.weather(weather)));
+        conditions.extend(cloud_cover.iter().map(|cloud| self.cloud(cloud)));
+        conditions
+    }
+
+    fn wind(&self, wind: &Wind) -> String {
+        if wind.is_calm() == Some(true) {
+            return self.say(Message::Calm);
+        }
+        match wind.speed.or(wind.peak_gust) {
+            Some(speed) => self.say(Message::Wind {
+                direction: wind
+                    .direction
+                    .map(|direction| self.angle(direction))
+                    .as_deref(),
+                speed: &self.speed(speed),
+                gust: wind.peak_gust.map(|gust| self.speed(gust)).as_deref(),
+            }),
+            None => self.say(Message::WindNotReported),
+        }
+    }
+
+    fn visibility(&self, visibility: &Visibility) -> Vec<String> {
+        let mut phrases = vec![];
+        if let Some(prevailing) = visibility.prevailing {
+            phrases.push(self.say(Message::Visibility(&self.visibility_distance(&prevailing))));
+        }
+        let directional = [
+            (true, visibility.minimum_directional),
+            (false, visibility.maximum_directional),
+        ];
+        for (minimum, directional) in directional.iter() {
+            if let Some(directional) = directional {
+                phrases.push(self.say(Message::DirectionalVisibility {
+                    minimum: *minimum,
+                    direction: &self.say(Message::CompassDirection(directional.direction)),
+                    distance: &self.visibility_distance(&directional.distance),
+                }));
+            }
+        }
+        phrases
+    }
+
+    fn visibility_distance(&self, visibility: &RawVisibility) -> String {
+        let unit = match self.units.visibility {
+            LengthUnit::Meter if visibility.distance.get::<meter>() >= 5000. => {
+                LengthUnit::Kilometer
+            }
+            unit => unit,
+        };
+        // 9999 is reported for visibilities of 10km or more
+        if visibility.out_of_range.is_none()
+            && (visibility.distance.get::<meter>() - 9999.).abs() < 0.5
+        {
+            let ten_kilometers = Length::new::<kilometer>(10.);
+            return self.say(Message::AtLeast(&self.length(ten_kilometers, unit, 1)));
+        }
+        self.out_of_range(
+            visibility.out_of_range,
+            self.length(visibility.distance, unit, 1),
+        )
+    }
+
+    fn out_of_range(&self, out_of_range: Option<OutOfRange>, quantity: String) -> String {
+        match out_of_range {
+            Some(OutOfRange::Above) => self.say(Message::MoreThan(&quantity)),
+            Some(OutOfRange::Below) => self.say(Message::LessThan(&quantity)),
+            None => quantity,
+        }
+    }
+
+    fn runway_visibility(&self, runway_visibility: &RunwayVisibility) -> String {
+        let unit = self.units.runway_visual_range;
+        let distance = |visibility: &RawVisibility| {
+            self.out_of_range(
+                visibility.out_of_range,
+                self.length(visibility.distance, unit, 0),
+            )
+        };
+        let range = match &runway_visibility.visibility {
+            VisibilityType::Fixed(visibility) => distance(visibility),
+            VisibilityType::Varying { lower, upper } => self.say(Message::Between {
+                lower: &distance(lower),
+                upper: &distance(upper),
+            }),
+        };
+        self.say(Message::RunwayVisualRange {
+            runway: &self.runway(&runway_visibility.designator),
+            range: &range,
+            trend: runway_visibility
+                .trend
+                .map(|trend| self.say(Message::VisibilityTrend(trend)))
+                .as_deref(),
+        })
+    }
+
+    fn runway(&self, designator: &str) -> String {
+        match designator {
+            "88" => self.say(Message::AllRunways),
+            "99" => self.say(Message::PreviousRunwayReport),
+            designator => self.say(Message::Runway(designator)),
+        }
+    }
+
+    fn runway_report(&self, runway_report: &RunwayReport) -> String {
+        let mut state = vec![];
+        match runway_report.report_info {
+            RunwayReportInfo::ClosedBySnow => return self.say(Message::ClosedBySnow),
+            RunwayReportInfo::Cleared { friction } => {
+                state.push(self.say(Message::RunwayCleared));
+                state.extend(friction.map(|friction| self.friction(friction)));
+            }
+            RunwayReportInfo::Contaminated {
+                deposit,
+                extent,
+                depth,
+                friction,
+            } => {
+                state.extend(deposit.map(|deposit| self.say(Message::RunwayDeposit(deposit))));
+                state.extend(
+                    extent.map(|extent| self.say(Message::RunwayContaminationExtent(extent))),
+                );
+                state.extend(depth.map(|depth| match depth {
+                    DepositDepth::Depth {
+                        depth,
+                        out_of_range,
+                    } => self.say(Message::DepositDepth(&self.out_of_range(
+                        out_of_range,
+                        self.length(depth, self.units.precipitation, 2),
+                    ))),
+                    DepositDepth::NotOperational => self.say(Message::RunwayNotOperational),
+                }));
+                state.extend(friction.map(|friction| self.friction(friction)));
+            }
+        }
+        if state.is_empty() {
+            state.push(self.say(Message::RunwayStateNotReported));
+        }
+        self.say(Message::RunwayState {
+            runway: &self.runway(&runway_report.designator),
+            state: &self.catalog.list(&state),
+        })
+    }
+
+    fn friction(&self, friction: RunwayFriction) -> String {
+        match friction {
+            RunwayFriction::Coefficient(coefficient) => {
+                self.say(Message::FrictionCoefficient(&self.number(coefficient, 2)))
+            }
+            RunwayFriction::BrakingAction(braking_action) => {
+                self.say(Message::BrakingAction(braking_action))
+            }
+            RunwayFriction::Unreliable => self.say(Message::FrictionUnreliable),
+        }
+    }
+
+    fn wind_shear(&self, wind_shear: &WindShear) -> String {
+        match wind_shear {
+            WindShear::Runway(designator) => {
+                self.say(Message::WindShear(&self.say(Message::Runway(designator))))
+            }
+            WindShear::AllRunways => self.say(Message::WindShear(&self.say(Message::AllRunways))),
+            WindShear::LowLevel { height, wind } => self.say(Message::LowLevelWindShear {
+                height: &self.length(*height, self.units.height, 0),
+                wind: &self.wind(wind),
+            }),
+        }
+    }
+
+    fn weather(&self, weather: &Weather) -> String {
+        let phenomena = match &weather.condition {
+            Some(Condition::Precipitation(precipitation)) => self.catalog.list(
+                &precipitation
+                    .iter()
+                    .map(|precipitation| self.say(Message::Precipitation(*precipitation)))
+                    .collect::<Vec<_>>(),
+            ),
+            Some(Condition::Obscuration(obscuration)) => {
+                self.say(Message::Obscuration(*obscuration))
+            }
+            Some(Condition::Other(other)) => self.say(Message::OtherPhenomenon(*other)),
+            None => String::new(),
+        };
+        self.say(Message::Weather {
+            intensity: weather.intensity,
+            descriptor: weather.descriptor,
+            phenomena: &phenomena,
+            vicinity: weather.vicinity,
+        })
+    }
+
+    fn cloud(&self, cloud: &CloudCover) -> String {
+        self.say(Message::Clouds {
+            coverage: cloud.coverage,
+            base: cloud
+                .base
+                .map(|base| self.length(base, self.units.height, 0))
+                .as_deref(),
+            cloud_type: cloud
+                .cloud_type
+                .map(|cloud_type| self.say(Message::CloudType(cloud_type)))
+                .as_deref(),
+        })
+    }
+
+    fn temperatures(&self, temperatures: &Temperatures, decimals: usize) -> Vec<String> {
+        let mut phrases = vec![self.say(Message::Temperature(
+            &self.temperature(temperatures.air, decimals),
+        ))];
+        if let Some(dewpoint) = temperatures.dewpoint {
+            phrases.push(self.say(Message::Dewpoint(&self.temperature(dewpoint, decimals))));
+        }
+        phrases
+    }
+
+    fn color(&self, current: ColorState, next: Option<ColorState>) -> String {
+        self.say(Message::Color {
+            current: &self.say(Message::ColorState(current)),
+            next: next
+                .map(|next| self.say(Message::ColorState(next)))
+                .as_deref(),
+        })
+    }
+
+    fn water_conditions(&self, water_conditions: &WaterConditions) -> Vec<String> {
+        let mut phrases = vec![];
+        if let Some(temperature) = water_conditions.temperature {
+            phrases.push(self.say(Message::WaterTemperature(&self.temperature(temperature, 0))));
+        }
+        if let Some(surface_state) = water_conditions.surface_state {
+            phrases.push(self.say(Message::WaterSurfaceState(surface_state)));
+        }
+        if let Some(wave_height) = water_conditions.significant_wave_height {
+            phrases.push(self.say(Message::WaveHeight(&self.length(
+                wave_height,
+                self.units.wave_height,
+                1,
+            ))));
+        }
+        phrases
+    }
+
+    fn trend(&self, trend: &Trend) -> Option<String> {
+        let (kind, report) = match trend {
+            Trend::NoSignificantChange => {
+                return self.sentence(vec![self.say(Message::NoSignificantChange)])
+            }
+            Trend::Becoming(report) => (Message::Becoming, report),
+            Trend::Temporarily(report) => (Message::Temporarily, report),
+        };
+        let mut prefix = vec![self.say(kind)];
+        if let Some(time) = report.time {
+            prefix.push(self.say(Message::TrendTime {
+                time_type: time.time_type,
+                time: &self.time(time.time),
+            }));
+        }
+        let mut conditions = self.conditions(
+            report.wind.as_ref(),
+            report.visibility.as_ref(),
+            false,
+            &report.weather,
+            &report.cloud_cover,
+        );
+        if let Some(color_state) = report.color_state {
+            conditions.push(self.color(color_state, None));
+        }
+        self.prefixed_sentence(prefix, conditions)
+    }
+
+    fn remarks(&self, remarks: &RemarkSection) -> Option<String> {
+        let mut phrases = vec![];
+        if let Some(station_type) = remarks.station_type {
+            phrases.push(self.say(Message::StationType(station_type)));
+        }
+        if let Some(peak_wind) = remarks.peak_wind {
+            phrases.push(self.say(Message::PeakWind {
+                direction: &self.angle(peak_wind.direction),
+                speed: &self.speed(peak_wind.speed),
+                time: &self.time_in_hour(peak_wind.hour, peak_wind.minute),
+            }));
+        }
+        if let Some(wind_shift) = remarks.wind_shift {
+            phrases.push(self.say(Message::WindShift {
+                time: &self.time_in_hour(wind_shift.hour, wind_shift.minute),
+                frontal_passage: wind_shift.frontal_passage,
+            }));
+        }
+        if let Some(pressure_change) = remarks.pressure_change {
+            phrases.push(self.say(Message::PressureChange(pressure_change)));
+        }
+        if let Some(sea_level_pressure) = remarks.sea_level_pressure {
+            let sea_level_pressure = self.quantity(
+                sea_level_pressure.get::<hectopascal>(),
+                Unit::Pressure(PressureUnit::Hectopascal),
+                1,
+            );
+            phrases.push(self.say(Message::SeaLevelPressure(&sea_level_pressure)));
+        }
+        if let Some(amount) = remarks.hourly_precipitation {
+            phrases.push(self.say(Message::HourlyPrecipitation(&self.precipitation(amount))));
+        }
+        if let Some(amount) = remarks.precipitation_3_or_6_hour {
+            phrases.push(self.say(Message::Precipitation3Or6Hour(&self.precipitation(amount))));
+        }
+        if let Some(amount) = remarks.precipitation_24_hour {
+            phrases.push(self.say(Message::Precipitation24Hour(&self.precipitation(amount))));
+        }
+        if let Some(maximum) = remarks.maximum_temperature_6_hour {
+            phrases.push(self.say(Message::MaximumTemperature6Hour(
+                &self.temperature(maximum, 1),
+            )));
+        }
+        if let Some(minimum) = remarks.minimum_temperature_6_hour {
+            phrases.push(self.say(Message::MinimumTemperature6Hour(
+                &self.temperature(minimum, 1),
+            )));
+        }
+        if let Some(extremes) = remarks.temperature_extremes_24_hour {
+            phrases.push(self.say(Message::TemperatureExtremes24Hour {
+                maximum: &self.temperature(extremes.maximum, 1),
+                minimum: &self.temperature(extremes.minimum, 1),
+            }));
+        }
+        if let Some(tendency) = remarks.pressure_tendency {
+            let change = self.pressure_change(tendency.change);
+            phrases.push(self.say(Message::PressureTendency {
+                characteristic: tendency.characteristic,
+                change: &change,
+            }));
+        }
+        for sensor_status in &remarks.sensor_status {
+            phrases.push(self.say(Message::SensorNotAvailable(*sensor_status)));
+        }
+        if !remarks.unparsed.is_empty() {
+            phrases.push(self.say(Message::UnparsedRemarks(&remarks.unparsed.join(" "))));
+        }
+        self.prefixed_sentence(vec![self.say(Message::Remarks)], phrases)
+    }
+
+    /// Capitalizes and punctuates phrases as a sentence
+    fn sentence(&self, phrases: Vec<String>) -> Option<String> {
+        if phrases.is_empty() {
+            return None;
+        }
+        let sentence = phrases.join(", ");
+        let mut chars = sentence.chars();
+        let first = chars.next()?;
+        Some(format!(
+            "{}{}.",
+            first.to_uppercase().collect::<String>(),
+            chars.as_str()
+        ))
+    }
+
+    /// Sentence introduced by a prefix, i.e. `Becoming from 14:00 UTC: ...`
+    fn prefixed_sentence(&self, prefix: Vec<String>, phrases: Vec<String>) -> Option<String> {
+        if phrases.is_empty() {
+            return None;
+        }
+        self.sentence(vec![format!(
+            "{}: {}",
+            prefix.join(" "),
+            phrases.join(", ")
+        )])
+    }
+
+    fn time(&self, time: ZuluTime) -> String {
+        format!("{:02}:{:02} UTC", time.hour, time.minute)
+    }
+
+    fn time_in_hour(&self, hour: Option<u8>, minute: u8) -> String {
+        match hour {
+            Some(hour) => self.say(Message::AtTime(&self.time(ZuluTime { hour, minute }))),
+            None => self.say(Message::MinutesPastTheHour(minute)),
+        }
+    }
+
+    fn angle(&self, angle: Angle) -> String {
+        let degrees = angle.get::<degree>().round() as i64;
+        format!(
+            "{:03}{}",
+            degrees,
+            self.catalog.unit(Unit::Degree, degrees as f64)
+        )
+    }
+
+    fn speed(&self, speed: Velocity) -> String {
+        let value = match self.units.speed {
+            SpeedUnit::Knot => speed.get::<knot>(),
+            SpeedUnit::MeterPerSecond => speed.get::<meter_per_second>(),
+            SpeedUnit::KilometerPerHour => speed.get::<kilometer_per_hour>(),
+        };
+        self.quantity(value, Unit::Speed(self.units.speed), 0)
+    }
+
+    fn length(&self, length: Length, unit: LengthUnit, decimals: usize) -> String {
+        let value = match unit {
+            LengthUnit::Millimeter => length.get::<millimeter>(),
+            LengthUnit::Centimeter => length.get::<centimeter>(),
+            LengthUnit::Meter => length.get::<meter>(),
+            LengthUnit::Kilometer => length.get::<kilometer>(),
+            LengthUnit::Inch => length.get::<inch>(),
+            LengthUnit::Foot => length.get::<foot>(),
+            LengthUnit::StatuteMile => {
+                let miles = length.get::<mile>();
+                if let Some(fraction) = fraction(miles) {
+                    return format!(
+                        "{} {}",
+                        fraction,
+                        self.catalog.unit(Unit::Length(unit), miles)
+                    );
+                }
+                miles
+            }
+        };
+        self.quantity(
+            value,
+            Unit::Length(unit),
+            significant_decimals(value, decimals),
+        )
+    }
+
+    fn precipitation(&self, amount: Length) -> String {
+        self.length(amount, self.units.precipitation, 2)
+    }
+
+    fn pressure(&self, pressure: Pressure) -> String {
+        match self.units.pressure {
+            PressureUnit::Hectopascal => self.quantity(
+                pressure.get::<hectopascal>(),
+                Unit::Pressure(PressureUnit::Hectopascal),
+                0,
+            ),
+            PressureUnit::InchOfMercury => self.quantity(
+                pressure.get::<inch_of_mercury>(),
+                Unit::Pressure(PressureUnit::InchOfMercury),
+                2,
+            ),
+        }
+    }
+
+    /// Pressure differences are always small enough to need a decimal
+    fn pressure_change(&self, change: Pressure) -> String {
+        let sign = if change.value < 0. { "-" } else { "+" };
+        let (value, decimals) = match self.units.pressure {
+            PressureUnit::Hectopascal => (change.get::<hectopascal>(), 1),
+            PressureUnit::InchOfMercury => (change.get::<inch_of_mercury>(), 2),
+        };
+        format!(
+            "{}{}",
+            sign,
+            self.quantity(value.abs(), Unit::Pressure(self.units.pressure), decimals)
+        )
+    }
+
+    fn temperature(&self, temperature: ThermodynamicTemperature, decimals: usize) -> String {
+        let value = match self.units.temperature {
+            TemperatureUnit::Celsius => temperature.get::<degree_celsius>(),
+            TemperatureUnit::Fahrenheit => temperature.get::<degree_fahrenheit>(),
+        };
+        self.quantity(value, Unit::Temperature(self.units.temperature), decimals)
+    }
+
+    fn quantity(&self, value: f64, unit: Unit, decimals: usize) -> String {
+        format!(
+            "{} {}",
+            self.number(value, decimals),
+            self.catalog.unit(unit, value)
+        )
+    }
+
+    fn number(&self, value: f64, decimals: usize) -> String {
+        let formatted = format!("{:.*}", decimals, value.abs());
+        let (integer, fraction) = formatted
+            .split_once('.')
+            .unwrap_or((formatted.as_str(), ""));
+        let mut number = String::new();
+        if value < 0. && formatted.bytes().any(|b| matches!(b, b'1'..=b'9')) {
+            number.push('-');
+        }
+        for (i, digit) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 && integer.len() > 3 {
+                number.push(self.catalog.thousands_separator());
+            }
+            number.push(digit);
+        }
+        if !fraction.is_empty() {
+            number.push(self.catalog.decimal_separator());
+            number.push_str(fraction);
+        }
+        number
+    }
+}
+
+/// Fewest decimals, up to `maximum`, that represent the value rounded to `maximum` decimals
+fn significant_decimals(value: f64, maximum: usize) -> usize {
+    let rounded = format!("{:.*}", maximum, value);
+    rounded
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.trim_end_matches('0').len())
+}
+
+/// Whole number and/or fraction in halves, quarters, eighths or sixteenths, i.e. `1 1/2`
+fn fraction(value: f64) -> Option<String> {
+    let whole = value.floor();
+    [1, 2, 4, 8, 16].iter().find_map(|denominator| {
+        let numerator = crate::metar::encode::whole((value - whole) * *denominator as f64)?;
+        Some(match (whole as i64, numerator) {
+            (whole, 0) => whole.to_string(),
+            (0, numerator) => format!("{}/{}", numerator, denominator),
+            (whole, numerator) => format!("{} {}/{}", whole, numerator, denominator),
+        })
+    })
+}
+
+/// Appends the station name when the `stations` feature knows it
+fn station_name(identifier: &str) -> String {
+    #[cfg(feature = "stations")]
+    if let Some(station) = crate::stations::station(identifier) {
+        return format!("{} ({})", identifier, station.name);
+    }
+    identifier.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metar::parse::metar;
+
+    #[test]
+    fn describe_in_english() {
+        let report = metar("KSEA 290353Z 27015G25KT 10SM -RA BKN025 19/09 A3004").unwrap();
+        let description = report.describe(&English);
+        assert!(
+            description.contains("Wind from 270° at 15 knots gusting 25 knots, visibility 10 statute miles, light rain, broken clouds at 2,500 ft, temperature 19 °C, dewpoint 9 °C, altimeter 30.04 inHg."),
+            "{}",
+            description
+        );
+        assert!(
+            description.starts_with("Report from KSEA"),
+            "{}",
+            description
+        );
+    }
+
+    #[test]
+    fn describe_every_element() {
+        let report = metar("EGLL 251450Z AUTO 27015KT 210V330 9999 1500N R27L/0600V1000U +TSRA VCSH FEW020CB 14/09 Q1002 RERA WS R27L BLACKBLU R27L/290155 W15/S3 TEMPO FM1600 3000 SHRA BKN008 AMB NOSIG RMK LAST OBS").unwrap();
+        let description = report.describe(&English);
+        for phrase in [
+            "automated observation",
+            "varying between 210° and 330°",
+            "visibility at least 10 km",
+            "minimum visibility 1,500 m to the north",
+            "visual range on runway 27L between 600 m and 1,000 m (increasing)",
+            "heavy thunderstorm with rain",
+            "showers in the vicinity",
+            "few clouds at 2,000 ft (cumulonimbus)",
+            "QNH 1,002 hPa",
+            "Recent rain, wind shear on runway 27L",
+            "runway 27L: wet, 51 to 100% covered, 1 mm deep and friction coefficient 0.55",
+            "airfield closed, color state blue",
+            "water temperature 15 °C, slight sea",
+            "Temporarily from 16:00 UTC: visibility 3,000 m, rain showers, broken clouds at 800 ft, color state amber.",
+            "Remarks: LAST OBS.",
+        ] {
+            assert!(description.contains(phrase), "{} in {}", phrase, description);
+        }
+    }
+
+    #[test]
+    fn describe_in_french_and_spanish() {
+        let report = metar("LFPG 251430Z 27015G25KT 9999 -RA BKN025 19/09 Q1013").unwrap();
+        let description = report.describe(&French);
+        assert!(
+            description.contains("Vent du 270° à 15 nœuds avec rafales à 25 nœuds, visibilité supérieure ou égale à 10 km, pluie faible, nuages fragmentés à 2\u{a0}500 ft"),
+            "{}",
+            description
+        );
+        let description = report.describe(&Spanish);
+        assert!(
+            description.contains("Viento de 270° a 15 nudos con rachas de 25 nudos, visibilidad igual o superior a 10 km, lluvia débil, nubes fragmentadas a 2.500 ft"),
+            "{}",
+            description
+        );
+        assert_eq!(
+            metar("LFLL 251430Z 00000KT CAVOK 19/09 Q1013 NOSIG")
+                .unwrap()
+                .describe(catalog("fr-FR").unwrap()),
+            "Message de LFLL, observé le 25 à 14:30 UTC. Vent calme, plafond et visibilité OK, température 19 °C, point de rosée 9 °C, QNH 1\u{a0}013 hPa. Pas de changement significatif prévu."
+        );
+    }
+
+    #[test]
+    fn describe_remarks() {
+        let report = metar("KSEA 290353Z 01008KT 1/2SM FG VV002 M01/M01 A2992 RMK AO2 PK WND 28032/0315 SLP013 P0012 T10061011 56033 PNO").unwrap();
+        let description = report.describe_with_units(&English, &Units::us());
+        for phrase in [
+            "visibility 1/2 statute mile",
+            "vertical visibility 200 ft",
+            "temperature -0.6 °C, dewpoint -1.1 °C",
+            "automated station with precipitation discriminator",
+            "peak wind from 280° at 32 knots at 03:15 UTC",
+            "sea level pressure 1,001.3 hPa",
+            "0.12 in of precipitation in the past hour",
+            "pressure decreasing then steady, -0.10 inHg over 3 hours",
+            "precipitation amount not available",
+        ] {
+            assert!(
+                description.contains(phrase),
+                "{} in {}",
+                phrase,
+                description
+            );
+        }
+
+        let report = metar(
+            "KSEA 290353Z 01008KT 10SM FEW020 19/09 A3004 RMK AO2 PK WND 28032/15 WSHFT 30 FROPA",
+        )
+        .unwrap();
+        let description = report.describe(&English);
+        for phrase in [
+            "peak wind from 280° at 32 knots at 15 minutes past the hour",
+            "wind shift at 30 minutes past the hour due to a frontal passage",
+        ] {
+            assert!(
+                description.contains(phrase),
+                "{} in {}",
+                phrase,
+                description
+            );
+        }
+    }
+
+    #[test]
+    fn catalog_lookup() {
+        assert!(catalog("es_ES").is_some());
+        assert!(catalog("EN").is_some());
+        assert!(catalog("de").is_none());
+        assert!(catalog("").is_none());
+    }
+
+    #[test]
+    fn units_for_station() {
+        // Known to the stations feature or not, territories use the same units as the US
+        for identifier in ["KSEA", "PANC", "PHNL", "PGUM", "TJSJ", "TIST"] {
+            assert_eq!(
+                Units::for_station(identifier),
+                Units::us(),
+                "{}",
+                identifier
+            );
+        }
+        // Marshall Islands, Palau and the UK
+        for identifier in ["PKMJ", "PTRO", "EGLL"] {
+            assert_eq!(
+                Units::for_station(identifier),
+                Units::metric(),
+                "{}",
+                identifier
+            );
+        }
+
+        for country in ["US", "GU", "PR", "VI", "AS", "MP"] {
+            assert_eq!(Units::for_country(country), Units::us(), "{}", country);
+        }
+        for country in ["GB", "MH", "PW", "WS"] {
+            assert_eq!(Units::for_country(country), Units::metric(), "{}", country);
+        }
+    }
+}
@@ -0,0 +1,478 @@
+use super::{LengthUnit, Message, MessageCatalog, PressureUnit, SpeedUnit, TemperatureUnit, Unit};
+use crate::tokens::*;
+
+/// Spanish texts
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Spanish;
+
+impl MessageCatalog for Spanish {
+    fn message(&self, message: Message<'_>) -> String {
+        use Message::*;
+        match message {
+            Station(station) => format!("informe de {}", station),
+            ObservationTime { day_of_month, time } => {
+                format!("observado el día {} a las {}", day_of_month, time)
+            }
+            ValidityRange { begin, end } => format!("válido de {} a {}", begin, end),
+            ObservationFlag(flag) => observation_flag(flag).into(),
+            MaintenanceNeeded => "la estación requiere mantenimiento".into(),
+
+            Calm => "viento en calma".into(),
+            Wind {
+                direction,
+                speed,
+                gust,
+            } => {
+                let mut wind = match direction {
+                    Some(direction) => format!("viento de {} a {}", direction, speed),
+                    None => format!("viento variable a {}", speed),
+                };
+                if let Some(gust) = gust {
+                    wind.push_str(&format!(" con rachas de {}", gust));
+                }
+                wind
+            }
+            WindNotReported => "viento no informado".into(),
+            WindVariance { from, to } => format!("variando entre {} y {}", from, to),
+
+            Cavok => "techo y visibilidad OK".into(),
+            Visibility(distance) => format!("visibilidad {}", distance),
+            DirectionalVisibility {
+                minimum,
+                direction,
+                distance,
+            } => format!(
+                "visibilidad {} {} hacia {}",
+                if minimum { "mínima" } else { "máxima" },
+                distance,
+                direction
+            ),
+            CompassDirection(direction) => compass_direction(direction).into(),
+            MoreThan(quantity) => format!("superior a {}", quantity),
+            LessThan(quantity) => format!("inferior a {}", quantity),
+            AtLeast(quantity) => format!("igual o superior a {}", quantity),
+            Between { lower, upper } => format!("entre {} y {}", lower, upper),
+            RunwayVisualRange {
+                runway,
+                range,
+                trend,
+            } => match trend {
+                Some(trend) => format!("alcance visual en {}: {} ({})", runway, range, trend),
+                None => format!("alcance visual en {}: {}", runway, range),
+            },
+            VisibilityTrend(trend) => visibility_trend(trend).into(),
+
+            Runway(designator) => format!("la pista {}", designator),
+            AllRunways => "todas las pistas".into(),
+            PreviousRunwayReport => "pistas según el informe anterior".into(),
+            RunwayState { runway, state } => format!("{}: {}", runway, state),
+            RunwayStateNotReported => "estado no informado".into(),
+            RunwayCleared => "despejada".into(),
+            RunwayDeposit(deposit) => runway_deposit(deposit).into(),
+            RunwayContaminationExtent(extent) => contamination_extent(extent).into(),
+            DepositDepth(depth) => format!("espesor {}", depth),
+            RunwayNotOperational => "fuera de servicio".into(),
+            FrictionCoefficient(coefficient) => {
+                format!("coeficiente de rozamiento {}", coefficient)
+            }
+            BrakingAction(action) => format!("eficacia de frenado {}", braking_action(action)),
+            FrictionUnreliable => "rozamiento no fiable".into(),
+            ClosedBySnow => "aeródromo cerrado por nieve".into(),
+
+            Weather {
+                intensity,
+                descriptor,
+                phenomena,
+                vicinity,
+            } => {
+                let mut weather = weather(descriptor, phenomena);
+                match intensity {
+                    Intensity::Light => weather.push_str(" débil"),
+                    Intensity::Heavy => weather.push_str(" fuerte"),
+                    Intensity::Moderate => {}
+                }
+                if vicinity {
+                    weather.push_str(" en las proximidades");
+                }
+                weather
+            }
+            Precipitation(kind) => precipitation(kind).into(),
+            Obscuration(kind) => obscuration(kind).into(),
+            OtherPhenomenon(other) => other_phenomenon(other).into(),
+            RecentWeather(weather) => format!("tiempo reciente: {}", weather),
+
+            Clouds {
+                coverage,
+                base,
+                cloud_type,
+            } => {
+                let mut clouds = match (coverage, base) {
+                    (CloudCoverage::NoCloud, _) => "cielo despejado".to_string(),
+                    (CloudCoverage::NilCloud, _) => "no se detectan nubes".to_string(),
+                    (CloudCoverage::Clear, _) => "sin nubes por debajo de 12.000 ft".to_string(),
+                    (CloudCoverage::NoSignificantCloud, _) => {
+                        "sin nubes significativas".to_string()
+                    }
+                    (CloudCoverage::VerticalVisibility, Some(base)) => {
+                        format!("visibilidad vertical {}", base)
+                    }
+                    (CloudCoverage::VerticalVisibility, None) => "cielo oscurecido".to_string(),
+                    (coverage, base) => {
+                        let coverage = match coverage {
+                            CloudCoverage::Few => "nubes escasas",
+                            CloudCoverage::Scattered => "nubes dispersas",
+                            CloudCoverage::Broken => "nubes fragmentadas",
+                            _ => "cielo cubierto",
+                        };
+                        match base {
+                            Some(base) => format!("{} a {}", coverage, base),
+                            None => coverage.to_string(),
+                        }
+                    }
+                };
+                if let Some(cloud_type) = cloud_type {
+                    clouds.push_str(&format!(" ({})", cloud_type));
+                }
+                clouds
+            }
+            CloudType(kind) => cloud_type(kind).into(),
+
+            Temperature(temperature) => format!("temperatura {}", temperature),
+            Dewpoint(dewpoint) => format!("punto de rocío {}", dewpoint),
+            Pressure {
+                pressure,
+                unit: PressureUnit::Hectopascal,
+            } => format!("QNH {}", pressure),
+            Pressure { pressure, .. } => format!("reglaje altimétrico {}", pressure),
+            AccumulatedRainfall { recent, past } => format!(
+                "lluvia acumulada de {} en los últimos 10 minutos y de {} desde las 09:00 hora local",
+                recent, past
+            ),
+            AirfieldClosed => "aeródromo cerrado".into(),
+            Color { current, next } => match next {
+                Some(next) => format!("estado de color {} pasando a {}", current, next),
+                None => format!("estado de color {}", current),
+            },
+            ColorState(state) => color_state(state).into(),
+            WindShear(runway) => format!("cizalladura del viento en {}", runway),
+            LowLevelWindShear { height, wind } => {
+                format!("cizalladura del viento hasta {} con {}", height, wind)
+            }
+            WaterTemperature(temperature) => format!("temperatura del agua {}", temperature),
+            WaterSurfaceState(state) => water_surface_state(state).into(),
+            WaveHeight(height) => format!("altura significativa de las olas {}", height),
+
+            NoSignificantChange => "sin cambios significativos previstos".into(),
+            Becoming => "evolucionando".into(),
+            Temporarily => "temporalmente".into(),
+            TrendTime { time_type, time } => match time_type {
+                TrendTimeType::At => format!("a las {}", time),
+                TrendTimeType::From => format!("desde las {}", time),
+                TrendTimeType::Until => format!("hasta las {}", time),
+            },
+
+            Remarks => "observaciones".into(),
+            StationType(kind) => station_type(kind).into(),
+            PeakWind {
+                direction,
+                speed,
+                time,
+            } => format!("viento máximo de {} a {} {}", direction, speed, time),
+            AtTime(time) => format!("a las {}", time),
+            MinutesPastTheHour(minute) => format!("en el minuto {} de la hora", minute),
+            WindShift {
+                time,
+                frontal_passage,
+            } => {
+                if frontal_passage {
+                    format!(
+                        "cambio de dirección del viento {} debido al paso de un frente",
+                        time
+                    )
+                } else {
+                    format!("cambio de dirección del viento {}", time)
+                }
+            }
+            PressureChange(change) => pressure_change(change).into(),
+            SeaLevelPressure(pressure) => format!("presión al nivel del mar {}", pressure),
+            HourlyPrecipitation(amount) => {
+                format!("{} de precipitación en la última hora", amount)
+            }
+            Precipitation3Or6Hour(amount) => {
+                format!("{} de precipitación en las últimas 3 o 6 horas", amount)
+            }
+            Precipitation24Hour(amount) => {
+                format!("{} de precipitación en las últimas 24 horas", amount)
+            }
+            MaximumTemperature6Hour(temperature) => {
+                format!("temperatura máxima en 6 horas {}", temperature)
+            }
+            MinimumTemperature6Hour(temperature) => {
+                format!("temperatura mínima en 6 horas {}", temperature)
+            }
+            TemperatureExtremes24Hour { maximum, minimum } => format!(
+                "temperatura máxima en 24 horas {} y mínima {}",
+                maximum, minimum
+            ),
+            PressureTendency {
+                characteristic,
+                change,
+            } => format!(
+                "presión {}, {} en 3 horas",
+                pressure_tendency(characteristic),
+                change
+            ),
+            SensorNotAvailable(sensor) => sensor_status(sensor).into(),
+            UnparsedRemarks(remarks) => remarks.into(),
+        }
+    }
+
+    fn unit(&self, unit: Unit, value: f64) -> &'static str {
+        let singular = (value - 1.).abs() < f64::EPSILON;
+        match unit {
+            Unit::Degree => "°",
+            Unit::Speed(SpeedUnit::Knot) if singular => "nudo",
+            Unit::Speed(SpeedUnit::Knot) => "nudos",
+            Unit::Speed(SpeedUnit::MeterPerSecond) => "m/s",
+            Unit::Speed(SpeedUnit::KilometerPerHour) => "km/h",
+            Unit::Length(LengthUnit::Millimeter) => "mm",
+            Unit::Length(LengthUnit::Centimeter) => "cm",
+            Unit::Length(LengthUnit::Meter) => "m",
+            Unit::Length(LengthUnit::Kilometer) => "km",
+            Unit::Length(LengthUnit::Inch) => "pulg",
+            Unit::Length(LengthUnit::Foot) => "ft",
+            // Fractions of a mile are singular, i.e. 1/2 milla terrestre
+            Unit::Length(LengthUnit::StatuteMile) if singular || value < 1. => "milla terrestre",
+            Unit::Length(LengthUnit::StatuteMile) => "millas terrestres",
+            Unit::Pressure(PressureUnit::Hectopascal) => "hPa",
+            Unit::Pressure(PressureUnit::InchOfMercury) => "inHg",
+            Unit::Temperature(TemperatureUnit::Celsius) => "°C",
+            Unit::Temperature(TemperatureUnit::Fahrenheit) => "°F",
+        }
+    }
+
+    fn list(&self, items: &[String]) -> String {
+        match items {
+            [] => String::new(),
+            [item] => item.clone(),
+            [init @ .., last] => format!("{} y {}", init.join(", "), last),
+        }
+    }
+
+    fn decimal_separator(&self) -> char {
+        ','
+    }
+
+    fn thousands_separator(&self) -> char {
+        '.'
+    }
+}
+
+fn observation_flag(flag: ObservationFlag) -> &'static str {
+    match flag {
+        ObservationFlag::Auto => "observación automática",
+        ObservationFlag::Nil => "informe ausente",
+        ObservationFlag::Correction => "informe corregido",
+        ObservationFlag::CorrectionA => "primera corrección",
+        ObservationFlag::CorrectionB => "segunda corrección",
+        ObservationFlag::CorrectionC => "tercera corrección",
+        ObservationFlag::Delayed => "informe retrasado",
+    }
+}
+
+/// Includes the article, i.e. `el este`
+fn compass_direction(direction: CompassDirection) -> &'static str {
+    match direction {
+        CompassDirection::NorthEast => "el noreste",
+        CompassDirection::NorthWest => "el noroeste",
+        CompassDirection::North => "el norte",
+        CompassDirection::SouthEast => "el sureste",
+        CompassDirection::SouthWest => "el suroeste",
+        CompassDirection::South => "el sur",
+        CompassDirection::East => "el este",
+        CompassDirection::West => "el oeste",
+    }
+}
+
+/// Adjectives agree with `pista`
+fn runway_deposit(deposit: RunwayDeposit) -> &'static str {
+    match deposit {
+        RunwayDeposit::ClearAndDry => "despejada y seca",
+        RunwayDeposit::Damp => "húmeda",
+        RunwayDeposit::Wet => "mojada",
+        RunwayDeposit::RimeOrFrost => "escarcha",
+        RunwayDeposit::DrySnow => "nieve seca",
+        RunwayDeposit::WetSnow => "nieve húmeda",
+        RunwayDeposit::Slush => "nieve fundente",
+        RunwayDeposit::Ice => "hielo",
+        RunwayDeposit::CompactedSnow => "nieve compactada",
+        RunwayDeposit::FrozenRuts => "surcos helados",
+    }
+}
+
+fn weather(descriptor: Option<Descriptor>, phenomena: &str) -> String {
+    match (descriptor, phenomena) {
+        (Some(Descriptor::Showers), "") => "chubascos".into(),
+        (Some(Descriptor::Thunderstorm), "") => "tormenta".into(),
+        (Some(Descriptor::Showers), phenomena) => format!("chubascos de {}", phenomena),
+        (Some(Descriptor::Thunderstorm), phenomena) => format!("tormenta con {}", phenomena),
+        (Some(Descriptor::Shallow), phenomena) => format!("{} baja", phenomena),
+        (Some(Descriptor::Partial), phenomena) => format!("{} parcial", phenomena),
+        (Some(Descriptor::Patches), phenomena) => format!("bancos de {}", phenomena),
+        (Some(Descriptor::LowDrifting), phenomena) => format!("ventisca baja de {}", phenomena),
+        (Some(Descriptor::Blowing), phenomena) => format!("ventisca alta de {}", phenomena),
+        (Some(Descriptor::Freezing), phenomena) => format!("{} engelante", phenomena),
+        (None, phenomena) => phenomena.into(),
+    }
+}
+
+fn pressure_tendency(characteristic: PressureTendencyCharacteristic) -> &'static str {
+    use PressureTendencyCharacteristic::*;
+    match characteristic {
+        IncreasingThenDecreasing => "en aumento y después en descenso",
+        IncreasingThenSteady => "en aumento y después estable",
+        Increasing => "en aumento",
+        DecreasingOrSteadyThenIncreasing => "en descenso o estable y después en aumento",
+        Steady => "estable",
+        DecreasingThenIncreasing => "en descenso y después en aumento",
+        DecreasingThenSteady => "en descenso y después estable",
+        Decreasing => "en descenso",
+        SteadyOrIncreasingThenDecreasing => "estable o en aumento y después en descenso",
+    }
+}
+
+fn visibility_trend(trend: VisibilityTrend) -> &'static str {
+    match trend {
+        VisibilityTrend::Up => "en aumento",
+        VisibilityTrend::Down => "en disminución",
+        VisibilityTrend::NoChange => "sin cambios",
+    }
+}
+
+fn contamination_extent(extent: RunwayContaminationExtent) -> &'static str {
+    match extent {
+        RunwayContaminationExtent::TenPercentOrLess => "cubierta en un 10 % o menos",
+        RunwayContaminationExtent::ElevenToTwentyFivePercent => "cubierta del 11 al 25 %",
+        RunwayContaminationExtent::TwentySixToFiftyPercent => "cubierta del 26 al 50 %",
+        RunwayContaminationExtent::FiftyOneToOneHundredPercent => "cubierta del 51 al 100 %",
+    }
+}
+
+fn precipitation(precipitation: Precipitation) -> &'static str {
+    match precipitation {
+        Precipitation::Rain => "lluvia",
+        Precipitation::Drizzle => "llovizna",
+        Precipitation::Snow => "nieve",
+        Precipitation::SnowGrains => "cinarra",
+        Precipitation::IceCrystals => "cristales de hielo",
+        Precipitation::IcePellets => "hielo granulado",
+        Precipitation::Hail => "granizo",
+        Precipitation::Graupel => "granizo pequeño",
+        Precipitation::Unknown => "precipitación desconocida",
+    }
+}
+
+fn obscuration(obscuration: Obscuration) -> &'static str {
+    match obscuration {
+        Obscuration::Fog => "niebla",
+        Obscuration::Mist => "neblina",
+        Obscuration::Haze => "calima",
+        Obscuration::VolcanicAsh => "ceniza volcánica",
+        Obscuration::WidespreadDust => "polvo extendido",
+        Obscuration::Smoke => "humo",
+        Obscuration::Sand => "arena",
+        Obscuration::Spray => "rociones",
+    }
+}
+
+fn other_phenomenon(other: Other) -> &'static str {
+    match other {
+        Other::Squall => "turbonadas",
+        Other::SandWhirls => "remolinos de polvo",
+        Other::Duststorm => "tempestad de polvo",
+        Other::Sandstorm => "tempestad de arena",
+        Other::FunnelCloud => "nube embudo",
+    }
+}
+
+fn cloud_type(cloud_type: CloudType) -> &'static str {
+    match cloud_type {
+        CloudType::Cumulonimbus => "cumulonimbus",
+        CloudType::ToweringCumulus => "cúmulos en torre",
+        CloudType::Cumulus => "cúmulos",
+        CloudType::Cirrus => "cirros",
+        CloudType::Altocumulus => "altocúmulos",
+        CloudType::Stratus => "estratos",
+    }
+}
+
+fn color_state(color_state: ColorState) -> &'static str {
+    match color_state {
+        ColorState::BluePlus => "azul+",
+        ColorState::Blue => "azul",
+        ColorState::White => "blanco",
+        ColorState::Green => "verde",
+        ColorState::YellowOne => "amarillo 1",
+        ColorState::YellowTwo => "amarillo 2",
+        ColorState::Amber => "ámbar",
+        ColorState::Red => "rojo",
+    }
+}
+
+fn water_surface_state(state: WaterSurfaceState) -> &'static str {
+    match state {
+        WaterSurfaceState::GlassyCalm => "mar en calma como un espejo",
+        WaterSurfaceState::RippledCalm => "mar en calma rizada",
+        WaterSurfaceState::Smooth => "mar rizada",
+        WaterSurfaceState::Slight => "marejadilla",
+        WaterSurfaceState::Moderate => "marejada",
+        WaterSurfaceState::Rough => "fuerte marejada",
+        WaterSurfaceState::VeryRough => "mar gruesa",
+        WaterSurfaceState::High => "mar muy gruesa",
+        WaterSurfaceState::VeryHigh => "mar arbolada",
+        WaterSurfaceState::Phenomenal => "mar montañosa",
+    }
+}
+
+fn station_type(station_type: AutomatedStationType) -> &'static str {
+    match station_type {
+        AutomatedStationType::WithoutPrecipitationDiscriminator => {
+            "estación automática sin discriminador de precipitación"
+        }
+        AutomatedStationType::WithPrecipitationDiscriminator => {
+            "estación automática con discriminador de precipitación"
+        }
+    }
+}
+
+fn pressure_change(change: PressureChange) -> &'static str {
+    match change {
+        PressureChange::RisingRapidly => "presión subiendo rápidamente",
+        PressureChange::FallingRapidly => "presión bajando rápidamente",
+    }
+}
+
+fn sensor_status(sensor: SensorStatus) -> &'static str {
+    match sensor {
+        SensorStatus::PresentWeatherIdentifierNotAvailable => {
+            "identificador de tiempo presente no disponible"
+        }
+        SensorStatus::PrecipitationAmountNotAvailable => "cantidad de precipitación no disponible",
+        SensorStatus::FreezingRainNotAvailable => "sensor de lluvia engelante no disponible",
+        SensorStatus::LightningNotAvailable => "detector de rayos no disponible",
+        SensorStatus::RunwayVisualRangeNotAvailable => "alcance visual en pista no disponible",
+        SensorStatus::VisibilityNotAvailable => "visibilidad secundaria no disponible",
+        SensorStatus::CeilingNotAvailable => "techo secundario no disponible",
+        SensorStatus::SeaLevelPressureNotAvailable => "presión al nivel del mar no disponible",
+    }
+}
+
+fn braking_action(braking_action: BrakingAction) -> &'static str {
+    match braking_action {
+        BrakingAction::Poor => "mala",
+        BrakingAction::MediumToPoor => "mediana a mala",
+        BrakingAction::Medium => "mediana",
+        BrakingAction::MediumToGood => "mediana a buena",
+        BrakingAction::Good => "buena",
+    }
+}
@@ -0,0 +1,348 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::Range;
+
+use crate::metar::parse::parser::weather_reports;
+
+/// The group of a report that could not be parsed
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum ParseErrorKind {
+    ObservationTime,
+    Wind,
+    Visibility,
+    RunwayVisibility,
+    RunwayReport,
+    Weather,
+    CloudCover,
+    Temperatures,
+    Pressure,
+    Color,
+    WaterConditions,
+    Trend,
+    /// TAF validity period or change group period
+    ValidityPeriod,
+    /// TAF change indicator, i.e. `FM`, `BECMG`, `TEMPO` or `PROB30`
+    ChangeIndicator,
+    /// TAF maximum or minimum temperature forecast
+    TemperatureForecast,
+    /// WMO bulletin abbreviated heading, i.e. `SAUS70 KWBC 251400`
+    BulletinHeading,
+    /// SYNOP group, i.e. `10094` or `333`
+    SynopGroup,
+    /// PIREP field or AIREP group, i.e. `/TB MOD` or `F350`
+    PilotReport,
+    /// SIGMET or AIRMET group, i.e. `WI N4500 W07000` or `MOV E 20KT`
+    Advisory,
+    /// The report ended before all required groups were found
+    UnexpectedEnd,
+    /// The token does not resemble any known group
+    Unrecognized,
+}
+
+impl ParseErrorKind {
+    /// A stable identifier for this kind of error, suitable for aggregating failures
+    pub fn code(&self) -> &'static str {
+        use ParseErrorKind::*;
+        match self {
+            ObservationTime => "E0001",
+            Wind => "E0002",
+            Visibility => "E0003",
+            RunwayVisibility => "E0004",
+            RunwayReport => "E0005",
+            Weather => "E0006",
+            CloudCover => "E0007",
+            Temperatures => "E0008",
+            Pressure => "E0009",
+            Color => "E0010",
+            WaterConditions => "E0011",
+            Trend => "E0012",
+            ValidityPeriod => "E0013",
+            ChangeIndicator => "E0014",
+            TemperatureForecast => "E0015",
+            BulletinHeading => "E0016",
+            SynopGroup => "E0017",
+            PilotReport => "E0018",
+            Advisory => "E0019",
+            UnexpectedEnd => "E0098",
+            Unrecognized => "E0099",
+        }
+    }
+
+    /// Short human-readable name of the group, i.e. `cloud cover`
+    pub fn description(&self) -> &'static str {
+        use ParseErrorKind::*;
+        match self {
+            ObservationTime => "observation time",
+            Wind => "wind",
+            Visibility => "visibility",
+            RunwayVisibility => "runway visibility",
+            RunwayReport => "runway report",
+            Weather => "weather",
+            CloudCover => "cloud cover",
+            Temperatures => "temperatures",
+            Pressure => "pressure",
+            Color => "color state",
+            WaterConditions => "water conditions",
+            Trend => "trend",
+            ValidityPeriod => "validity period",
+            ChangeIndicator => "change indicator",
+            TemperatureForecast => "temperature forecast",
+            BulletinHeading => "bulletin heading",
+            SynopGroup => "SYNOP group",
+            PilotReport => "pilot report group",
+            Advisory => "SIGMET or AIRMET group",
+            UnexpectedEnd => "end of report",
+            Unrecognized => "group",
+        }
+    }
+}
+
+/// Error returned when a report could not be parsed
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    /// Byte range of the offending token in the input
+    pub span: Range<usize>,
+    /// The offending token
+    pub token: String,
+    /// Descriptions of what the parser would have accepted at the point of failure, sorted
+    pub expected: Vec<String>,
+}
+
+impl ParseError {
+    pub fn code(&self) -> &'static str {
+        self.kind.code()
+    }
+
+    /// Builds an error from the raw parser error, guessing which group the offending token was meant to be
+    pub(crate) fn new(input: &str, err: peg::error::ParseError<peg::str::LineCol>) -> Self {
+        let span = token_span(input, err.location.offset);
+        let token = &input[span.clone()];
+        let mut expected = err
+            .expected
+            .tokens()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        expected.sort_unstable();
+        Self {
+            kind: classify(token),
+            token: token.to_string(),
+            span,
+            expected,
+        }
+    }
+
+    /// Replaces the kind with one specific to the report format, unless the report ended early
+    ///
+    /// Classification only knows about METAR and TAF groups.
+    pub(crate) fn reclassify(mut self, kind: ParseErrorKind) -> Self {
+        if self.kind != ParseErrorKind::UnexpectedEnd {
+            self.kind = kind;
+        }
+        self
+    }
+
+    /// Points the error at a token of `input`, re-classifying it if the original error was at the end of the report
+    pub(crate) fn relocate(self, input: &str, span: Range<usize>) -> Self {
+        let token = &input[span.clone()];
+        Self {
+            kind: if self.kind == ParseErrorKind::UnexpectedEnd {
+                classify(token)
+            } else {
+                self.kind
+            },
+            token: token.to_string(),
+            span,
+            expected: self.expected,
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.kind == ParseErrorKind::UnexpectedEnd {
+            write!(f, "[{}] unexpected end of report", self.code())?;
+        } else {
+            write!(
+                f,
+                "[{}] invalid {} `{}` at {}..{}",
+                self.code(),
+                self.kind.description(),
+                self.token,
+                self.span.start,
+                self.span.end
+            )?;
+        }
+        if !self.expected.is_empty() {
+            write!(f, ": {}", expected_message(&self.expected))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub(crate) fn is_separator(c: char) -> bool {
+    matches!(c, ' ' | '\r' | '\n' | '\t' | '>' | '=')
+}
+
+/// Expands an offset to the surrounding whitespace-delimited token, or the next one if it points at whitespace
+fn token_span(input: &str, offset: usize) -> Range<usize> {
+    let offset = input[offset..]
+        .find(|c| !is_separator(c))
+        .map(|skipped| offset + skipped)
+        .unwrap_or(input.len());
+    let start = input[..offset]
+        .rfind(is_separator)
+        .map(|separator| separator + 1)
+        .unwrap_or(0)
+        .max(if offset == input.len() { offset } else { 0 });
+    let end = input[offset..]
+        .find(is_separator)
+        .map(|separator| offset + separator)
+        .unwrap_or(input.len());
+    start..end
+}
+
+/// Picks the group whose parser gets furthest into the token, preferring earlier candidates on ties
+fn classify(token: &str) -> ParseErrorKind {
+    use ParseErrorKind::*;
+
+    if token.is_empty() {
+        return UnexpectedEnd;
+    }
+
+    fn progress<T>(result: Result<T, peg::error::ParseError<peg::str::LineCol>>) -> usize {
+        match result {
+            Ok(_) => usize::MAX,
+            Err(err) => err.location.offset,
+        }
+    }
+
+    let candidates = [
+        (Wind, progress(weather_reports::wind(token))),
+        (
+            ValidityPeriod,
+            progress(weather_reports::day_hour_range(token)),
+        ),
+        (Visibility, progress(weather_reports::visibility(token))),
+        (
+            RunwayVisibility,
+            progress(weather_reports::runway_visibility(token)),
+        ),
+        (
+            RunwayReport,
+            progress(weather_reports::runway_report(token)),
+        ),
+        (Weather, progress(weather_reports::weather(token))),
+        (CloudCover, progress(weather_reports::cloud_cover(token))),
+        (Temperatures, progress(weather_reports::temperatures(token))),
+        (Pressure, progress(weather_reports::pressure(token))),
+        (Color, progress(weather_reports::color(token))),
+        (
+            WaterConditions,
+            progress(weather_reports::water_conditions(token)),
+        ),
+        (Trend, progress(weather_reports::trend(token))),
+        (
+            ChangeIndicator,
+            progress(weather_reports::taf_change_indicator(token)),
+        ),
+        (
+            TemperatureForecast,
+            progress(weather_reports::temperature_forecast(token)),
+        ),
+        // Last, so that digit-heavy groups like wind win ties
+        (
+            ObservationTime,
+            progress(weather_reports::observation_time(token)),
+        ),
+    ];
+    candidates
+        .iter()
+        .rev()
+        .max_by_key(|(_, progress)| *progress)
+        .filter(|(_, progress)| *progress > 0)
+        .map(|(kind, _)| *kind)
+        .unwrap_or(Unrecognized)
+}
+
+fn expected_message(expected: &[String]) -> String {
+    match expected {
+        [] => "unclear cause".to_string(),
+        [token] => format!("expected {}", token),
+        [tokens @ .., last] => format!("expected one of {}, or {}", tokens.join(", "), last),
+    }
+}
+
+/// Convenience function for converting a parsing error
+/// into a [codespan_reporting::diagnostic::Diagnostic] for displaying to a user.
+#[cfg(feature = "codespan_helpers")]
+pub fn into_diagnostic(err: &ParseError) -> codespan_reporting::diagnostic::Diagnostic<()> {
+    let message = if err.kind == ParseErrorKind::UnexpectedEnd {
+        "could not parse report: unexpected end of report".to_string()
+    } else {
+        format!("could not parse report: invalid {}", err.kind.description())
+    };
+    codespan_reporting::diagnostic::Diagnostic::error()
+        .with_code(err.code())
+        .with_message(message)
+        .with_labels(vec![codespan_reporting::diagnostic::Label::primary(
+            (),
+            err.span.clone(),
+        )
+        .with_message(expected_message(&err.expected))])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_offending_tokens() {
+        for (val, kind) in [
+            ("KSEA 290353Z 01008KX 10SM", ParseErrorKind::Wind),
+            (
+                "KSEA 290353Z 01008KT 10SM BKN0X0",
+                ParseErrorKind::CloudCover,
+            ),
+            (
+                "KSEA 290353Z 01008KT 10SM>BKN0X0",
+                ParseErrorKind::CloudCover,
+            ),
+            (
+                "KSEA 290353Z 01008KT 10SM FEW020 19/09 A30X4",
+                ParseErrorKind::Pressure,
+            ),
+            (
+                "KSEA 290353Z 01008KT 10SM FEW020 19/M0X",
+                ParseErrorKind::Temperatures,
+            ),
+            (
+                "KSEA 290353Z 01008KT 10SM FEW020 XYZZY",
+                ParseErrorKind::Unrecognized,
+            ),
+        ] {
+            let err = crate::metar::parse::metar(val).expect_err(val);
+            assert_eq!(err.kind, kind, "{}: {}", val, err);
+        }
+    }
+
+    #[test]
+    fn report_span_and_token() {
+        let val = "KSEA 290353Z 01008KT 10SM BKN0X0 19/09";
+        let err = crate::metar::parse::metar(val).unwrap_err();
+        assert_eq!(err.token, "BKN0X0");
+        assert_eq!(&val[err.span.clone()], "BKN0X0");
+        assert_eq!(err.code(), "E0007");
+    }
+
+    #[test]
+    fn classify_taf_and_truncated_reports() {
+        let err = crate::taf::parse::taf("TAF KSEA 251720Z 2518/2X24 20008KT").unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::ValidityPeriod, "{}", err);
+
+        let err = crate::taf::parse::taf("TAF ").unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::UnexpectedEnd, "{}", err);
+        assert_eq!(err.span, 4..4);
+    }
+}
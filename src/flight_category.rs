@@ -0,0 +1,355 @@
+use uom::si::f64::Length;
+use uom::si::length::{foot, meter, mile};
+
+use crate::clouds;
+use crate::tokens::*;
+
+/// [FAA flight category](https://www.aviationweather.gov/taf/help?page=plot), ordered from worst to best
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FlightCategory {
+    /// Ceiling below 500 feet and/or visibility less than 1 statute mile
+    LowInstrument,
+    /// Ceiling 500 to below 1,000 feet and/or visibility 1 to less than 3 statute miles
+    Instrument,
+    /// Ceiling 1,000 to 3,000 feet and/or visibility 3 to 5 statute miles
+    MarginalVisual,
+    /// Ceiling greater than 3,000 feet and visibility greater than 5 statute miles
+    Visual,
+}
+
+impl From<FlightCategory> for &'static str {
+    fn from(category: FlightCategory) -> Self {
+        match category {
+            FlightCategory::LowInstrument => "LIFR",
+            FlightCategory::Instrument => "IFR",
+            FlightCategory::MarginalVisual => "MVFR",
+            FlightCategory::Visual => "VFR",
+        }
+    }
+}
+
+/// The element of a report that determined its [FlightCategory]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LimitingFactor {
+    Ceiling,
+    Visibility,
+    /// Both fall in the same category, below [FlightCategory::Visual]
+    CeilingAndVisibility,
+    /// Neither lowers the category, so it is [FlightCategory::Visual]
+    Unrestricted,
+    /// `CAVOK` was reported
+    Cavok,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FlightConditions {
+    pub category: FlightCategory,
+    pub limited_by: LimitingFactor,
+}
+
+impl<'input> MetarReport<'input> {
+    /// Derives the FAA flight category from the prevailing visibility and ceiling
+    ///
+    /// Returns [None] if neither visibility nor cloud cover was reported.
+    pub fn flight_category(&self) -> Option<FlightConditions> {
+        flight_conditions(self.visibility.as_ref(), &self.cloud_cover, self.cavok)
+    }
+
+    /// Derives the [NATO color state](https://en.wikipedia.org/wiki/Colour_state) from the prevailing visibility and cloud base
+    ///
+    /// [ColorState::BluePlus] is never derived. Returns [None] if either visibility or cloud cover was not reported.
+    pub fn derived_color_state(&self) -> Option<ColorState> {
+        color_state(self.visibility.as_ref(), &self.cloud_cover, self.cavok)
+    }
+}
+
+impl TafForecast<'_> {
+    /// See [MetarReport::flight_category]
+    ///
+    /// Change groups only list the elements that change, so they may be unclassifiable on their own.
+    pub fn flight_category(&self) -> Option<FlightConditions> {
+        flight_conditions(self.visibility.as_ref(), &self.cloud_cover, self.cavok)
+    }
+
+    /// See [MetarReport::derived_color_state]
+    pub fn derived_color_state(&self) -> Option<ColorState> {
+        color_state(self.visibility.as_ref(), &self.cloud_cover, self.cavok)
+    }
+}
+
+impl TafChangeGroup<'_> {
+    /// See [TafForecast::flight_category]
+    pub fn flight_category(&self) -> Option<FlightConditions> {
+        self.forecast.flight_category()
+    }
+
+    /// See [MetarReport::derived_color_state]
+    pub fn derived_color_state(&self) -> Option<ColorState> {
+        self.forecast.derived_color_state()
+    }
+}
+
+impl TrendReport {
+    /// See [TafForecast::flight_category]
+    pub fn flight_category(&self) -> Option<FlightConditions> {
+        flight_conditions(self.visibility.as_ref(), &self.cloud_cover, false)
+    }
+
+    /// See [MetarReport::derived_color_state]
+    pub fn derived_color_state(&self) -> Option<ColorState> {
+        color_state(self.visibility.as_ref(), &self.cloud_cover, false)
+    }
+}
+
+/// Base of the lowest layer matching `is_layer`, where a vertical visibility of unknown height counts as ground level
+///
+/// `Some(None)` means clouds were reported but none of them matched.
+pub(crate) fn lowest_base(
+    cloud_cover: &[CloudCover],
+    is_layer: impl Fn(CloudCoverage) -> bool,
+) -> Option<Option<Length>> {
+    if cloud_cover.is_empty() {
+        return None;
+    }
+    Some(
+        clouds::lowest_layer(cloud_cover, is_layer)
+            .map(|layer| layer.base.unwrap_or_else(|| Length::new::<foot>(0.))),
+    )
+}
+
+pub(crate) fn ceiling_category(ceiling: Option<Length>) -> FlightCategory {
+    match ceiling.map(|ceiling| ceiling.get::<foot>()) {
+        Some(feet) if feet < 500. => FlightCategory::LowInstrument,
+        Some(feet) if feet < 1000. => FlightCategory::Instrument,
+        Some(feet) if feet <= 3000. => FlightCategory::MarginalVisual,
+        _ => FlightCategory::Visual,
+    }
+}
+
+pub(crate) fn visibility_category(visibility: RawVisibility) -> FlightCategory {
+    let miles = visibility.distance.get::<mile>();
+    // i.e. M1SM is less than 1 mile and P5SM is more than 5 miles
+    let at_least = |threshold: f64| match visibility.out_of_range {
+        Some(OutOfRange::Below) => miles > threshold,
+        _ => miles >= threshold,
+    };
+    let more_than = |threshold: f64| match visibility.out_of_range {
+        Some(OutOfRange::Above) => miles >= threshold,
+        _ => miles > threshold,
+    };
+    if more_than(5.) {
+        FlightCategory::Visual
+    } else if at_least(3.) {
+        FlightCategory::MarginalVisual
+    } else if at_least(1.) {
+        FlightCategory::Instrument
+    } else {
+        FlightCategory::LowInstrument
+    }
+}
+
+fn flight_conditions(
+    visibility: Option<&Visibility>,
+    cloud_cover: &[CloudCover],
+    cavok: bool,
+) -> Option<FlightConditions> {
+    if cavok {
+        return Some(FlightConditions {
+            category: FlightCategory::Visual,
+            limited_by: LimitingFactor::Cavok,
+        });
+    }
+
+    let ceiling = lowest_base(cloud_cover, clouds::is_ceiling).map(ceiling_category);
+    let visibility = visibility
+        .and_then(|visibility| visibility.prevailing)
+        .map(visibility_category);
+
+    let (category, limited_by) = match (ceiling, visibility) {
+        (None, None) => return None,
+        (Some(ceiling), None) => (ceiling, LimitingFactor::Ceiling),
+        (None, Some(visibility)) => (visibility, LimitingFactor::Visibility),
+        (Some(ceiling), Some(visibility)) if ceiling < visibility => {
+            (ceiling, LimitingFactor::Ceiling)
+        }
+        (Some(ceiling), Some(visibility)) if visibility < ceiling => {
+            (visibility, LimitingFactor::Visibility)
+        }
+        (Some(ceiling), Some(_)) => (ceiling, LimitingFactor::CeilingAndVisibility),
+    };
+    Some(FlightConditions {
+        category,
+        limited_by: if category == FlightCategory::Visual {
+            LimitingFactor::Unrestricted
+        } else {
+            limited_by
+        },
+    })
+}
+
+/// Minimum visibility in meters and cloud base in feet for each color state, from best to worst
+const COLOR_STATE_MINIMUMS: [(ColorState, f64, f64); 6] = [
+    (ColorState::Blue, 8000., 2500.),
+    (ColorState::White, 5000., 1500.),
+    (ColorState::Green, 3700., 700.),
+    (ColorState::YellowOne, 2500., 500.),
+    (ColorState::YellowTwo, 1600., 300.),
+    (ColorState::Amber, 800., 200.),
+];
+
+fn color_state(
+    visibility: Option<&Visibility>,
+    cloud_cover: &[CloudCover],
+    cavok: bool,
+) -> Option<ColorState> {
+    if cavok {
+        return Some(ColorState::Blue);
+    }
+
+    // Color states use the lowest layer covering at least 3/8 of the sky
+    let base = lowest_base(cloud_cover, |coverage| {
+        matches!(
+            coverage,
+            CloudCoverage::Scattered
+                | CloudCoverage::Broken
+                | CloudCoverage::Overcast
+                | CloudCoverage::VerticalVisibility
+        )
+    })?
+    .map(|base| base.get::<foot>());
+    let visibility = visibility?.prevailing?;
+    let meters = visibility.distance.get::<meter>();
+    let below = visibility.out_of_range == Some(OutOfRange::Below);
+
+    Some(
+        COLOR_STATE_MINIMUMS
+            .iter()
+            .find(|(_, minimum_visibility, minimum_base)| {
+                (meters > *minimum_visibility || (!below && meters == *minimum_visibility))
+                    && base.map_or(true, |base| base >= *minimum_base)
+            })
+            .map(|(color_state, _, _)| *color_state)
+            .unwrap_or(ColorState::Red),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metar::parse::metar;
+    use crate::taf::parse::taf;
+
+    #[test]
+    fn classify_metar_reports() {
+        for (val, category, limited_by) in [
+            (
+                "KSEA 290353Z 01008KT 10SM FEW020 SCT200 19/09 A3004",
+                FlightCategory::Visual,
+                LimitingFactor::Unrestricted,
+            ),
+            (
+                "KSEA 290353Z 01008KT 10SM BKN025 19/09 A3004",
+                FlightCategory::MarginalVisual,
+                LimitingFactor::Ceiling,
+            ),
+            (
+                "KSEA 290353Z 01008KT 2SM BR BKN025 19/09 A3004",
+                FlightCategory::Instrument,
+                LimitingFactor::Visibility,
+            ),
+            (
+                "KSEA 290353Z 01008KT 1/4SM FG VV001 19/19 A3004",
+                FlightCategory::LowInstrument,
+                LimitingFactor::CeilingAndVisibility,
+            ),
+            (
+                "KSEA 290353Z 01008KT M1SM FG OVC008 19/19 A3004",
+                FlightCategory::LowInstrument,
+                LimitingFactor::Visibility,
+            ),
+            (
+                "KSEA 290353Z 01008KT P5SM OVC040 19/09 A3004",
+                FlightCategory::Visual,
+                LimitingFactor::Unrestricted,
+            ),
+            (
+                "EGLL 290350Z 24010KT CAVOK 19/09 Q1012",
+                FlightCategory::Visual,
+                LimitingFactor::Cavok,
+            ),
+        ] {
+            let conditions = metar(val).unwrap().flight_category().expect(val);
+            assert_eq!(
+                (conditions.category, conditions.limited_by),
+                (category, limited_by),
+                "{}",
+                val
+            );
+        }
+        assert_eq!(
+            metar("KSEA 290353Z 01008KT 19/09 A3004")
+                .unwrap()
+                .flight_category(),
+            None
+        );
+    }
+
+    #[test]
+    fn classify_taf_periods() {
+        let report = taf("TAF KSEA 251720Z 2518/2624 20008KT P6SM FEW040 BKN250 TEMPO 2522/2602 5SM -SHRA BKN035 PROB30 2604/2608 3SM BR OVC008 BECMG 2612/2614 22010KT").unwrap();
+        assert_eq!(
+            report.forecast.flight_category().unwrap().category,
+            FlightCategory::Visual
+        );
+        assert_eq!(
+            report
+                .changes
+                .iter()
+                .map(|change| change
+                    .flight_category()
+                    .map(|conditions| conditions.category))
+                .collect::<Vec<_>>(),
+            vec![
+                Some(FlightCategory::MarginalVisual),
+                Some(FlightCategory::Instrument),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn derive_color_states() {
+        for (val, color_state) in [
+            (
+                "EGXX 290350Z 24010KT 9999 SCT030 19/09 Q1012",
+                ColorState::Blue,
+            ),
+            (
+                "EGXX 290350Z 24010KT 6000 FEW005 BKN020 19/09 Q1012",
+                ColorState::White,
+            ),
+            (
+                "EGXX 290350Z 24010KT 9999 SCT008 19/09 Q1012",
+                ColorState::Green,
+            ),
+            (
+                "EGXX 290350Z 24010KT 1200 BR OVC003 19/09 Q1012",
+                ColorState::Amber,
+            ),
+            (
+                "EGXX 290350Z 24010KT 0200 FG VV/// 19/09 Q1012",
+                ColorState::Red,
+            ),
+        ] {
+            assert_eq!(
+                metar(val).unwrap().derived_color_state(),
+                Some(color_state),
+                "{}",
+                val
+            );
+        }
+    }
+}
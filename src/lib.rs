@@ -1,4 +1,19 @@
+#[cfg(feature = "chrono_helpers")]
+pub mod archive;
+pub mod bulletin;
+pub mod clouds;
+pub mod derived;
+pub mod describe;
+pub mod error;
+pub mod flight_category;
 pub mod metar;
+pub mod pirep;
+pub mod runway;
+pub mod sigmet;
+pub mod speci;
+#[cfg(feature = "stations")]
+pub mod stations;
+pub mod synop;
 pub mod taf;
 pub mod tokens;
-pub mod utils;
\ No newline at end of file
+pub mod utils;
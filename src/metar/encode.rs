@@ -0,0 +1,490 @@
+use std::fmt::{self, Display, Formatter};
+use uom::{
+    si::angle::degree,
+    si::f64::{Length, Pressure, ThermodynamicTemperature, Velocity},
+    si::length::{decimeter, foot, kilometer, meter, mile, millimeter},
+    si::pressure::{hectopascal, inch_of_mercury},
+    si::thermodynamic_temperature::degree_celsius,
+    si::velocity::{kilometer_per_hour, knot, meter_per_second},
+};
+
+use crate::tokens::*;
+
+/// Renders a report back into METAR text
+///
+/// Quantities are written in the unit they were most likely reported in,
+/// so that parsing the output yields the same report.
+pub fn metar(report: &MetarReport) -> String {
+    report.to_string()
+}
+
+/// Returns the nearest integer if `value` is within floating point error of it
+pub(crate) fn whole(value: f64) -> Option<i64> {
+    let rounded = value.round();
+    if (value - rounded).abs() < 1e-6 {
+        Some(rounded as i64)
+    } else {
+        None
+    }
+}
+
+pub(crate) fn temperature(temperature: ThermodynamicTemperature) -> String {
+    let celsius = temperature.get::<degree_celsius>().round() as i64;
+    if celsius < 0 {
+        format!("M{:02}", -celsius)
+    } else {
+        format!("{:02}", celsius)
+    }
+}
+
+fn pressure(pressure: Pressure) -> String {
+    if let Some(hundredths) = whole(pressure.get::<inch_of_mercury>() * 100.) {
+        format!("A{:04}", hundredths)
+    } else {
+        format!("Q{:04}", pressure.get::<hectopascal>().round() as i64)
+    }
+}
+
+fn velocity(velocity: Velocity) -> (i64, &'static str) {
+    if let Some(knots) = whole(velocity.get::<knot>()) {
+        (knots, "KT")
+    } else if let Some(mps) = whole(velocity.get::<meter_per_second>()) {
+        (mps, "MPS")
+    } else if let Some(kmh) = whole(velocity.get::<kilometer_per_hour>()) {
+        (kmh, "KMH")
+    } else {
+        (velocity.get::<knot>().round() as i64, "KT")
+    }
+}
+
+/// Statute miles are written as a whole number and/or a fraction, i.e. `1 1/2SM`
+fn statute_miles(distance: Length) -> Option<String> {
+    let miles = distance.get::<mile>();
+    let whole_miles = miles.floor();
+    [1, 2, 4, 8, 16].iter().find_map(|denominator| {
+        let numerator = whole((miles - whole_miles) * *denominator as f64)?;
+        Some(match (whole_miles as i64, numerator) {
+            (whole_miles, 0) => format!("{}SM", whole_miles),
+            (0, numerator) => format!("{}/{}SM", numerator, denominator),
+            (whole_miles, numerator) => {
+                format!("{} {}/{}SM", whole_miles, numerator, denominator)
+            }
+        })
+    })
+}
+
+/// Runway visual range is written in feet or meters
+///
+/// Feet are preferred when both are whole numbers, since the overlap (multiples of 1250 feet) is rarely a reported metric value.
+fn is_in_feet(distance: &RawVisibility) -> bool {
+    whole(distance.distance.get::<foot>()).is_some()
+}
+
+fn runway_distance(distance: &RawVisibility, in_feet: bool) -> String {
+    let out_of_range = distance.out_of_range.map(<&str>::from).unwrap_or_default();
+    if in_feet {
+        format!(
+            "{}{:04}",
+            out_of_range,
+            distance.distance.get::<foot>().round() as i64
+        )
+    } else {
+        format!(
+            "{}{:04}",
+            out_of_range,
+            distance.distance.get::<meter>().round() as i64
+        )
+    }
+}
+
+impl Display for ZuluTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}{:02}", self.hour, self.minute)
+    }
+}
+
+impl Display for ZuluDateTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}{}", self.day_of_month, self.time)?;
+        if self.is_zulu {
+            write!(f, "Z")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for ZuluTimeRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.begin, self.end)
+    }
+}
+
+impl Display for Wind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.direction {
+            Some(direction) => write!(f, "{:03}", direction.get::<degree>().round() as i64)?,
+            None => write!(f, "VRB")?,
+        }
+        let unit = self
+            .speed
+            .or(self.peak_gust)
+            .map(velocity)
+            .map_or("KT", |(_, unit)| unit);
+        if let Some(speed) = self.speed {
+            write!(f, "{:02}", velocity(speed).0)?;
+        }
+        if let Some(peak_gust) = self.peak_gust {
+            write!(f, "G{:02}", velocity(peak_gust).0)?;
+        }
+        write!(f, "{}", unit)?;
+        if let Some((begin, end)) = self.variance {
+            write!(
+                f,
+                " {:03}V{:03}",
+                begin.get::<degree>().round() as i64,
+                end.get::<degree>().round() as i64
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for RawVisibility {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(out_of_range) = self.out_of_range {
+            write!(f, "{}", <&str>::from(out_of_range))?;
+        }
+        let meters = self.distance.get::<meter>();
+        match whole(meters) {
+            Some(meters) if meters < 10000 => write!(f, "{:04}", meters),
+            _ => {
+                if let Some(statute_miles) = statute_miles(self.distance) {
+                    write!(f, "{}", statute_miles)
+                } else if let Some(kilometers) = whole(self.distance.get::<kilometer>()) {
+                    write!(f, "{}KM", kilometers)
+                } else {
+                    write!(f, "{:04}", meters.round() as i64)
+                }
+            }
+        }
+    }
+}
+
+impl Display for DirectionalVisibility {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.distance, <&str>::from(self.direction))
+    }
+}
+
+impl Display for Visibility {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let groups = self
+            .prevailing
+            .iter()
+            .map(ToString::to_string)
+            .chain(self.minimum_directional.iter().map(ToString::to_string))
+            .chain(self.maximum_directional.iter().map(ToString::to_string))
+            .collect::<Vec<_>>();
+        write!(f, "{}", groups.join(" "))
+    }
+}
+
+impl<'input> Display for RunwayVisibility<'input> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "R{}/", self.designator)?;
+        let in_feet = match &self.visibility {
+            VisibilityType::Fixed(visibility) => {
+                let in_feet = is_in_feet(visibility);
+                write!(f, "{}", runway_distance(visibility, in_feet))?;
+                in_feet
+            }
+            VisibilityType::Varying { lower, upper } => {
+                // The unit is only written once, after the upper bound
+                let in_feet = is_in_feet(upper);
+                write!(
+                    f,
+                    "{}V{}",
+                    runway_distance(lower, in_feet),
+                    runway_distance(upper, in_feet)
+                )?;
+                in_feet
+            }
+        };
+        if in_feet {
+            write!(f, "FT")?;
+        }
+        if let Some(trend) = self.trend {
+            write!(f, "{}", <&str>::from(trend))?;
+        }
+        Ok(())
+    }
+}
+
+impl<'input> Display for RunwayReport<'input> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "R{}/", self.designator)?;
+        match self.report_info {
+            RunwayReportInfo::Cleared { friction } => {
+                write!(f, "CLRD")?;
+                write_runway_friction(f, friction)
+            }
+            RunwayReportInfo::Contaminated {
+                deposit,
+                extent,
+                depth,
+                friction,
+            } => {
+                write!(f, "{}", deposit.map_or("/", <&str>::from))?;
+                write!(f, "{}", extent.map_or("/", <&str>::from))?;
+                match depth {
+                    Some(DepositDepth::Depth {
+                        out_of_range: Some(OutOfRange::Below),
+                        ..
+                    }) => write!(f, "00")?,
+                    Some(DepositDepth::Depth {
+                        out_of_range: Some(OutOfRange::Above),
+                        ..
+                    }) => write!(f, "98")?,
+                    Some(DepositDepth::Depth { depth, .. }) => {
+                        let millimeters = depth.get::<millimeter>().round() as i64;
+                        if millimeters <= 90 {
+                            write!(f, "{:02}", millimeters)?;
+                        } else {
+                            write!(f, "{}", 92 + (millimeters - 100) / 50)?;
+                        }
+                    }
+                    Some(DepositDepth::NotOperational) => write!(f, "99")?,
+                    None => write!(f, "//")?,
+                }
+                write_runway_friction(f, friction)
+            }
+            RunwayReportInfo::ClosedBySnow => write!(f, "SNOCLO"),
+        }
+    }
+}
+
+fn write_runway_friction(f: &mut Formatter<'_>, friction: Option<RunwayFriction>) -> fmt::Result {
+    match friction {
+        Some(RunwayFriction::Coefficient(coefficient)) => {
+            write!(f, "{:02}", (coefficient * 100.).round() as i64)
+        }
+        Some(RunwayFriction::BrakingAction(braking_action)) => {
+            write!(f, "{}", <&str>::from(braking_action))
+        }
+        Some(RunwayFriction::Unreliable) => write!(f, "99"),
+        None => write!(f, "//"),
+    }
+}
+
+impl<'input> Display for WindShear<'input> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            WindShear::Runway(designator) => write!(f, "WS R{}", designator),
+            WindShear::AllRunways => write!(f, "WS ALL RWY"),
+            WindShear::LowLevel { height, wind } => write!(
+                f,
+                "WS{:03}/{}",
+                (height.get::<foot>() / 100.).round() as i64,
+                wind
+            ),
+        }
+    }
+}
+
+impl Display for Weather {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", <&str>::from(self.intensity))?;
+        if self.vicinity {
+            write!(f, "VC")?;
+        }
+        if let Some(descriptor) = self.descriptor {
+            write!(f, "{}", <&str>::from(descriptor))?;
+        }
+        match &self.condition {
+            Some(Condition::Precipitation(precipitation)) => {
+                for precipitation in precipitation {
+                    write!(f, "{}", <&str>::from(*precipitation))?;
+                }
+                Ok(())
+            }
+            Some(Condition::Obscuration(obscuration)) => {
+                write!(f, "{}", <&str>::from(*obscuration))
+            }
+            Some(Condition::Other(other)) => write!(f, "{}", <&str>::from(*other)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Display for CloudCover {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", <&str>::from(self.coverage))?;
+        match self.base {
+            Some(base) => write!(f, "{:03}", (base.get::<foot>() / 100.).round() as i64)?,
+            None if self.cloud_type.is_some() => write!(f, "///")?,
+            None => {}
+        }
+        if let Some(cloud_type) = self.cloud_type {
+            write!(f, "{}", <&str>::from(cloud_type))?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Temperatures {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/", temperature(self.air))?;
+        if let Some(dewpoint) = self.dewpoint {
+            write!(f, "{}", temperature(dewpoint))?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for AccumulatedRainfall {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "RF{:04.1}/{:05.1}",
+            self.recent.get::<millimeter>(),
+            self.past.get::<millimeter>()
+        )
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.is_black {
+            write!(f, "BLACK")?;
+        }
+        write!(f, "{}", <&str>::from(self.current_color))?;
+        if let Some(next_color) = self.next_color {
+            write!(f, " {}", <&str>::from(next_color))?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for WaterConditions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.temperature {
+            Some(temperature) => write!(f, "W{}/", self::temperature(temperature))?,
+            None => write!(f, "W///")?,
+        }
+        match (self.surface_state, self.significant_wave_height) {
+            (Some(surface_state), _) => write!(f, "S{}", <&str>::from(surface_state)),
+            (None, Some(wave_height)) => {
+                write!(f, "H{}", wave_height.get::<decimeter>().round() as i64)
+            }
+            (None, None) => write!(f, "S/"),
+        }
+    }
+}
+
+impl Display for TrendTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", <&str>::from(self.time_type), self.time)
+    }
+}
+
+impl Display for TrendReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let groups = self
+            .time
+            .iter()
+            .map(ToString::to_string)
+            .chain(self.wind.iter().map(ToString::to_string))
+            .chain(self.visibility.iter().map(ToString::to_string))
+            .chain(self.weather.iter().map(ToString::to_string))
+            .chain(self.cloud_cover.iter().map(ToString::to_string))
+            .chain(
+                self.color_state
+                    .map(|color_state| <&str>::from(color_state).to_string()),
+            )
+            .collect::<Vec<_>>();
+        write!(f, "{}", groups.join(" "))
+    }
+}
+
+impl Display for Trend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Trend::NoSignificantChange => write!(f, "NOSIG"),
+            Trend::Becoming(trend) => write!(f, "BECMG {}", trend),
+            Trend::Temporarily(trend) => write!(f, "TEMPO {}", trend),
+        }
+    }
+}
+
+impl<'input> Display for MetarReport<'input> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut groups = vec![self.identifier.to_string()];
+        groups.extend(self.observation_time.iter().map(ToString::to_string));
+        groups.extend(
+            self.observation_validity_range
+                .iter()
+                .map(ToString::to_string),
+        );
+        groups.extend(
+            self.observation_flags
+                .iter()
+                .map(|flag| <&str>::from(*flag).to_string()),
+        );
+        groups.extend(self.wind.iter().map(ToString::to_string));
+        if self.cavok {
+            groups.push("CAVOK".to_string());
+        }
+        groups.extend(self.visibility.iter().map(ToString::to_string));
+        groups.extend(self.runway_visibilities.iter().map(ToString::to_string));
+        groups.extend(self.weather.iter().map(ToString::to_string));
+        groups.extend(self.cloud_cover.iter().map(ToString::to_string));
+        groups.extend(self.temperatures.iter().map(ToString::to_string));
+        groups.extend(self.pressure.map(pressure));
+        groups.extend(self.accumulated_rainfall.iter().map(ToString::to_string));
+        groups.extend(
+            self.recent_weather
+                .iter()
+                .map(|weather| format!("RE{}", weather)),
+        );
+        groups.extend(self.wind_shear.iter().map(ToString::to_string));
+        groups.extend(self.color.iter().map(ToString::to_string));
+        groups.extend(self.runway_reports.iter().map(ToString::to_string));
+        groups.extend(self.water_conditions.iter().map(ToString::to_string));
+        groups.extend(self.trends.iter().map(ToString::to_string));
+        write!(f, "{}", groups.join(" "))?;
+
+        // Written verbatim so that it is preserved
+        if let Some(remark) = &self.remark {
+            write!(f, " {}", remark)?;
+        } else if self.maintenance_needed {
+            write!(f, " ")?;
+        }
+        if self.maintenance_needed {
+            write!(f, "$")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::metar::parse::metar;
+
+    #[test]
+    fn encode_metar() {
+        for val in [
+            "KSEA 290353Z 01008KT 10SM SCT200 19/09 A3004 RMK AO2 SLP179 T01940094",
+            "EGLL 251450Z AUTO 24015G28KT 210V280 9999 -SHRA FEW025CB BKN040 14/09 Q1002 NOSIG",
+            "KTPA 300353Z VRB03KT 1 1/2SM R36L/2400V4000FT +TSRA BR OVC008 M02/M03 A2992",
+            "UUWW 251430Z 33004MPS 1500 0800N R06/0600N SN BKN006 M05/M06 Q1020 R06/CLRD70 TEMPO 0400 +SN",
+            "ENQA 251420Z 27025KT CAVOK 12/08 Q1010 W13/H22",
+            "EGXX 251450Z 09010KT 8000 SCT012 10/09 Q1015 BLACKAMB RED",
+            "YSSY 251430Z 20010KT 9999 VCSH 15/10 Q1015 RF00.4/012.6",
+            "LPPT 251430Z 33015KT 9999 FEW030 18/11 Q1018 RETS WS R03 WS ALL RWY",
+            "UUDD 251430Z 18003MPS 3000 -SN OVC010 M04/M05 Q1012 R14L/590295 R88/459442 R/SNOCLO",
+        ] {
+            let report = metar(val).expect(val);
+            assert_eq!(report.to_string(), val);
+        }
+    }
+}
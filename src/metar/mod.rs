@@ -1,3 +1,4 @@
+pub mod encode;
 pub mod parse;
 
 #[cfg(test)]
@@ -10,18 +11,26 @@ mod tests {
     };
     use zstd::Decoder;
 
+    use crate::archive::{ArchiveFormat, ArchiveReader, ArchivedReport};
     use crate::metar::parse::into_diagnostic;
 
+    fn ktpa_reports() -> Vec<ArchivedReport> {
+        ArchiveReader::new(
+            std::io::BufReader::new(
+                Decoder::new(Cursor::new(include_bytes!("../../tests/ktpa.txt.zst"))).unwrap(),
+            ),
+            ArchiveFormat::Ogimet,
+        )
+        .collect::<std::io::Result<Vec<_>>>()
+        .unwrap()
+    }
+
     #[test]
     fn validate_against_year_of_ktpa_metar_reports() {
-        let mut reports = String::new();
-        Decoder::new(Cursor::new(include_bytes!("../../tests/ktpa.txt.zst")))
-            .unwrap()
-            .read_to_string(&mut reports)
-            .unwrap();
+        let reports = ktpa_reports();
         let errors = reports
-            .split('\n')
-            .map(|report| report.split_at(13).1)
+            .iter()
+            .map(|report| report.text.as_str())
             .filter_map(|report| {
                 if let Err(err) = crate::metar::parse::metar(report) {
                     let mut writer = StandardStream::stderr(ColorChoice::Never);
@@ -45,6 +54,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn encode_round_trip_year_of_ktpa_metar_reports() {
+        let reports = ktpa_reports();
+        for report in reports.iter().map(|report| report.text.as_str()) {
+            let parsed = crate::metar::parse::metar(report).unwrap();
+            let encoded = crate::metar::encode::metar(&parsed);
+            assert_eq!(
+                crate::metar::parse::metar(&encoded).expect(&encoded),
+                parsed,
+                "{}",
+                encoded
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_year_of_ktpa_metar_reports() {
+        let reports = ktpa_reports();
+        for report in reports.iter().map(|report| report.text.as_str()) {
+            let parsed = crate::metar::parse::metar(report).unwrap();
+            let json = serde_json::to_string(&parsed).unwrap();
+            assert_eq!(
+                serde_json::from_str::<crate::tokens::MetarReport>(&json).unwrap(),
+                parsed,
+                "{}",
+                json
+            );
+        }
+    }
+
     #[test]
     fn validate_against_all_ogimet_latest_reports_by_country() {
         tar::Archive::new(
@@ -71,17 +111,16 @@ mod tests {
                 .unwrap();
 
             let mut acc = 0;
-            let errors = reports_in_country
-                .split('=')
-                .filter(|report| report.len() >= 14)
-                .map(|report| report.split_at(13).1)
+            let mut recovered = 0;
+            let reports = ArchiveReader::new(reports_in_country.as_bytes(), ArchiveFormat::Ogimet)
+                .collect::<std::io::Result<Vec<_>>>()
+                .unwrap();
+            let errors = reports
+                .iter()
+                .map(|report| report.text.as_str())
                 .filter(|report| {
                     // Skip Canada SAO observations
-                    if report.contains("AUTO8") {
-                        false
-                    } else {
-                        true
-                    }
+                    !report.contains("AUTO8")
                 })
                 .filter_map(|report| {
                     acc += 1;
@@ -98,6 +137,9 @@ mod tests {
                             &into_diagnostic(&err),
                         )
                         .unwrap();
+                        if crate::metar::parse::metar_lenient(report).is_ok() {
+                            recovered += 1;
+                        }
                         Some(err)
                     } else {
                         None
@@ -107,13 +149,20 @@ mod tests {
 
             if !errors.is_empty() {
                 println!(
-                    "### {}: {} failures out of {} total cases ({:.2}% coverage)",
+                    "### {}: {} failures out of {} total cases ({:.2}% coverage), {} partially recovered",
                     entry.path().unwrap().to_string_lossy(),
                     errors.len(),
                     acc,
-                    (100. - errors.len() as f64 / acc as f64 * 100.)
+                    (100. - errors.len() as f64 / acc as f64 * 100.),
+                    recovered
                 );
             }
+            assert_eq!(
+                recovered,
+                errors.len(),
+                "lenient parsing should recover every report in {}",
+                entry.path().unwrap().to_string_lossy()
+            );
         });
     }
 
@@ -154,18 +203,17 @@ mod tests {
                     .unwrap();
 
                 let mut acc = 0;
-                let errors = reports_in_country
-                    .split('=')
-                    .filter(|report| report.len() >= 14)
-                    .map(|report| report.split_at(13).1)
+                let reports =
+                    ArchiveReader::new(reports_in_country.as_bytes(), ArchiveFormat::Ogimet)
+                        .collect::<std::io::Result<Vec<_>>>()
+                        .unwrap();
+                let errors = reports
+                    .iter()
+                    .map(|report| report.text.as_str())
                     .filter(|report| {
                         // Skip SAO observations
                         let sao_name = path.get(1..=3).unwrap();
-                        if report.starts_with(&format!(" {} ", sao_name)) {
-                            false
-                        } else {
-                            true
-                        }
+                        !report.starts_with(&format!("{} ", sao_name))
                     })
                     .filter_map(|report| {
                         acc += 1;
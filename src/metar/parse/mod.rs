@@ -1,40 +1,100 @@
-mod parser;
+pub(crate) mod parser;
 
-pub use parser::weather_reports::metar;
+use crate::error::ParseError;
+use crate::tokens::MetarReport;
 
-/// Convenience function for converting a parsing error
-/// into a [codespan_reporting::diagnostic::Diagnostic] for displaying to a user.
 #[cfg(feature = "codespan_helpers")]
-pub fn into_diagnostic(
-    err: &peg::error::ParseError<peg::str::LineCol>,
-) -> codespan_reporting::diagnostic::Diagnostic<()> {
-    let expected_count = err.expected.tokens().count();
-    let label_msg = if expected_count == 0 {
-        "unclear cause".to_string()
-    } else if expected_count == 1 {
-        format!("expected {}", err.expected.tokens().next().unwrap())
-    } else {
-        let tokens = {
-            let mut tokens = err.expected.tokens().collect::<Vec<_>>();
-            tokens.sort_unstable();
-            tokens
+pub use crate::error::into_diagnostic;
+
+/// Parses a METAR report
+pub fn metar(input: &str) -> Result<MetarReport<'_>, ParseError> {
+    parser::weather_reports::metar(input).map_err(|err| ParseError::new(input, err))
+}
+
+/// A report decoded by [metar_lenient]
+#[derive(PartialEq, Clone, Debug)]
+pub struct PartialMetar<'input> {
+    pub report: MetarReport<'input>,
+    /// Tokens that were skipped to decode the rest of the report, in order of appearance
+    pub skipped: Vec<ParseError>,
+}
+
+/// Parses a METAR report, skipping any tokens that can't be decoded
+///
+/// Each skipped token is recorded with its span in `input` and the reason it was rejected.
+/// Fails only if the station identifier itself is malformed.
+pub fn metar_lenient(input: &str) -> Result<PartialMetar<'_>, ParseError> {
+    if let Ok(report) = metar(input) {
+        return Ok(PartialMetar {
+            report,
+            skipped: vec![],
+        });
+    }
+
+    let mut spans = vec![];
+    let mut start = None;
+    for (i, c) in input.char_indices() {
+        match (start, crate::error::is_separator(c)) {
+            (None, false) => start = Some(i),
+            (Some(token_start), true) => {
+                spans.push(token_start..i);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    spans.extend(start.map(|token_start| token_start..input.len()));
+
+    let mut kept = vec![true; spans.len()];
+    let mut skipped = vec![];
+    loop {
+        // Rebuild the report without the skipped tokens, keeping the original separators
+        let mut text = String::with_capacity(input.len());
+        let mut offsets = vec![];
+        let mut previous_end = 0;
+        for (i, span) in spans.iter().enumerate() {
+            if kept[i] {
+                text += &input[previous_end..span.start];
+                offsets.push((text.len(), i));
+                text += &input[span.clone()];
+            }
+            previous_end = span.end;
+        }
+        text += &input[previous_end..];
+
+        let err = match metar(&text) {
+            Ok(report) => {
+                return Ok(PartialMetar {
+                    report: report.into_owned(),
+                    skipped,
+                })
+            }
+            Err(err) => err,
         };
-        let mut acc = "expected one of ".to_string();
-        for token in tokens.iter().take(expected_count - 1) {
-            acc += token;
-            acc += ", ";
-        }
-        acc += "or ";
-        acc += tokens.last().unwrap();
-        acc
-    };
-    codespan_reporting::diagnostic::Diagnostic::error()
-        .with_message("could not parse report")
-        .with_labels(vec![codespan_reporting::diagnostic::Label::primary(
-            (),
-            err.location.offset..err.location.offset,
-        )
-        .with_message(label_msg)])
+
+        let failed = offsets
+            .iter()
+            .rev()
+            .find(|(offset, _)| *offset <= err.span.start)
+            .map(|(_, i)| *i);
+        let in_identifier_position = failed.map_or(true, |failed| {
+            !(0..failed).any(|i| {
+                kept[i]
+                    && parser::weather_reports::icao_identifier(&input[spans[i].clone()]).is_ok()
+            })
+        });
+        let err = err.relocate(
+            input,
+            failed.map_or(input.len()..input.len(), |i| spans[i].clone()),
+        );
+        if in_identifier_position {
+            return Err(err);
+        }
+        kept[failed.unwrap()] = false;
+        let position =
+            skipped.partition_point(|skipped: &ParseError| skipped.span.start < err.span.start);
+        skipped.insert(position, err);
+    }
 }
 
 #[cfg(test)]
@@ -76,6 +136,85 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_runway_report() {
+        use crate::tokens::*;
+        use uom::si::length::{centimeter, millimeter};
+
+        for val in [
+            "R06/CLRD70",
+            "R88/CLRD//",
+            "R99/290155",
+            "R24L/19//95",
+            "R/SNOCLO",
+            "SNOCLO",
+        ] {
+            runway_report(val).expect(val);
+        }
+        let report = runway_report("R24/290155").unwrap().unwrap();
+        assert_eq!(
+            report.report_info,
+            RunwayReportInfo::Contaminated {
+                deposit: Some(RunwayDeposit::Wet),
+                extent: Some(RunwayContaminationExtent::FiftyOneToOneHundredPercent),
+                depth: Some(DepositDepth::Depth {
+                    depth: uom::si::f64::Length::new::<millimeter>(1.),
+                    out_of_range: None,
+                }),
+                friction: Some(RunwayFriction::Coefficient(0.55)),
+            }
+        );
+        assert!(matches!(
+            runway_report("R24L/19//95").unwrap().unwrap().report_info,
+            RunwayReportInfo::Contaminated {
+                depth: None,
+                friction: Some(RunwayFriction::BrakingAction(BrakingAction::Good)),
+                ..
+            }
+        ));
+        let report = runway_report("88459442").unwrap().unwrap();
+        assert_eq!(report.designator, "88");
+        match report.report_info {
+            RunwayReportInfo::Contaminated {
+                depth: Some(DepositDepth::Depth { depth, .. }),
+                friction: Some(RunwayFriction::Coefficient(coefficient)),
+                ..
+            } => {
+                assert_eq!(depth.get::<centimeter>().round(), 20.);
+                assert!((coefficient - 0.42).abs() < 1e-9);
+            }
+            info => panic!("unexpected runway report {:?}", info),
+        }
+        assert_eq!(
+            runway_report("74290155").unwrap().unwrap().designator,
+            "24R"
+        );
+        for val in ["R24/299155", "R24/290197"] {
+            runway_report(val).expect_err(val);
+        }
+    }
+
+    #[test]
+    fn parse_wind_shear() {
+        use crate::tokens::WindShear;
+
+        for (val, wind_shear) in [
+            ("WS R27", WindShear::Runway("27".into())),
+            ("WS RWY09L", WindShear::Runway("09L".into())),
+            ("WS ALL RWY", WindShear::AllRunways),
+        ] {
+            assert_eq!(self::wind_shear(val).expect(val), wind_shear);
+        }
+        assert!(matches!(
+            self::wind_shear("WS020/27045KT"),
+            Ok(WindShear::LowLevel { .. })
+        ));
+        let report =
+            super::metar("LPPT 251430Z 33015KT 9999 FEW030 18/11 Q1018 WS R03 NOSIG").unwrap();
+        assert_eq!(report.wind_shear, vec![WindShear::Runway("03".into())]);
+        assert_eq!(report.trends.len(), 1);
+    }
+
     #[test]
     fn parse_weather() {
         for val in ["-RA", "BR", "MIFG"] {
@@ -104,6 +243,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn decode_pressure() {
+        use uom::si::pressure::{hectopascal, inch_of_mercury};
+
+        for (val, inches_of_mercury) in [("A3002", 30.02), ("A29.92", 29.92)] {
+            let altimeter = pressure(val).unwrap().unwrap();
+            assert!(
+                (altimeter.get::<inch_of_mercury>() - inches_of_mercury).abs() < 1e-9,
+                "{}",
+                val
+            );
+        }
+        let qnh = pressure("Q1013").unwrap().unwrap();
+        assert!((qnh.get::<hectopascal>() - 1013.).abs() < 1e-9);
+    }
+
     #[test]
     fn parse_water_conditions() {
         for val in ["W13/S3", "W13/S/", "W13/H10", "W///S3", "W13/H//"] {
@@ -120,8 +275,133 @@ mod tests {
 
     #[test]
     fn parse_whitespace() {
-        for val in [" ///// "] {
+        for val in [" ///// ", "\t", ">"] {
             whitespace(val).expect(val);
         }
     }
+
+    #[test]
+    fn parse_remark_section() {
+        for val in [
+            "RMK AO2 SLP179 T01940094",
+            "RMK AO2 PK WND 28045/15 WSHFT 30 FROPA RAB15 PRESFR SLP982 P0003 60009 70015 T10171022 11021 21033 401120084 52018 PWINO TSNO",
+            "RMK AO1 SLPNO 6//// VISNO RWY06=",
+        ] {
+            remark_section(val).expect(val);
+        }
+    }
+
+    #[test]
+    fn decode_remark_section() {
+        use crate::tokens::*;
+        use uom::si::{
+            f64::{Pressure, ThermodynamicTemperature},
+            length::inch,
+            pressure::hectopascal,
+            thermodynamic_temperature::degree_celsius,
+        };
+
+        let section = remark_section(
+            "RMK AO2 PK WND 28045/1715 RAB15 SLP982 P0003 6//// T10171022 401120084 52018 PWINO",
+        )
+        .unwrap();
+        assert_eq!(
+            section.station_type,
+            Some(AutomatedStationType::WithPrecipitationDiscriminator)
+        );
+        let peak_wind = section.peak_wind.unwrap();
+        assert_eq!((peak_wind.hour, peak_wind.minute), (Some(17), 15));
+        assert!(
+            (section.sea_level_pressure.unwrap() - Pressure::new::<hectopascal>(998.2))
+                .abs()
+                .get::<hectopascal>()
+                < 1e-9
+        );
+        assert!((section.hourly_precipitation.unwrap().get::<inch>() - 0.03).abs() < 1e-9);
+        assert_eq!(section.precipitation_3_or_6_hour, None);
+        assert_eq!(
+            section.precise_temperatures.unwrap().air,
+            ThermodynamicTemperature::new::<degree_celsius>(-1.7)
+        );
+        assert_eq!(
+            section.temperature_extremes_24_hour.unwrap().maximum,
+            ThermodynamicTemperature::new::<degree_celsius>(11.2)
+        );
+        let tendency = section.pressure_tendency.unwrap();
+        assert_eq!(
+            tendency.characteristic,
+            PressureTendencyCharacteristic::Increasing
+        );
+        assert!((tendency.change.get::<hectopascal>() - 1.8).abs() < 1e-9);
+        assert_eq!(
+            section.sensor_status,
+            vec![SensorStatus::PresentWeatherIdentifierNotAvailable]
+        );
+        assert_eq!(section.unparsed, vec!["RAB15"]);
+
+        // Minutes only, when the event happened during the hour of the report
+        let section = remark_section("RMK AO2 PK WND 28045/15 WSHFT 30 FROPA").unwrap();
+        let peak_wind = section.peak_wind.unwrap();
+        assert_eq!((peak_wind.hour, peak_wind.minute), (None, 15));
+        let wind_shift = section.wind_shift.unwrap();
+        assert_eq!((wind_shift.hour, wind_shift.minute), (None, 30));
+        assert!(wind_shift.frontal_passage);
+        assert!(section.unparsed.is_empty());
+
+        let wind_shift = remark_section("RMK WSHFT 1715")
+            .unwrap()
+            .wind_shift
+            .unwrap();
+        assert_eq!((wind_shift.hour, wind_shift.minute), (Some(17), 15));
+        assert!(!wind_shift.frontal_passage);
+    }
+
+    #[test]
+    fn into_owned_outlives_input() {
+        let report = {
+            let input = String::from(
+                "KSEA 290353Z 01008KT 10SM R16L/P6000FT SCT200 19/09 A3004 RMK AO2 SLP179",
+            );
+            metar(&input).unwrap().into_owned()
+        };
+        let report = std::thread::spawn(move || report).join().unwrap();
+        assert_eq!(report.identifier, "KSEA");
+        assert_eq!(report.runway_visibilities[0].designator, "16L");
+        assert_eq!(report.remark.as_deref(), Some("RMK AO2 SLP179"));
+    }
+
+    #[test]
+    fn lenient_parse_skips_malformed_groups() {
+        use crate::error::ParseErrorKind;
+
+        let input = "KSEA 290353Z 01008KT 10SM BKN0X0 FEW020 19/09 A30X4 RMK AO2";
+        let partial = super::metar_lenient(input).unwrap();
+        assert!(partial.report.wind.is_some());
+        assert!(partial.report.visibility.is_some());
+        assert_eq!(partial.report.cloud_cover.len(), 1);
+        assert!(partial.report.temperatures.is_some());
+        assert_eq!(partial.report.remark.as_deref(), Some("RMK AO2"));
+        assert_eq!(
+            partial
+                .skipped
+                .iter()
+                .map(|err| (&input[err.span.clone()], err.kind))
+                .collect::<Vec<_>>(),
+            vec![
+                ("BKN0X0", ParseErrorKind::CloudCover),
+                ("A30X4", ParseErrorKind::Pressure)
+            ]
+        );
+
+        let input = "KSEA 290353Z 01008KT 10SM FEW020 19/09 A3004";
+        assert_eq!(
+            super::metar_lenient(input).unwrap(),
+            super::PartialMetar {
+                report: super::metar(input).unwrap(),
+                skipped: vec![]
+            }
+        );
+
+        assert!(super::metar_lenient("K$EA 290353Z 01008KT").is_err());
+    }
 }
@@ -2,7 +2,7 @@ use std::convert::TryFrom;
 use uom::{
     si::angle::degree,
     si::f64::{Angle, Length, Pressure, ThermodynamicTemperature, Velocity},
-    si::length::{decimeter, foot, kilometer, meter, mile, millimeter},
+    si::length::{centimeter, decimeter, foot, inch, kilometer, meter, mile, millimeter},
     si::pressure::{hectopascal, inch_of_mercury},
     si::thermodynamic_temperature::degree_celsius,
     si::velocity::{kilometer_per_hour, knot, meter_per_second},
@@ -10,6 +10,25 @@ use uom::{
 
 use crate::tokens::*;
 
+/// A single group of a [RemarkSection]
+enum RemarkGroup<'input> {
+    StationType(AutomatedStationType),
+    PeakWind(PeakWind),
+    WindShift(WindShift),
+    PressureChange(PressureChange),
+    SeaLevelPressure(Pressure),
+    HourlyPrecipitation(Option<Length>),
+    Precipitation3Or6Hour(Option<Length>),
+    Precipitation24Hour(Option<Length>),
+    PreciseTemperatures(Temperatures),
+    MaximumTemperature6Hour(ThermodynamicTemperature),
+    MinimumTemperature6Hour(ThermodynamicTemperature),
+    TemperatureExtremes24Hour(TemperatureExtremes),
+    PressureTendency(PressureTendency),
+    SensorStatus(SensorStatus),
+    Unparsed(&'input str),
+}
+
 peg::parser! {
     pub grammar weather_reports() for str {
         /// [METAR](https://en.wikipedia.org/wiki/METAR) parser
@@ -42,6 +61,7 @@ peg::parser! {
                     recent_weather:recent_weather_sequence()? whitespace()
                     cloud_cover_post_recent_weather:cloud_cover() ** whitespace() whitespace()
                     temperatures_post_recent_weather:temperatures()? whitespace()
+                    wind_shear:wind_shear() ** whitespace() whitespace()
                     // Military stations often report these
                     color:color()? whitespace()
                     // Some stations report runway visibility after pressure
@@ -57,14 +77,14 @@ peg::parser! {
                     quiet!{"=" [_]*}? whitespace()
                     {
                 MetarReport {
-                    identifier,
+                    identifier: identifier.into(),
                     observation_time,
                     observation_validity_range,
                     observation_flags: pre_observation_flags.iter().copied().chain(observation_flags).collect(),
                     wind: wind.flatten(),
                     visibility: visibility.flatten(),
-                    runway_visibilities: runway_visibilities.iter().copied().chain(runway_visibilities_post_pressure).flatten().collect(),
-                    runway_reports: runway_reports.iter().copied().flatten().collect(),
+                    runway_visibilities: runway_visibilities.into_iter().chain(runway_visibilities_post_pressure).flatten().collect(),
+                    runway_reports: runway_reports.into_iter().flatten().collect(),
                     weather: weather.unwrap_or_default().drain(..).chain(weather_post_pressure.unwrap_or_default()).collect(),
                     cloud_cover: cloud_cover.iter().copied().chain(cloud_cover_post_pressure).chain(cloud_cover_post_recent_weather).flatten().collect(),
                     cavok: cavok.is_some(),
@@ -72,13 +92,112 @@ peg::parser! {
                     pressure: pressure.flatten(),
                     accumulated_rainfall,
                     recent_weather: pre_recent_weather.unwrap_or_default().iter().cloned().chain(recent_weather.unwrap_or_default()).collect(),
+                    wind_shear,
                     color,
                     water_conditions,
                     trends,
-                    remark,
+                    remark: remark.map(Into::into),
+                    remark_section: remark.and_then(|remark| remark_section(remark).ok()),
                     maintenance_needed: maintenance_needed.is_some(),
                 }
             }
+        /// [TAF](https://en.wikipedia.org/wiki/Terminal_aerodrome_forecast) parser
+        pub rule taf() -> TafReport<'input> =
+                    whitespace()
+                    ("TAF" whitespace())?
+                    pre_flags:taf_flag() ** whitespace() whitespace()
+                    identifier:icao_identifier() whitespace()
+                    issue_time:observation_time()? whitespace()
+                    validity:day_hour_range()? whitespace()
+                    // NIL and CNL come after the validity period
+                    flags:taf_flag() ** whitespace() whitespace()
+                    forecast:taf_forecast() whitespace()
+                    temperature_forecasts:temperature_forecast() ** whitespace() whitespace()
+                    changes:taf_change_group() ** whitespace() whitespace()
+                    // Some stations report temperature forecasts at the end instead
+                    temperature_forecasts_post_changes:temperature_forecast() ** whitespace() whitespace()
+                    remark:$(("RMK" / "AMD" / "NXT") [^'=']*)?
+                    // Some machines use = to indicate end of message
+                    quiet!{"=" [_]*}? whitespace()
+                    {
+                TafReport {
+                    identifier: identifier.into(),
+                    flags: pre_flags.iter().copied().chain(flags).collect(),
+                    issue_time,
+                    validity,
+                    forecast,
+                    temperature_forecasts: temperature_forecasts.iter().copied().chain(temperature_forecasts_post_changes).collect(),
+                    changes,
+                    remark: remark.map(str::trim_end).map(Into::into),
+                }
+            }
+        rule taf_flag() -> TafFlag = val:$(quiet!{"AMD" / "COR" / "CNL" / "NIL"} / expected!("TAF flag")) { TafFlag::try_from(val).unwrap() };
+
+        rule day_hour() -> ZuluDayHour = day_of_month:$(digit()*<2>) hour:$(digit()*<2>) {
+            ZuluDayHour {
+                day_of_month: day_of_month.parse().unwrap(),
+                hour: hour.parse().unwrap(),
+            }
+        }
+        pub rule day_hour_range() -> ZuluDayHourRange = begin:day_hour() "/" end:day_hour() {
+            ZuluDayHourRange {
+                begin,
+                end,
+            }
+        }
+
+        rule taf_forecast() -> TafForecast<'input> =
+                wind:wind()? whitespace()
+                cavok:("CAVOK" whitespace())?
+                visibility:visibility()? whitespace()
+                weather:weather_sequence()? whitespace()
+                no_significant_weather:("NSW" whitespace())?
+                cloud_cover:cloud_cover() ** whitespace()
+                wind_shear:(whitespace() wind_shear:wind_shear() { wind_shear })? {
+            TafForecast {
+                wind: wind.flatten(),
+                visibility: visibility.flatten(),
+                weather: weather.unwrap_or_default(),
+                no_significant_weather: no_significant_weather.is_some(),
+                cloud_cover: cloud_cover.iter().copied().flatten().collect(),
+                cavok: cavok.is_some(),
+                wind_shear,
+            }
+        }
+
+        rule taf_change_group() -> TafChangeGroup<'input> = indicator:taf_change_indicator() whitespace() forecast:taf_forecast() {
+            TafChangeGroup {
+                indicator,
+                forecast,
+            }
+        }
+        pub rule taf_change_indicator() -> TafChangeIndicator =
+            "FM" time:observation_time() { TafChangeIndicator::From(time) }
+            / "BECMG" whitespace() period:day_hour_range() { TafChangeIndicator::Becoming(period) }
+            / "TEMPO" whitespace() period:day_hour_range() { TafChangeIndicator::Temporarily(period) }
+            / "PROB" probability:$(digit()*<2>) whitespace() "TEMPO" whitespace() period:day_hour_range() {
+                TafChangeIndicator::ProbabilityTemporarily {
+                    probability: probability.parse().unwrap(),
+                    period,
+                }
+            }
+            / "PROB" probability:$(digit()*<2>) whitespace() period:day_hour_range() {
+                TafChangeIndicator::Probability {
+                    probability: probability.parse().unwrap(),
+                    period,
+                }
+            }
+            / expected!("change indicator")
+
+        pub rule temperature_forecast() -> TemperatureForecast = forecast_type:temperature_forecast_type() temperature:temperature() "/" time:day_hour() "Z" {
+            TemperatureForecast {
+                forecast_type,
+                temperature,
+                time,
+            }
+        }
+        rule temperature_forecast_type() -> TemperatureForecastType = val:$(quiet!{"TX" / "TN"} / expected!("temperature forecast type")) { TemperatureForecastType::try_from(val).unwrap() }
+
         rule report_name() -> &'input str = quiet!{$("METAR" / "SPECI")} / expected!("report name");
 
         pub rule icao_identifier() -> &'input str = $(quiet!{letter() letter_or_digit()*<3>} / expected!("ICAO identifier"));
@@ -94,8 +213,10 @@ peg::parser! {
                     / " "
                     / "\r\n"
                     / "\n"
+                    // GTS bulletins end lines with CR CR LF
+                    / "\r"
                     / "\t"
-                    ">"
+                    / ">"
                 )+
             }
             / expected!("whitespace");
@@ -256,7 +377,7 @@ peg::parser! {
         pub rule runway_visibility() -> Option<RunwayVisibility<'input>> =
             "R" designator:designator() "/" !runway_report_info() range:raw_runway_visibility_range() trend:visibility_trend()? "/"? {
                 Some(RunwayVisibility {
-                    designator,
+                    designator: designator.into(),
                     visibility: VisibilityType::Varying {
                         lower: range.0,
                         upper: range.1,
@@ -266,7 +387,7 @@ peg::parser! {
             }
             / "R" designator:designator() "/" !runway_report_info() visibility:raw_runway_visibility() trend:visibility_trend()? "/"? {
                 Some(RunwayVisibility {
-                    designator,
+                    designator: designator.into(),
                     visibility: VisibilityType::Fixed(visibility),
                     trend,
                 })
@@ -321,23 +442,95 @@ peg::parser! {
 
         pub rule runway_report() -> Option<RunwayReport<'input>> =
             "R" designator:designator() "/" report_info:runway_report_info() {
+                Some(RunwayReport {
+                    designator: designator.into(),
+                    report_info,
+                })
+            }
+            // MOTNE groups without the R prefix, where 50 is added to the designator of right-hand parallel runways
+            / designator:$(digit()*<2>) report_info:runway_contamination() &required_whitespace_or_eof() {
+                let designator = match designator.parse::<u8>().unwrap() {
+                    right @ 51..=86 => format!("{:02}R", right - 50).into(),
+                    _ => designator.into(),
+                };
                 Some(RunwayReport {
                     designator,
                     report_info,
                 })
             }
+            / ("R" "/"?)? "SNOCLO" &required_whitespace_or_eof() {
+                Some(RunwayReport {
+                    designator: "".into(),
+                    report_info: RunwayReportInfo::ClosedBySnow,
+                })
+            }
         rule runway_report_info() -> RunwayReportInfo =
-            "CLRD" friction:$("//" / digit()+) {
+            "CLRD" friction:runway_friction() {
                 RunwayReportInfo::Cleared {
-                    friction: if friction == "//" { None } else { Some(friction.parse::<f64>().unwrap()) }
+                    friction,
+                }
+            }
+            / "SNOCLO" { RunwayReportInfo::ClosedBySnow }
+            / runway_contamination:runway_contamination() &required_whitespace_or_eof() { runway_contamination }
+        rule runway_contamination() -> RunwayReportInfo =
+            deposit:("/" { None } / val:$(quiet!{digit()} / expected!("runway deposit")) { Some(RunwayDeposit::try_from(val).unwrap()) })
+            extent:("/" { None } / val:$(quiet!{"1" / "2" / "5" / "9"} / expected!("extent of runway contamination")) { Some(RunwayContaminationExtent::try_from(val).unwrap()) })
+            depth:deposit_depth()
+            friction:runway_friction() {
+                RunwayReportInfo::Contaminated {
+                    deposit,
+                    extent,
+                    depth,
+                    friction,
+                }
+            }
+        rule deposit_depth() -> Option<DepositDepth> =
+            "//" { None }
+            / val:$(quiet!{digit()*<2>} / expected!("depth of deposit")) {?
+                let out_of_range = match val {
+                    "00" => Some(OutOfRange::Below),
+                    "98" => Some(OutOfRange::Above),
+                    _ => None,
+                };
+                let depth = match val.parse::<u8>().unwrap() {
+                    0 => Length::new::<millimeter>(1.),
+                    99 => return Ok(Some(DepositDepth::NotOperational)),
+                    millimeters @ 1..=90 => Length::new::<millimeter>(millimeters.into()),
+                    91 => return Err("depth of deposit"),
+                    // 92 is 10cm, in 5cm steps up to 98
+                    code => Length::new::<centimeter>(f64::from(code - 92) * 5. + 10.),
+                };
+                Ok(Some(DepositDepth::Depth { depth, out_of_range }))
+            }
+        rule runway_friction() -> Option<RunwayFriction> =
+            "//" { None }
+            / val:$(quiet!{digit()*<2>} / expected!("friction coefficient or braking action")) {?
+                match val.parse::<u8>().unwrap() {
+                    coefficient @ 0..=90 => Ok(Some(RunwayFriction::Coefficient(f64::from(coefficient) / 100.))),
+                    91..=95 => Ok(Some(RunwayFriction::BrakingAction(BrakingAction::try_from(val).unwrap()))),
+                    99 => Ok(Some(RunwayFriction::Unreliable)),
+                    _ => Err("friction coefficient or braking action"),
                 }
             }
 
         rule designator() -> &'input str = $(quiet!{digit()+ ("L"/"C"/"R"/"D")?} / expected!("runway designator"));
 
 
+        pub rule wind_shear() -> WindShear<'input> =
+            "WS" whitespace() "ALL" whitespace() "RWY" &required_whitespace_or_eof() { WindShear::AllRunways }
+            / "WS" whitespace() ("RWY" / "R") designator:designator() &required_whitespace_or_eof() { WindShear::Runway(designator.into()) }
+            / "WS" height:$(digit()*<3>) "/" wind:wind() {?
+                match wind {
+                    Some(wind) => Ok(WindShear::LowLevel {
+                        height: Length::new::<foot>(height.parse::<f64>().unwrap() * 100.),
+                        wind,
+                    }),
+                    None => Err("wind shear"),
+                }
+            }
+
         rule recent_weather_sequence() -> Vec<Weather> = recent_weather:recent_weather() ++ whitespace() &required_whitespace_or_eof() {
-            recent_weather.iter().cloned().flatten().collect()
+            recent_weather.into_iter().flatten().collect()
         }
         rule recent_weather() -> Option<Weather> =
             "RE" weather:weather() &required_whitespace_or_eof() { Some(weather) }
@@ -515,8 +708,10 @@ peg::parser! {
         pub rule pressure() -> Option<Pressure> =
             pressure_unit:pressure_unit() whitespace() pressure:$(digit()+ ("." digit()+)?) {
                 match pressure_unit {
-                    "A" => Some(Pressure::new::<hectopascal>(pressure.parse().unwrap())),
-                    _ => Some(Pressure::new::<inch_of_mercury>(pressure.parse::<f64>().unwrap() / 100.))
+                    // Altimeter settings are in hundredths of an inch of mercury, unless a decimal point is included
+                    "A" if pressure.contains('.') => Some(Pressure::new::<inch_of_mercury>(pressure.parse().unwrap())),
+                    "A" => Some(Pressure::new::<inch_of_mercury>(pressure.parse::<f64>().unwrap() / 100.)),
+                    _ => Some(Pressure::new::<hectopascal>(pressure.parse().unwrap())),
                 }
             }
             / pressure_unit() whitespace() ("////" / "NIL") { None }
@@ -562,7 +757,107 @@ peg::parser! {
                 }
             }
 
-        rule trend() -> Trend =
+        /// Decodes the remark section of reports from stations in the United States
+        pub rule remark_section() -> RemarkSection<'input> =
+            (":RMK" / "R MK"/ "RMK" / "REMARK") whitespace()
+            groups:remark_group() ** whitespace() whitespace()
+            quiet!{"=" [_]*}? {
+                let mut section = RemarkSection::default();
+                for group in groups {
+                    match group {
+                        RemarkGroup::StationType(station_type) => section.station_type = Some(station_type),
+                        RemarkGroup::PeakWind(peak_wind) => section.peak_wind = Some(peak_wind),
+                        RemarkGroup::WindShift(wind_shift) => section.wind_shift = Some(wind_shift),
+                        RemarkGroup::PressureChange(pressure_change) => section.pressure_change = Some(pressure_change),
+                        RemarkGroup::SeaLevelPressure(pressure) => section.sea_level_pressure = Some(pressure),
+                        RemarkGroup::HourlyPrecipitation(amount) => section.hourly_precipitation = amount,
+                        RemarkGroup::Precipitation3Or6Hour(amount) => section.precipitation_3_or_6_hour = amount,
+                        RemarkGroup::Precipitation24Hour(amount) => section.precipitation_24_hour = amount,
+                        RemarkGroup::PreciseTemperatures(temperatures) => section.precise_temperatures = Some(temperatures),
+                        RemarkGroup::MaximumTemperature6Hour(temperature) => section.maximum_temperature_6_hour = Some(temperature),
+                        RemarkGroup::MinimumTemperature6Hour(temperature) => section.minimum_temperature_6_hour = Some(temperature),
+                        RemarkGroup::TemperatureExtremes24Hour(extremes) => section.temperature_extremes_24_hour = Some(extremes),
+                        RemarkGroup::PressureTendency(tendency) => section.pressure_tendency = Some(tendency),
+                        RemarkGroup::SensorStatus(status) => section.sensor_status.push(status),
+                        RemarkGroup::Unparsed(group) => section.unparsed.push(group.into()),
+                    }
+                }
+                section
+            }
+        rule remark_group() -> RemarkGroup<'input> =
+            val:$(quiet!{"AO1" / "AO2" / "A01" / "A02"} / expected!("automated station type")) remark_group_end() {
+                RemarkGroup::StationType(AutomatedStationType::try_from(val).unwrap())
+            }
+            / "PK" whitespace() "WND" whitespace() direction:$(digit()*<3>) speed:$(digit()*<2,3>) "/" hour:(&(digit()*<4>) hour:$(digit()*<2>) { hour })? minute:$(digit()*<2>) remark_group_end() {
+                RemarkGroup::PeakWind(PeakWind {
+                    direction: Angle::new::<degree>(direction.parse().unwrap()),
+                    speed: Velocity::new::<knot>(speed.parse().unwrap()),
+                    hour: hour.map(|hour| hour.parse().unwrap()),
+                    minute: minute.parse().unwrap(),
+                })
+            }
+            / "WSHFT" whitespace() hour:(&(digit()*<4>) hour:$(digit()*<2>) { hour })? minute:$(digit()*<2>) frontal_passage:(whitespace() "FROPA")? remark_group_end() {
+                RemarkGroup::WindShift(WindShift {
+                    hour: hour.map(|hour| hour.parse().unwrap()),
+                    minute: minute.parse().unwrap(),
+                    frontal_passage: frontal_passage.is_some(),
+                })
+            }
+            / val:$(quiet!{"PRESRR" / "PRESFR"} / expected!("pressure change")) remark_group_end() {
+                RemarkGroup::PressureChange(PressureChange::try_from(val).unwrap())
+            }
+            / "SLP" pressure:$(digit()*<3>) remark_group_end() {
+                let tenths = pressure.parse::<f64>().unwrap();
+                // Only the last three digits are reported, i.e. 982.5 hPa is SLP825 and 1013.2 hPa is SLP132
+                RemarkGroup::SeaLevelPressure(Pressure::new::<hectopascal>(if tenths >= 500. { 900. } else { 1000. } + tenths / 10.))
+            }
+            / "P" amount:precipitation_amount() remark_group_end() { RemarkGroup::HourlyPrecipitation(amount) }
+            / "6" amount:precipitation_amount() remark_group_end() { RemarkGroup::Precipitation3Or6Hour(amount) }
+            / "7" amount:precipitation_amount() remark_group_end() { RemarkGroup::Precipitation24Hour(amount) }
+            / "T" air:precise_temperature() dewpoint:precise_temperature()? remark_group_end() {
+                RemarkGroup::PreciseTemperatures(Temperatures {
+                    air,
+                    dewpoint,
+                })
+            }
+            / "1" temperature:precise_temperature() remark_group_end() { RemarkGroup::MaximumTemperature6Hour(temperature) }
+            / "2" temperature:precise_temperature() remark_group_end() { RemarkGroup::MinimumTemperature6Hour(temperature) }
+            / "4" maximum:precise_temperature() minimum:precise_temperature() remark_group_end() {
+                RemarkGroup::TemperatureExtremes24Hour(TemperatureExtremes {
+                    maximum,
+                    minimum,
+                })
+            }
+            / "5" characteristic:$(['0'..='8']) change:$(digit()*<3>) remark_group_end() {
+                let characteristic = PressureTendencyCharacteristic::try_from(characteristic).unwrap();
+                let change = Pressure::new::<hectopascal>(change.parse::<f64>().unwrap() / 10.);
+                RemarkGroup::PressureTendency(PressureTendency {
+                    characteristic,
+                    change: match characteristic {
+                        PressureTendencyCharacteristic::DecreasingThenIncreasing
+                        | PressureTendencyCharacteristic::DecreasingThenSteady
+                        | PressureTendencyCharacteristic::Decreasing
+                        | PressureTendencyCharacteristic::SteadyOrIncreasingThenDecreasing => -change,
+                        _ => change,
+                    },
+                })
+            }
+            / val:$(quiet!{"PWINO" / "PNO" / "FZRANO" / "TSNO" / "RVRNO" / "VISNO" / "CHINO" / "SLPNO"} / expected!("sensor status")) remark_group_end() {
+                RemarkGroup::SensorStatus(SensorStatus::try_from(val).unwrap())
+            }
+            / group:$((!(required_whitespace() / "=") [_])+) { RemarkGroup::Unparsed(group) }
+        rule remark_group_end() = &(required_whitespace_or_eof() / "=")
+        /// Hundredths of an inch, or slashes if indeterminate
+        rule precipitation_amount() -> Option<Length> =
+            amount:$(digit()*<4>) { Some(Length::new::<inch>(amount.parse::<f64>().unwrap() / 100.)) }
+            / "////" { None }
+        /// Sign digit followed by tenths of a degree celsius
+        rule precise_temperature() -> ThermodynamicTemperature = sign:$(['0' | '1']) temperature:$(digit()*<3>) {
+            let temperature = temperature.parse::<f64>().unwrap() / 10.;
+            ThermodynamicTemperature::new::<degree_celsius>(if sign == "1" { -temperature } else { temperature })
+        }
+
+        pub rule trend() -> Trend =
             $(quiet!{"NOSIG" / "NOISIG" / "N0SIG" / "NOS16" / "NOSING" / "NOSG" / "NSG"} / expected!("trend")) {
                 Trend::NoSignificantChange
             }
@@ -0,0 +1,156 @@
+pub(crate) mod parser;
+
+use crate::error::{ParseError, ParseErrorKind};
+use crate::tokens::{AirepReport, PirepReport};
+
+#[cfg(feature = "codespan_helpers")]
+pub use crate::error::into_diagnostic;
+
+/// Parses a US pilot report, starting with `UA` or `UUA` and an optional station identifier
+pub fn pirep(input: &str) -> Result<PirepReport<'_>, ParseError> {
+    parser::pilot_reports::pirep(input)
+        .map_err(|err| ParseError::new(input, err).reclassify(ParseErrorKind::PilotReport))
+}
+
+/// Parses an ICAO air report, starting with `ARP` or `ARS`
+pub fn airep(input: &str) -> Result<AirepReport<'_>, ParseError> {
+    parser::pilot_reports::airep(input)
+        .map_err(|err| ParseError::new(input, err).reclassify(ParseErrorKind::PilotReport))
+}
+
+#[cfg(test)]
+mod tests {
+    use uom::si::{
+        angle::degree, length::foot, length::nautical_mile,
+        thermodynamic_temperature::degree_celsius, velocity::knot,
+    };
+
+    use super::{airep, pirep};
+    use crate::error::ParseErrorKind;
+    use crate::tokens::*;
+
+    #[test]
+    fn parse_pirep() {
+        let report = pirep("OKC UA /OV OKC063015/TM 1522/FL085/TP C172/SK BKN065-TOP090/OVC200/WX FV03SM HZ FU/TA M05/WV 270045KT/TB OCNL LGT-MOD CHOP 080-100/IC LGT RIME/RM SMOOTH ABV 100").unwrap();
+        assert_eq!(report.station.as_deref(), Some("OKC"));
+        assert!(!report.urgent);
+        assert_eq!(report.location.len(), 1);
+        assert_eq!(report.location[0].fix, "OKC");
+        assert_eq!(
+            report.location[0].radial.unwrap().get::<degree>().round(),
+            63.
+        );
+        assert_eq!(
+            report.location[0]
+                .distance
+                .unwrap()
+                .get::<nautical_mile>()
+                .round(),
+            15.
+        );
+        assert_eq!(
+            report.time,
+            Some(ZuluTime {
+                hour: 15,
+                minute: 22
+            })
+        );
+        assert_eq!(report.altitude.unwrap().get::<foot>().round(), 8500.);
+        assert_eq!(report.aircraft_type.as_deref(), Some("C172"));
+        assert_eq!(report.sky_condition.len(), 2);
+        assert_eq!(
+            report.sky_condition[0].cover.coverage,
+            CloudCoverage::Broken
+        );
+        assert_eq!(
+            report.sky_condition[0].top.unwrap().get::<foot>().round(),
+            9000.
+        );
+        assert_eq!(
+            report.sky_condition[1].cover.coverage,
+            CloudCoverage::Overcast
+        );
+        assert_eq!(report.weather.len(), 2);
+        assert!(report.flight_visibility.is_some());
+        assert_eq!(
+            report.temperature.unwrap().get::<degree_celsius>().round(),
+            -5.
+        );
+        assert_eq!(
+            report.wind.unwrap().speed.unwrap().get::<knot>().round(),
+            45.
+        );
+        assert_eq!(
+            report.turbulence,
+            vec![Turbulence {
+                intensity: TurbulenceIntensity::LightModerate,
+                frequency: Some(TurbulenceFrequency::Occasional),
+                turbulence_type: Some(TurbulenceType::Chop),
+                base: report.turbulence[0].base,
+                top: report.turbulence[0].top,
+            }]
+        );
+        assert_eq!(
+            report.turbulence[0].top.unwrap().get::<foot>().round(),
+            10000.
+        );
+        assert_eq!(report.icing[0].intensity, IcingIntensity::Light);
+        assert_eq!(report.icing[0].icing_type, Some(IcingType::Rime));
+        assert_eq!(report.remark.as_deref(), Some("SMOOTH ABV 100"));
+    }
+
+    #[test]
+    fn parse_urgent_pirep() {
+        let report =
+            pirep("UUA /OV KOKC-TUL/TM 0300/FLDURD/TP B738/TB SEV CAT BLO 050/IC NEG").unwrap();
+        assert!(report.urgent);
+        assert_eq!(report.station, None);
+        assert_eq!(
+            report
+                .location
+                .iter()
+                .map(|location| location.fix.as_ref())
+                .collect::<Vec<_>>(),
+            vec!["KOKC", "TUL"]
+        );
+        assert_eq!(report.flight_phase, Some(FlightPhase::Descent));
+        assert_eq!(report.turbulence[0].intensity, TurbulenceIntensity::Severe);
+        assert_eq!(report.turbulence[0].base, None);
+        assert!(report.turbulence[0].top.is_some());
+        assert_eq!(report.icing[0].intensity, IcingIntensity::Negative);
+        assert!(TurbulenceIntensity::Severe > TurbulenceIntensity::ModerateSevere);
+    }
+
+    #[test]
+    fn parse_airep() {
+        let report =
+            airep("ARS BAW123 5030N02000W 1415 F350 MS48 250/045 TURB SEV ICE NIL").unwrap();
+        assert!(report.special);
+        assert_eq!(report.aircraft_identification, "BAW123");
+        match report.position {
+            AirepPosition::Coordinates(coordinates) => {
+                assert_eq!(coordinates.latitude.get::<degree>(), 50.5);
+                assert_eq!(coordinates.longitude.get::<degree>(), -20.);
+            }
+            other => panic!("{:?}", other),
+        }
+        assert_eq!(report.level.get::<foot>().round(), 35000.);
+        assert_eq!(
+            report.temperature.unwrap().get::<degree_celsius>().round(),
+            -48.
+        );
+        assert_eq!(report.turbulence, Some(TurbulenceIntensity::Severe));
+        assert_eq!(report.icing, Some(IcingIntensity::Negative));
+        assert_eq!(report.remark, None);
+
+        let report = airep("ARP UAL1 DENNY 0930 A080").unwrap();
+        assert_eq!(report.position, AirepPosition::Fix("DENNY".into()));
+    }
+
+    #[test]
+    fn report_pilot_report_errors() {
+        let val = "OKC UA /OV OKC063015/TM 1522/TB MODERATE";
+        let err = pirep(val).unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::PilotReport);
+    }
+}
@@ -0,0 +1,286 @@
+use std::convert::TryFrom;
+use uom::{
+    si::angle::degree,
+    si::f64::{Angle, Length, ThermodynamicTemperature, Velocity},
+    si::length::{foot, mile, nautical_mile},
+    si::thermodynamic_temperature::degree_celsius,
+    si::velocity::knot,
+};
+
+use crate::metar::parse::parser::weather_reports;
+use crate::tokens::*;
+use crate::utils::degrees_and_minutes;
+
+/// A single field of a [PirepReport], i.e. `/TB MOD`
+enum PirepField<'input> {
+    Location(Vec<PirepLocation<'input>>),
+    Time(ZuluTime),
+    Altitude {
+        altitude: Option<Length>,
+        flight_phase: Option<FlightPhase>,
+    },
+    AircraftType(&'input str),
+    SkyCondition(Vec<PirepCloudLayer>),
+    Weather {
+        flight_visibility: Option<Length>,
+        weather: Vec<Weather>,
+    },
+    Temperature(ThermodynamicTemperature),
+    Wind(Wind),
+    Turbulence(Vec<Turbulence>),
+    Icing(Vec<Icing>),
+    Remark(&'input str),
+}
+
+peg::parser! {
+    pub grammar pilot_reports() for str {
+        /// US [PIREP](https://en.wikipedia.org/wiki/Pilot_report) parser
+        pub rule pirep() -> PirepReport<'input> =
+                    whitespace()
+                    station:(!report_type() station:$(quiet!{letter() letter_or_digit()*<2,3>} / expected!("station identifier")) required_whitespace() { station })?
+                    report_type:report_type()
+                    fields:(whitespace() "/" whitespace() field:pirep_field() { field })*
+                    whitespace()
+                    // Some machines use = to indicate end of message
+                    quiet!{"=" [_]*}? whitespace()
+                    {
+                let mut report = PirepReport {
+                    station: station.map(Into::into),
+                    urgent: report_type == "UUA",
+                    location: vec![],
+                    time: None,
+                    altitude: None,
+                    flight_phase: None,
+                    aircraft_type: None,
+                    sky_condition: vec![],
+                    flight_visibility: None,
+                    weather: vec![],
+                    temperature: None,
+                    wind: None,
+                    turbulence: vec![],
+                    icing: vec![],
+                    remark: None,
+                };
+                for field in fields {
+                    match field {
+                        PirepField::Location(location) => report.location = location,
+                        PirepField::Time(time) => report.time = Some(time),
+                        PirepField::Altitude { altitude, flight_phase } => {
+                            report.altitude = altitude;
+                            report.flight_phase = flight_phase;
+                        }
+                        PirepField::AircraftType(aircraft_type) => report.aircraft_type = Some(aircraft_type.into()),
+                        PirepField::SkyCondition(layers) => report.sky_condition = layers,
+                        PirepField::Weather { flight_visibility, weather } => {
+                            report.flight_visibility = flight_visibility;
+                            report.weather = weather;
+                        }
+                        PirepField::Temperature(temperature) => report.temperature = Some(temperature),
+                        PirepField::Wind(wind) => report.wind = Some(wind),
+                        PirepField::Turbulence(turbulence) => report.turbulence = turbulence,
+                        PirepField::Icing(icing) => report.icing = icing,
+                        PirepField::Remark(remark) => report.remark = Some(remark.into()),
+                    }
+                }
+                report
+            }
+
+        /// ICAO AIREP parser
+        pub rule airep() -> AirepReport<'input> =
+                    whitespace()
+                    report_type:$(quiet!{"ARP" / "ARS"} / expected!("ARP or ARS")) required_whitespace()
+                    aircraft_identification:$(quiet!{letter_or_digit()+} / expected!("aircraft identification")) required_whitespace()
+                    position:airep_position() required_whitespace()
+                    time:zulu_time() required_whitespace()
+                    level:airep_level()
+                    temperature:(required_whitespace() temperature:airep_temperature() { temperature })?
+                    wind:(required_whitespace() wind:airep_wind() { wind })?
+                    turbulence:(required_whitespace() "TURB" required_whitespace() intensity:turbulence_intensity() { intensity })?
+                    icing:(required_whitespace() "ICE" required_whitespace() intensity:icing_intensity() { intensity })?
+                    remark:(required_whitespace() remark:$([^'=']+) { remark })?
+                    whitespace()
+                    quiet!{"=" [_]*}? whitespace()
+                    {
+                AirepReport {
+                    special: report_type == "ARS",
+                    aircraft_identification: aircraft_identification.into(),
+                    position,
+                    time,
+                    level,
+                    temperature,
+                    wind,
+                    turbulence,
+                    icing,
+                    remark: remark.map(str::trim_end).map(Into::into),
+                }
+            }
+
+        rule report_type() -> &'input str = val:$(quiet!{"UUA" / "UA"} / expected!("UA or UUA")) field_end() { val }
+
+        rule pirep_field() -> PirepField<'input> =
+            "OV" whitespace() location:location() ++ (whitespace() "-" whitespace()) field_end() { PirepField::Location(location) }
+            / "TM" whitespace() time:zulu_time() field_end() { PirepField::Time(time) }
+            / "FL" whitespace() altitude:altitude() field_end() {
+                let (altitude, flight_phase) = altitude;
+                PirepField::Altitude { altitude, flight_phase }
+            }
+            / "TP" whitespace() aircraft_type:$([^'/' | '=']+) { PirepField::AircraftType(aircraft_type.trim_end()) }
+            / "SK" whitespace() layers:sky_layer() ++ layer_separator() field_end() { PirepField::SkyCondition(layers) }
+            / "WX" whitespace() flight_visibility:flight_visibility()? whitespace() weather:weather() ** required_whitespace() field_end() {
+                PirepField::Weather { flight_visibility, weather }
+            }
+            / "TA" whitespace() temperature:temperature() field_end() { PirepField::Temperature(temperature) }
+            / "WV" whitespace() wind:pirep_wind() field_end() { PirepField::Wind(wind) }
+            / "TB" whitespace() turbulence:turbulence() ++ required_whitespace() field_end() { PirepField::Turbulence(turbulence) }
+            / "IC" whitespace() icing:icing() ++ required_whitespace() field_end() { PirepField::Icing(icing) }
+            / "RM" whitespace() remark:$([^'=']*) { PirepField::Remark(remark.trim_end()) }
+            / expected!("PIREP field")
+        rule pirep_field_tag() = ("OV" / "TM" / "FL" / "TP" / "SK" / "WX" / "TA" / "WV" / "TB" / "IC" / "RM") !letter()
+        rule field_end() = &(whitespace() ("/" / "=" / ![_]))
+
+        /// A fix, optionally followed by the radial and distance in nautical miles from it
+        rule location() -> PirepLocation<'input> =
+            fix:$(letter()+) radial:$(digit()*<3>) distance:$(digit()*<3>) {
+                PirepLocation {
+                    fix: fix.into(),
+                    radial: Some(Angle::new::<degree>(radial.parse().unwrap())),
+                    distance: Some(Length::new::<nautical_mile>(distance.parse().unwrap())),
+                }
+            }
+            / fix:$(quiet!{letter_or_digit()+} / expected!("location")) {
+                PirepLocation {
+                    fix: fix.into(),
+                    radial: None,
+                    distance: None,
+                }
+            }
+
+        rule altitude() -> (Option<Length>, Option<FlightPhase>) =
+            flight_phase:flight_phase() { (None, Some(flight_phase)) }
+            / "UNKN" { (None, None) }
+            / altitude:hundreds_of_feet() { (Some(altitude), None) }
+        rule flight_phase() -> FlightPhase = val:$(quiet!{"DURC" / "DURD"} / expected!("flight phase")) { FlightPhase::try_from(val).unwrap() }
+
+        /// Layers are separated by spaces or by slashes that don't start another field
+        rule layer_separator() = (whitespace() "/" !pirep_field_tag() whitespace()) / required_whitespace()
+        rule sky_layer() -> PirepCloudLayer =
+            cover:cloud_cover() top:(whitespace() "-"? whitespace() "TOP" "S"? whitespace() top:("UNKN" { None } / top:hundreds_of_feet() { Some(top) }) { top })? {
+                PirepCloudLayer {
+                    cover,
+                    top: top.flatten(),
+                }
+            }
+        rule cloud_cover() -> CloudCover =
+            cover:$(quiet!{letter()+ digit()*<3>} / expected!("cloud cover")) {?
+                match weather_reports::cloud_cover(cover) {
+                    Ok(Some(cover)) => Ok(cover),
+                    _ => Err("cloud cover"),
+                }
+            }
+
+        rule flight_visibility() -> Length = "FV" miles:$(digit()+) "SM" { Length::new::<mile>(miles.parse().unwrap()) }
+        rule weather() -> Weather =
+            val:$(quiet!{(!(required_whitespace() / "/" / "=") [_])+} / expected!("weather")) {?
+                weather_reports::weather(val).map_err(|_| "weather")
+            }
+
+        rule temperature() -> ThermodynamicTemperature =
+            sign:$("M" / "-" / "+")? degrees:$(digit()*<1,2>) !digit() {
+                let degrees = degrees.parse::<f64>().unwrap();
+                ThermodynamicTemperature::new::<degree_celsius>(if matches!(sign, Some("M") | Some("-")) { -degrees } else { degrees })
+            }
+        rule pirep_wind() -> Wind =
+            direction:$(digit()*<3>) speed:$(digit()*<2,3>) "KT"? {
+                wind(direction, speed)
+            }
+
+        rule turbulence() -> Turbulence =
+            frequency:(frequency:turbulence_frequency() required_whitespace() { frequency })?
+            intensity:turbulence_intensity()
+            turbulence_type:(required_whitespace() turbulence_type:turbulence_type() { turbulence_type })?
+            range:(required_whitespace() range:altitude_range() { range })? {
+                let (base, top) = range.unwrap_or((None, None));
+                Turbulence {
+                    intensity,
+                    frequency,
+                    turbulence_type,
+                    base,
+                    top,
+                }
+            }
+        rule turbulence_intensity() -> TurbulenceIntensity =
+            val:$(quiet!{"SMTH-LGT" / "SMTH" / "NEG" / "NIL" / "LGT-MOD" / "LGT" / "MOD-SEV" / "MOD" / "SEV-EXTRM" / "SEV" / "EXTRM" / "EXTM"} / expected!("turbulence intensity")) !letter() {
+                TurbulenceIntensity::try_from(val).unwrap()
+            }
+        rule turbulence_type() -> TurbulenceType = val:$(quiet!{"CAT" / "CHOP" / "LLWS" / "MWAVE"} / expected!("turbulence type")) { TurbulenceType::try_from(val).unwrap() }
+        rule turbulence_frequency() -> TurbulenceFrequency = val:$(quiet!{"OCNL" / "INTMT" / "CONS"} / expected!("turbulence frequency")) { TurbulenceFrequency::try_from(val).unwrap() }
+
+        rule icing() -> Icing =
+            intensity:icing_intensity()
+            icing_type:(required_whitespace() icing_type:icing_type() { icing_type })?
+            range:(required_whitespace() range:altitude_range() { range })? {
+                let (base, top) = range.unwrap_or((None, None));
+                Icing {
+                    intensity,
+                    icing_type,
+                    base,
+                    top,
+                }
+            }
+        rule icing_intensity() -> IcingIntensity =
+            val:$(quiet!{"NEGCLR" / "NEG" / "NIL" / "TRACE-LGT" / "TRC-LGT" / "TRACE" / "TRC" / "LGT-MOD" / "LGT" / "MOD-SEV" / "MOD" / "SEV" / "HVY"} / expected!("icing intensity")) !letter() {
+                IcingIntensity::try_from(val).unwrap()
+            }
+        rule icing_type() -> IcingType = val:$(quiet!{"RIME" / "CLR" / "MIXED" / "MXD" / "MX"} / expected!("icing type")) { IcingType::try_from(val).unwrap() }
+
+        /// Base and top of a layer, i.e. `080-100`, `BLO 050`, or a single altitude
+        rule altitude_range() -> (Option<Length>, Option<Length>) =
+            "BLO" whitespace() top:hundreds_of_feet() { (None, Some(top)) }
+            / "ABV" whitespace() base:hundreds_of_feet() { (Some(base), None) }
+            / base:hundreds_of_feet() "-" top:hundreds_of_feet() { (Some(base), Some(top)) }
+            / altitude:hundreds_of_feet() { (Some(altitude), Some(altitude)) }
+        rule hundreds_of_feet() -> Length = val:$(quiet!{digit()*<3>} / expected!("altitude")) !digit() { Length::new::<foot>(val.parse::<f64>().unwrap() * 100.) }
+
+        rule airep_position() -> AirepPosition<'input> =
+            latitude:$(digit()*<2>) latitude_minutes:$(digit()*<2>)? north_south:$(['N' | 'S'])
+            longitude:$(digit()*<3>) longitude_minutes:$(digit()*<2>)? east_west:$(['E' | 'W']) {
+                AirepPosition::Coordinates(Coordinates {
+                    latitude: degrees_and_minutes(latitude, latitude_minutes, north_south == "S"),
+                    longitude: degrees_and_minutes(longitude, longitude_minutes, east_west == "W"),
+                })
+            }
+            / fix:$(quiet!{letter_or_digit()+} / expected!("position")) { AirepPosition::Fix(fix.into()) }
+        /// Flight level or altitude in hundreds of feet
+        rule airep_level() -> Length = quiet!{['F' | 'A']} level:hundreds_of_feet() { level } / expected!("flight level")
+        rule airep_temperature() -> ThermodynamicTemperature =
+            sign:$(quiet!{"MS" / "PS" / "M" / "P"} / expected!("temperature sign")) degrees:$(digit()*<2>) {
+                let degrees = degrees.parse::<f64>().unwrap();
+                ThermodynamicTemperature::new::<degree_celsius>(if sign.starts_with('M') { -degrees } else { degrees })
+            }
+        rule airep_wind() -> Wind =
+            direction:$(digit()*<3>) "/"? speed:$(digit()*<2,3>) "KT"? {
+                wind(direction, speed)
+            }
+
+        rule zulu_time() -> ZuluTime = hour:$(digit()*<2>) minute:$(digit()*<2>) "Z"? {
+            ZuluTime {
+                hour: hour.parse().unwrap(),
+                minute: minute.parse().unwrap(),
+            }
+        }
+        rule letter() -> &'input str = quiet!{$(['A'..='Z'])} / expected!("letter");
+        rule digit() -> &'input str = quiet!{$(['0'..='9'])} / expected!("digit");
+        rule letter_or_digit() -> &'input str = letter() / digit();
+        rule whitespace() = required_whitespace()?
+        rule required_whitespace() = quiet!{[' ' | '\r' | '\n' | '\t']+} / expected!("whitespace")
+    }
+}
+
+fn wind(direction: &str, speed: &str) -> Wind {
+    Wind {
+        direction: Some(Angle::new::<degree>(direction.parse().unwrap())),
+        speed: Some(Velocity::new::<knot>(speed.parse().unwrap())),
+        peak_gust: None,
+        variance: None,
+    }
+}
@@ -0,0 +1,188 @@
+//! Wind components along and across a runway
+
+use uom::si::angle::degree;
+use uom::si::f64::{Angle, Velocity};
+
+use crate::tokens::*;
+
+/// Wind resolved along and across a runway
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindComponents {
+    /// Negative for a tailwind
+    pub headwind: Velocity,
+    /// Positive for wind from the right of the runway heading, negative from the left
+    pub crosswind: Velocity,
+}
+
+/// Worst-case components of the reported wind and its gusts
+///
+/// The worst headwind and crosswind are found independently, so they may come from different directions within the variance sector.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RunwayWindComponents {
+    pub steady: WindComponents,
+    /// Present if gusts were reported
+    pub gust: Option<WindComponents>,
+}
+
+/// True heading of a runway from its designator, i.e. `27L`
+///
+/// Designators are the magnetic heading in tens of degrees, while reported winds are relative to true north.
+/// `magnetic_variation` is positive to the east. Returns [None] for designators that are not headings, like `88`.
+pub fn runway_heading(designator: &str, magnetic_variation: Angle) -> Option<Angle> {
+    let number = designator.trim_end_matches(&['L', 'C', 'R', 'D'][..]);
+    if number.is_empty() || number.len() > 2 || !number.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    match number.parse::<u8>().ok()? {
+        heading @ 1..=36 => Some(Angle::new::<degree>(
+            (f64::from(heading) * 10. + magnetic_variation.get::<degree>()).rem_euclid(360.),
+        )),
+        _ => None,
+    }
+}
+
+impl Wind {
+    /// Components of the mean wind for a runway with the given true heading
+    ///
+    /// Returns [None] if the wind is variable or its speed was not reported.
+    pub fn components(&self, runway_heading: Angle) -> Option<WindComponents> {
+        let relative = self.direction?.get::<degree>() - runway_heading.get::<degree>();
+        Some(worst_case(self.speed?, relative, 0.))
+    }
+
+    /// Worst-case components of the wind and gusts for a runway with the given true heading
+    ///
+    /// The wind is assumed to blow from anywhere within the variance sector, or from any direction if it is variable (`VRB`).
+    /// Returns [None] if the speed was not reported.
+    pub fn worst_case_components(&self, runway_heading: Angle) -> Option<RunwayWindComponents> {
+        let heading = runway_heading.get::<degree>();
+        let (from, width) = match (self.variance, self.direction) {
+            (Some((begin, end)), _) => (
+                begin.get::<degree>() - heading,
+                (end.get::<degree>() - begin.get::<degree>()).rem_euclid(360.),
+            ),
+            (None, Some(direction)) => (direction.get::<degree>() - heading, 0.),
+            (None, None) => (0., 360.),
+        };
+        Some(RunwayWindComponents {
+            steady: worst_case(self.speed?, from, width),
+            gust: self
+                .peak_gust
+                .map(|peak_gust| worst_case(peak_gust, from, width)),
+        })
+    }
+}
+
+impl<'input> MetarReport<'input> {
+    /// See [Wind::worst_case_components]
+    pub fn runway_wind_components(&self, runway_heading: Angle) -> Option<RunwayWindComponents> {
+        self.wind?.worst_case_components(runway_heading)
+    }
+}
+
+/// Lowest headwind and strongest crosswind for wind blowing from anywhere in the sector
+/// starting at `from` degrees relative to the runway heading and extending `width` degrees clockwise
+fn worst_case(speed: Velocity, from: f64, width: f64) -> WindComponents {
+    let mut candidates = vec![from, from + width];
+    candidates.extend(
+        [90., 180., 270.]
+            .iter()
+            .filter(|critical| (*critical - from).rem_euclid(360.) <= width)
+            .copied(),
+    );
+    let headwind = candidates
+        .iter()
+        .map(|relative| relative.to_radians().cos())
+        .fold(f64::INFINITY, f64::min);
+    let crosswind = candidates
+        .iter()
+        .map(|relative| relative.to_radians().sin())
+        .fold(0., |worst: f64, crosswind| {
+            if crosswind.abs() > worst.abs() {
+                crosswind
+            } else {
+                worst
+            }
+        });
+    WindComponents {
+        headwind: speed * headwind,
+        crosswind: speed * crosswind,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uom::si::{angle::degree, f64::Angle, velocity::knot};
+
+    use super::*;
+    use crate::metar::parse::metar;
+
+    fn knots(components: WindComponents) -> (f64, f64) {
+        (
+            (components.headwind.get::<knot>() * 10.).round() / 10.,
+            (components.crosswind.get::<knot>() * 10.).round() / 10.,
+        )
+    }
+
+    #[test]
+    fn heading_from_designator() {
+        let east = Angle::new::<degree>(10.);
+        assert_eq!(
+            runway_heading("27L", east).unwrap().get::<degree>().round(),
+            280.
+        );
+        assert_eq!(
+            runway_heading("36", Angle::new::<degree>(-5.))
+                .unwrap()
+                .get::<degree>()
+                .round(),
+            355.
+        );
+        for designator in ["88", "99", "00", "", "L"] {
+            assert_eq!(runway_heading(designator, east), None, "{}", designator);
+        }
+    }
+
+    #[test]
+    fn steady_and_gust_components() {
+        let heading = Angle::new::<degree>(270.);
+        let report = metar("KSEA 290353Z 30020G30KT 10SM SCT200 19/09 A3004").unwrap();
+        let wind = report.wind.unwrap();
+        assert_eq!(knots(wind.components(heading).unwrap()), (17.3, 10.));
+        let components = report.runway_wind_components(heading).unwrap();
+        assert_eq!(knots(components.steady), (17.3, 10.));
+        assert_eq!(knots(components.gust.unwrap()), (26., 15.));
+
+        // Wind from the left of runway 09
+        let components = wind
+            .worst_case_components(Angle::new::<degree>(90.))
+            .unwrap();
+        assert_eq!(knots(components.steady), (-17.3, -10.));
+    }
+
+    #[test]
+    fn worst_case_across_variance_and_variable_wind() {
+        let heading = Angle::new::<degree>(270.);
+        let report = metar("EGLL 251450Z 27015KT 210V330 9999 FEW025 14/09 Q1002").unwrap();
+        let components = report.runway_wind_components(heading).unwrap();
+        assert_eq!(knots(components.steady), (7.5, -13.));
+        assert_eq!(components.gust, None);
+
+        // The sector includes a direct crosswind and a slight tailwind
+        let report = metar("EGLL 251450Z 30010KT 250V010 9999 FEW025 14/09 Q1002").unwrap();
+        assert_eq!(
+            knots(report.runway_wind_components(heading).unwrap().steady),
+            (-1.7, 10.)
+        );
+
+        let report = metar("KTPA 300353Z VRB05KT 10SM FEW040 29/21 A2992").unwrap();
+        let wind = report.wind.unwrap();
+        assert_eq!(wind.components(heading), None);
+        assert_eq!(
+            knots(wind.worst_case_components(heading).unwrap().steady),
+            (-5., 5.)
+        );
+    }
+}
@@ -0,0 +1,149 @@
+pub(crate) mod parser;
+
+use crate::error::{ParseError, ParseErrorKind};
+use crate::tokens::SigmetReport;
+
+#[cfg(feature = "codespan_helpers")]
+pub use crate::error::into_diagnostic;
+
+/// Parses a SIGMET or AIRMET advisory
+///
+/// Both the ICAO format and the US SIGMET, convective SIGMET and AIRMET Sierra/Tango/Zulu formats are accepted.
+pub fn sigmet(input: &str) -> Result<SigmetReport<'_>, ParseError> {
+    parser::advisories::sigmet(input)
+        .map_err(|err| ParseError::new(input, err).reclassify(ParseErrorKind::Advisory))
+}
+
+#[cfg(test)]
+mod tests {
+    use uom::si::{angle::degree, length::foot, length::nautical_mile, velocity::knot};
+
+    use super::sigmet;
+    use crate::error::ParseErrorKind;
+    use crate::tokens::*;
+
+    #[test]
+    fn parse_icao_sigmet() {
+        let report = sigmet("YUDD SIGMET 2 VALID 101200/101600 YUSO-\nYUDD SHANLON FIR/UIR OBSC TS FCST WI N2020 W07005 - N2048 W07100 - N2114 W06954 - N2020 W07005 TOP FL390 MOV E 20KT WKN=").unwrap();
+        assert_eq!(report.advisory_type, AdvisoryType::Sigmet);
+        assert_eq!(report.location_indicator.as_deref(), Some("YUDD"));
+        assert_eq!(report.sequence.as_deref(), Some("2"));
+        assert_eq!(
+            report.validity,
+            Some(ZuluTimeRange {
+                begin: ZuluTime {
+                    hour: 12,
+                    minute: 0
+                },
+                end: ZuluTime {
+                    hour: 16,
+                    minute: 0
+                },
+            })
+        );
+        assert_eq!(report.valid_until_day_of_month, Some(10));
+        assert_eq!(report.originator.as_deref(), Some("YUSO"));
+        assert_eq!(report.region.as_deref(), Some("YUDD SHANLON FIR/UIR"));
+        assert_eq!(
+            report.phenomena,
+            vec![SigmetPhenomenon::Thunderstorms {
+                distribution: Some(ThunderstormDistribution::Obscured),
+                severe: false,
+                hail: false,
+            }]
+        );
+        assert_eq!(report.observation, Some(ObservationStatus::Forecast));
+        match &report.areas[..] {
+            [SigmetArea::Polygon(polygon)] => {
+                assert_eq!(polygon.len(), 4);
+                assert!((polygon[0].latitude.get::<degree>() - 20.333).abs() < 0.001);
+                assert!((polygon[0].longitude.get::<degree>() + 70.083).abs() < 0.001);
+                assert_eq!(polygon[0], polygon[3]);
+            }
+            areas => panic!("unexpected areas {:?}", areas),
+        }
+        assert_eq!(report.base, None);
+        assert_eq!(report.top.unwrap().get::<foot>().round(), 39000.);
+        let movement = report.movement.unwrap();
+        assert_eq!(movement.direction.unwrap().get::<degree>().round(), 90.);
+        assert_eq!(movement.speed.unwrap().get::<knot>().round(), 20.);
+        assert_eq!(report.intensity_change, Some(IntensityChange::Weakening));
+        assert!(report.unparsed.is_empty());
+
+        let cancellation =
+            sigmet("YUDD SIGMET 3 VALID 101345/101600 YUSO-\nYUDD SHANLON FIR/UIR CNL SIGMET 2 101200/101600=")
+                .unwrap();
+        assert_eq!(cancellation.cancelled.as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn parse_us_advisories() {
+        let report = sigmet("BOSN WS 251455\nSIGMET NOVEMBER 3 VALID UNTIL 251855\nME NH VT\nFROM 30NW YQB TO 40SE YSC TO 20E BGR TO 30NW YQB\nOCNL SEV TURB BTN FL250 AND FL380. DUE TO JTST. CONDS CONTG BYD 1855Z.").unwrap();
+        assert_eq!(report.advisory_type, AdvisoryType::Sigmet);
+        assert_eq!(report.sequence.as_deref(), Some("NOVEMBER 3"));
+        assert_eq!(
+            report.validity.unwrap().begin,
+            ZuluTime {
+                hour: 14,
+                minute: 55
+            }
+        );
+        assert_eq!(report.region.as_deref(), Some("ME NH VT"));
+        assert_eq!(
+            report.phenomena,
+            vec![SigmetPhenomenon::Turbulence(TurbulenceIntensity::Severe)]
+        );
+        match &report.areas[..] {
+            [SigmetArea::Points(points)] => {
+                assert_eq!(points.len(), 4);
+                assert_eq!(points[0].fix, "YQB");
+                assert_eq!(points[0].direction.unwrap().get::<degree>().round(), 315.);
+                assert_eq!(
+                    points[0].distance.unwrap().get::<nautical_mile>().round(),
+                    30.
+                );
+            }
+            areas => panic!("unexpected areas {:?}", areas),
+        }
+        assert_eq!(report.base.unwrap().get::<foot>().round(), 25000.);
+        assert_eq!(report.top.unwrap().get::<foot>().round(), 38000.);
+        assert!(report.unparsed.iter().any(|word| word == "JTST"));
+
+        let convective = sigmet("MKCE WST 251455\nCONVECTIVE SIGMET 25C\nVALID UNTIL 1655Z\nKS OK TX\nFROM 30SW ICT-40E OKC-50W ABI-30SW ICT\nAREA SEV TS MOV FROM 24025KT. TOPS ABV FL450.").unwrap();
+        assert_eq!(convective.advisory_type, AdvisoryType::ConvectiveSigmet);
+        assert_eq!(convective.sequence.as_deref(), Some("25C"));
+        assert_eq!(convective.region.as_deref(), Some("KS OK TX"));
+        assert_eq!(
+            convective.phenomena,
+            vec![SigmetPhenomenon::Thunderstorms {
+                distribution: Some(ThunderstormDistribution::Area),
+                severe: true,
+                hail: false,
+            }]
+        );
+        let movement = convective.movement.unwrap();
+        assert_eq!(movement.direction.unwrap().get::<degree>().round(), 60.);
+        assert_eq!(movement.speed.unwrap().get::<knot>().round(), 25.);
+        assert_eq!(convective.top.unwrap().get::<foot>().round(), 45000.);
+
+        let airmet = sigmet("CHIS WA 251445\nAIRMET SIERRA UPDT 2 FOR IFR AND MTN OBSCN VALID UNTIL 252100\nAIRMET IFR...ME NH VT\nFROM 30NW YQB TO 40SE YSC TO 20E BGR TO 30NW YQB\nCIG BLW 010/VIS BLW 3SM BR. CONDS CONTG BYD 21Z THRU 03Z.").unwrap();
+        assert_eq!(airmet.advisory_type, AdvisoryType::AirmetSierra);
+        assert_eq!(airmet.sequence.as_deref(), Some("2"));
+        assert_eq!(
+            airmet.phenomena,
+            vec![
+                SigmetPhenomenon::InstrumentConditions,
+                SigmetPhenomenon::MountainObscuration
+            ]
+        );
+        assert_eq!(airmet.region.as_deref(), Some("ME NH VT"));
+        assert_eq!(airmet.areas.len(), 1);
+    }
+
+    #[test]
+    fn sigmet_error() {
+        let err = sigmet("YUDD SIGMET 2 VALID 101200/101600 YUSO-\nYUDD SHANLON FIR/UIR SEV TURB FCST WI N2020 W07005 - N20X8 W07100").unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::Advisory);
+        assert_eq!(err.token, "N20X8");
+    }
+}
@@ -0,0 +1,386 @@
+use std::convert::TryFrom;
+use uom::{
+    si::angle::degree,
+    si::f64::{Angle, Length, Velocity},
+    si::length::{foot, nautical_mile},
+    si::velocity::{kilometer_per_hour, knot},
+};
+
+use crate::tokens::*;
+use crate::utils::degrees_and_minutes;
+
+/// The first line(s) of an advisory, up to the validity period and region
+struct Header<'input> {
+    advisory_type: AdvisoryType,
+    location_indicator: Option<&'input str>,
+    sequence: Option<&'input str>,
+    valid_from: Option<ZuluTime>,
+    valid_until: ZuluTime,
+    valid_until_day_of_month: Option<u8>,
+    originator: Option<&'input str>,
+    region: Option<&'input str>,
+    phenomena: Vec<SigmetPhenomenon<'input>>,
+}
+
+/// A group in the body of an advisory, i.e. `MOV E 20KT`
+enum SigmetElement<'input> {
+    Phenomenon(SigmetPhenomenon<'input>),
+    /// `AIRMET IFR...ME NH VT`
+    AirmetArea {
+        phenomena: Vec<SigmetPhenomenon<'input>>,
+        region: &'input str,
+    },
+    Observation(ObservationStatus, Option<ZuluTime>),
+    Area(SigmetArea<'input>),
+    Levels(Option<Length>, Option<Length>),
+    Movement(Movement),
+    IntensityChange(IntensityChange),
+    Cancellation(&'input str),
+    Unparsed(&'input str),
+}
+
+peg::parser! {
+    pub grammar advisories() for str {
+        /// ICAO [SIGMET](https://en.wikipedia.org/wiki/SIGMET) and AIRMET parser, also accepting US SIGMETs, convective SIGMETs and AIRMETs
+        pub rule sigmet() -> SigmetReport<'input> =
+                    whitespace()
+                    issue_time:(issue_time:issue_line() required_whitespace() { issue_time })?
+                    header:header()
+                    elements:(separator() element:element() { element })*
+                    separator()?
+                    // Some machines use = to indicate end of message
+                    quiet!{"=" [_]*}? whitespace()
+                    {
+                let mut report = SigmetReport {
+                    advisory_type: header.advisory_type,
+                    location_indicator: header.location_indicator.map(Into::into),
+                    sequence: header.sequence.map(Into::into),
+                    issue_time,
+                    validity: header
+                        .valid_from
+                        .or_else(|| issue_time.map(|issue_time| issue_time.time))
+                        .map(|begin| ZuluTimeRange {
+                            begin,
+                            end: header.valid_until,
+                        }),
+                    valid_until: header.valid_until,
+                    valid_until_day_of_month: header.valid_until_day_of_month,
+                    originator: header.originator.map(Into::into),
+                    region: header.region.map(Into::into),
+                    phenomena: vec![],
+                    observation: None,
+                    observation_time: None,
+                    areas: vec![],
+                    base: None,
+                    top: None,
+                    movement: None,
+                    intensity_change: None,
+                    cancelled: None,
+                    unparsed: vec![],
+                };
+                let mut add_phenomenon = |phenomena: &mut Vec<SigmetPhenomenon<'input>>, phenomenon| {
+                    if !phenomena.contains(&phenomenon) {
+                        phenomena.push(phenomenon);
+                    }
+                };
+                for phenomenon in header.phenomena {
+                    add_phenomenon(&mut report.phenomena, phenomenon);
+                }
+                for element in elements {
+                    match element {
+                        SigmetElement::Phenomenon(phenomenon) => add_phenomenon(&mut report.phenomena, phenomenon),
+                        SigmetElement::AirmetArea { phenomena, region } => {
+                            for phenomenon in phenomena {
+                                add_phenomenon(&mut report.phenomena, phenomenon);
+                            }
+                            report.region.get_or_insert_with(|| region.trim_end().into());
+                        }
+                        SigmetElement::Observation(status, time) => {
+                            if report.observation.is_none() {
+                                report.observation = Some(status);
+                                report.observation_time = time;
+                            }
+                        }
+                        SigmetElement::Area(area) => report.areas.push(area),
+                        SigmetElement::Levels(base, top) => {
+                            if report.base.is_none() && report.top.is_none() {
+                                report.base = base;
+                                report.top = top;
+                            }
+                        }
+                        SigmetElement::Movement(movement) => {
+                            report.movement.get_or_insert(movement);
+                        }
+                        SigmetElement::IntensityChange(change) => {
+                            report.intensity_change.get_or_insert(change);
+                        }
+                        SigmetElement::Cancellation(sequence) => report.cancelled = Some(sequence.into()),
+                        SigmetElement::Unparsed(word) => report.unparsed.push(word.into()),
+                    }
+                }
+                report
+            }
+
+        /// `BOSN WS 251455` line preceding US advisories
+        rule issue_line() -> ZuluDateTime =
+            letter()*<3,4> required_whitespace() ("WST" / "WS" / "WA") required_whitespace() time:day_time() { time }
+
+        rule header() -> Header<'input> = icao_header() / convective_header() / us_sigmet_header() / airmet_header() / expected!("SIGMET or AIRMET header")
+
+        /// `YUDD SIGMET 2 VALID 101200/101600 YUSO-`
+        rule icao_header() -> Header<'input> =
+            location_indicator:icao_id() required_whitespace()
+            advisory_type:$("SIGMET" / "AIRMET") required_whitespace()
+            sequence:$(quiet!{letter_or_digit()+ (" " digit()+)?} / expected!("sequence number")) required_whitespace()
+            "VALID" required_whitespace() begin:day_time() "/" end:day_time() required_whitespace()
+            originator:icao_id() whitespace() "-"
+            region:(separator() region:fir() { region })? {
+                Header {
+                    advisory_type: AdvisoryType::try_from(advisory_type).unwrap(),
+                    location_indicator: Some(location_indicator),
+                    sequence: Some(sequence),
+                    valid_from: Some(begin.time),
+                    valid_until: end.time,
+                    valid_until_day_of_month: Some(end.day_of_month),
+                    originator: Some(originator),
+                    region,
+                    phenomena: vec![],
+                }
+            }
+        /// `CONVECTIVE SIGMET 25C VALID UNTIL 1655Z`
+        rule convective_header() -> Header<'input> =
+            "CONVECTIVE" required_whitespace() "SIGMET" required_whitespace()
+            sequence:$(quiet!{digit()+ letter()} / expected!("sequence number")) required_whitespace()
+            "VALID" required_whitespace() "UNTIL" required_whitespace() until:zulu_time()
+            region:states()? {
+                Header {
+                    advisory_type: AdvisoryType::ConvectiveSigmet,
+                    location_indicator: None,
+                    sequence: Some(sequence),
+                    valid_from: None,
+                    valid_until: until,
+                    valid_until_day_of_month: None,
+                    originator: None,
+                    region,
+                    phenomena: vec![],
+                }
+            }
+        /// `SIGMET NOVEMBER 3 VALID UNTIL 251855`
+        rule us_sigmet_header() -> Header<'input> =
+            "SIGMET" required_whitespace()
+            sequence:$(quiet!{letter()+ " " digit()+} / expected!("sequence number")) required_whitespace()
+            "VALID" required_whitespace() "UNTIL" required_whitespace() until:day_time()
+            region:states()? {
+                Header {
+                    advisory_type: AdvisoryType::Sigmet,
+                    location_indicator: None,
+                    sequence: Some(sequence),
+                    valid_from: None,
+                    valid_until: until.time,
+                    valid_until_day_of_month: Some(until.day_of_month),
+                    originator: None,
+                    region,
+                    phenomena: vec![],
+                }
+            }
+        /// `AIRMET SIERRA UPDT 2 FOR IFR AND MTN OBSCN VALID UNTIL 252100`
+        rule airmet_header() -> Header<'input> =
+            "AIRMET" required_whitespace()
+            advisory_type:$(quiet!{"SIERRA" / "TANGO" / "ZULU"} / expected!("AIRMET type"))
+            sequence:(required_whitespace() "UPDT" required_whitespace() sequence:$(digit()+) { sequence })? required_whitespace()
+            "FOR" required_whitespace() phenomena:airmet_phenomenon() ++ (required_whitespace() ("AND" required_whitespace())?) required_whitespace()
+            "VALID" required_whitespace() "UNTIL" required_whitespace() until:day_time() {
+                Header {
+                    advisory_type: AdvisoryType::try_from(format!("AIRMET {}", advisory_type).as_str()).unwrap(),
+                    location_indicator: None,
+                    sequence,
+                    valid_from: None,
+                    valid_until: until.time,
+                    valid_until_day_of_month: Some(until.day_of_month),
+                    originator: None,
+                    region: None,
+                    phenomena,
+                }
+            }
+
+        /// `YUDD SHANLON FIR/UIR`
+        rule fir() -> &'input str =
+            $(icao_id() (required_whitespace() !region_kind() letter()+)* required_whitespace() region_kind())
+        rule region_kind() = ("FIR/UIR" / "FIR" / "UIR" / "CTA") !letter()
+        /// A line of US state abbreviations, i.e. `KS OK TX`
+        rule states() -> &'input str =
+            [' ' | '\t' | '\r']* "\n" whitespace() states:$((letter()*<2> !letter_or_digit()) ++ " ") &([' ' | '\t' | '\r']* ("\n" / ![_])) { states }
+
+        rule element() -> SigmetElement<'input> =
+            "CNL" required_whitespace() ("SIGMET" / "AIRMET") required_whitespace() sequence:$(letter_or_digit()+)
+                (required_whitespace() day_time() "/" day_time())? element_end() { SigmetElement::Cancellation(sequence) }
+            / "AIRMET" required_whitespace() phenomena:airmet_phenomenon() ++ (required_whitespace() "AND" required_whitespace()) "..." region:$([^'\r' | '\n']+) {
+                SigmetElement::AirmetArea { phenomena, region }
+            }
+            / status:$("OBS" / "FCST") time:(required_whitespace() "AT" required_whitespace() time:zulu_time() { time })? element_end() {
+                SigmetElement::Observation(ObservationStatus::try_from(status).unwrap(), time)
+            }
+            / "WI" required_whitespace() polygon:coordinates() ++ (whitespace() "-" whitespace()) element_end() { SigmetElement::Area(SigmetArea::Polygon(polygon)) }
+            / "ENTIRE" required_whitespace() region_kind() { SigmetElement::Area(SigmetArea::EntireRegion) }
+            / ("FROM" / "BOUNDED" required_whitespace() "BY") required_whitespace() first:point() rest:(point_separator() point:point() { point })+ element_end() {
+                let mut points = vec![first];
+                points.extend(rest);
+                SigmetElement::Area(SigmetArea::Points(points))
+            }
+            / levels:levels() element_end() {
+                let (base, top) = levels;
+                SigmetElement::Levels(base, top)
+            }
+            / movement:movement() element_end() { SigmetElement::Movement(movement) }
+            / change:$("INTSF" / "WKN" / "NC") element_end() { SigmetElement::IntensityChange(IntensityChange::try_from(change).unwrap()) }
+            / phenomenon:phenomenon() element_end() { SigmetElement::Phenomenon(phenomenon) }
+            // Groups that must be decoded and dangling separators are not skipped, so malformed ones are reported
+            / !(("WI" / "MOV" / "CNL") element_end() / "-") word:$(quiet!{(!(separator() / "=") [_])+} / expected!("SIGMET or AIRMET group")) {
+                SigmetElement::Unparsed(word)
+            }
+        rule element_end() = &(separator() / "=" / ![_])
+        rule separator() = quiet!{[' ' | '\r' | '\n' | '\t' | '.']+} / expected!("whitespace")
+
+        rule phenomenon() -> SigmetPhenomenon<'input> =
+            distribution:(distribution:thunderstorm_distribution() required_whitespace() { distribution })?
+            severe:("SEV" required_whitespace())? "TS" hail:"GR"? {
+                SigmetPhenomenon::Thunderstorms {
+                    distribution,
+                    severe: severe.is_some(),
+                    hail: hail.is_some(),
+                }
+            }
+            / intensity:$("SEV" / "MOD") required_whitespace() "TURB" { SigmetPhenomenon::Turbulence(TurbulenceIntensity::try_from(intensity).unwrap()) }
+            / intensity:$("SEV" / "MOD") required_whitespace() "ICE" freezing_rain:(required_whitespace() "(FZRA)")? {
+                SigmetPhenomenon::Icing {
+                    intensity: IcingIntensity::try_from(intensity).unwrap(),
+                    freezing_rain: freezing_rain.is_some(),
+                }
+            }
+            / ("SEV" required_whitespace())? "MTW" { SigmetPhenomenon::MountainWave }
+            / "HVY" required_whitespace() "DS" { SigmetPhenomenon::Duststorm }
+            / "HVY" required_whitespace() "SS" { SigmetPhenomenon::Sandstorm }
+            / "VA" required_whitespace() ("ERUPTION" / "CLD") { SigmetPhenomenon::VolcanicAsh }
+            / "RDOACT" required_whitespace() "CLD" { SigmetPhenomenon::RadioactiveCloud }
+            / "TC" required_whitespace() !("OBS" / "FCST") name:$(letter()+) { SigmetPhenomenon::TropicalCyclone(name.into()) }
+            / airmet_phenomenon()
+        rule thunderstorm_distribution() -> ThunderstormDistribution =
+            val:$(quiet!{"OBSC" / "EMBD" / "FRQ" / "SQL" / "ISOL" / "OCNL" / "AREA" / "LINE"} / expected!("thunderstorm distribution")) {
+                ThunderstormDistribution::try_from(val).unwrap()
+            }
+        /// Hazards named in the header of US AIRMETs
+        rule airmet_phenomenon() -> SigmetPhenomenon<'input> =
+            ("IFR" !letter() { SigmetPhenomenon::InstrumentConditions }
+            / "MTN" required_whitespace() "OBSCN" !letter() { SigmetPhenomenon::MountainObscuration }
+            / "TURB" !letter() { SigmetPhenomenon::Turbulence(TurbulenceIntensity::Moderate) }
+            / "STG" required_whitespace() ("SFC" required_whitespace())? "WNDS" !letter() { SigmetPhenomenon::StrongSurfaceWinds }
+            / "LLWS" !letter() { SigmetPhenomenon::LowLevelWindShear }
+            / "ICE" !letter() {
+                SigmetPhenomenon::Icing {
+                    intensity: IcingIntensity::Moderate,
+                    freezing_rain: false,
+                }
+            }
+            / "FRZLVL" !letter() { SigmetPhenomenon::FreezingLevel })
+
+        /// `N4500 W07000` or `S05 E100`
+        rule coordinates() -> Coordinates =
+            north_south:$(quiet!{['N' | 'S']} / expected!("latitude")) latitude:$(digit()*<2>) latitude_minutes:$(digit()*<2>)? required_whitespace()
+            east_west:$(quiet!{['E' | 'W']} / expected!("longitude")) longitude:$(digit()*<3>) longitude_minutes:$(digit()*<2>)? {
+                Coordinates {
+                    latitude: degrees_and_minutes(latitude, latitude_minutes, north_south == "S"),
+                    longitude: degrees_and_minutes(longitude, longitude_minutes, east_west == "W"),
+                }
+            }
+        /// A navaid, optionally preceded by the distance in nautical miles and direction from it, i.e. `30NW YQB`
+        rule point() -> SigmetPoint<'input> =
+            distance:$(digit()+) direction:compass_point() whitespace() fix:fix() {
+                SigmetPoint {
+                    fix: fix.into(),
+                    direction: Some(direction),
+                    distance: Some(Length::new::<nautical_mile>(distance.parse().unwrap())),
+                }
+            }
+            / fix:fix() {
+                SigmetPoint {
+                    fix: fix.into(),
+                    direction: None,
+                    distance: None,
+                }
+            }
+        rule fix() -> &'input str = val:$(quiet!{letter()*<2,5>} / expected!("navaid")) !letter_or_digit() { val }
+        rule point_separator() = required_whitespace() "TO" required_whitespace() / whitespace() "-" whitespace()
+
+        /// Base and top of the phenomenon
+        rule levels() -> (Option<Length>, Option<Length>) =
+            "SFC/" top:flight_level() { (Some(Length::new::<foot>(0.)), Some(top)) }
+            / base:flight_level() "/" top:hundreds_of_feet() { (Some(base), Some(top)) }
+            / ("TOPS" / "TOP") required_whitespace() (("ABV" / "BLW" / "TO") required_whitespace())? top:altitude() { (None, Some(top)) }
+            / "ABV" required_whitespace() base:altitude() { (Some(base), None) }
+            / "BLW" required_whitespace() top:altitude() { (None, Some(top)) }
+            / "BTN" required_whitespace()
+                base:("SFC" { Some(Length::new::<foot>(0.)) } / "FRZLVL" { None } / base:altitude() { Some(base) })
+                required_whitespace() "AND" required_whitespace() top:altitude() { (base, Some(top)) }
+            / level:flight_level() { (Some(level), Some(level)) }
+        rule altitude() -> Length = flight_level() / hundreds_of_feet()
+        rule flight_level() -> Length = "FL" level:hundreds_of_feet() { level }
+        rule hundreds_of_feet() -> Length = val:$(quiet!{digit()*<3>} / expected!("altitude")) !digit() { Length::new::<foot>(val.parse::<f64>().unwrap() * 100.) }
+
+        rule movement() -> Movement =
+            "MOV" required_whitespace() "FROM" required_whitespace() direction:$(digit()*<3>) speed:$(digit()*<2,3>) "KT" {
+                Movement {
+                    direction: Some(Angle::new::<degree>((direction.parse::<f64>().unwrap() + 180.) % 360.)),
+                    speed: Some(Velocity::new::<knot>(speed.parse().unwrap())),
+                }
+            }
+            / "MOV" required_whitespace() direction:compass_point() speed:(required_whitespace() speed:speed() { speed })? {
+                Movement {
+                    direction: Some(direction),
+                    speed,
+                }
+            }
+            / "STNR" {
+                Movement {
+                    direction: None,
+                    speed: Some(Velocity::new::<knot>(0.)),
+                }
+            }
+        rule speed() -> Velocity =
+            val:$(digit()+) "KT" { Velocity::new::<knot>(val.parse().unwrap()) }
+            / val:$(digit()+) "KMH" { Velocity::new::<kilometer_per_hour>(val.parse().unwrap()) }
+        rule compass_point() -> Angle =
+            val:$(quiet!{"NNE" / "NNW" / "ENE" / "ESE" / "SSE" / "SSW" / "WSW" / "WNW" / "NE" / "NW" / "SE" / "SW" / "N" / "E" / "S" / "W"} / expected!("direction")) {
+                compass_point_angle(val)
+            }
+
+        rule day_time() -> ZuluDateTime = day_of_month:$(digit()*<2>) time:zulu_time() {
+            ZuluDateTime {
+                day_of_month: day_of_month.parse().unwrap(),
+                time,
+                is_zulu: true,
+            }
+        }
+        rule zulu_time() -> ZuluTime = hour:$(digit()*<2>) minute:$(digit()*<2>) "Z"? {
+            ZuluTime {
+                hour: hour.parse().unwrap(),
+                minute: minute.parse().unwrap(),
+            }
+        }
+        rule icao_id() -> &'input str = $(quiet!{letter() letter_or_digit()*<3>} / expected!("location indicator"))
+        rule letter() -> &'input str = quiet!{$(['A'..='Z'])} / expected!("letter");
+        rule digit() -> &'input str = quiet!{$(['0'..='9'])} / expected!("digit");
+        rule letter_or_digit() -> &'input str = letter() / digit();
+        rule whitespace() = required_whitespace()?
+        rule required_whitespace() = quiet!{[' ' | '\r' | '\n' | '\t']+} / expected!("whitespace")
+    }
+}
+
+/// Angle of a point on the 16-point compass rose
+fn compass_point_angle(val: &str) -> Angle {
+    const POINTS: [&str; 16] = [
+        "N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE", "S", "SSW", "SW", "WSW", "W", "WNW",
+        "NW", "NNW",
+    ];
+    let index = POINTS.iter().position(|point| *point == val).unwrap();
+    Angle::new::<degree>(index as f64 * 22.5)
+}
@@ -0,0 +1,632 @@
+//! Detects which special observation (SPECI) criteria were met between two consecutive reports
+//!
+//! FAA criteria follow the Federal Meteorological Handbook No. 1 and ICAO criteria follow Annex 3, Appendix 3.
+//! Thresholds that depend on the station, such as the lowest instrument approach minimums, are not included.
+
+use std::borrow::Cow;
+
+use uom::si::f64::{Angle, Length};
+use uom::si::{
+    angle::degree,
+    length::{foot, meter, mile},
+    velocity::knot,
+};
+
+use crate::clouds;
+use crate::flight_category::lowest_base;
+use crate::tokens::*;
+
+/// FAA ceiling thresholds in feet
+const FAA_CEILINGS: [f64; 4] = [3000., 1500., 1000., 500.];
+/// FAA visibility thresholds in statute miles
+const FAA_VISIBILITIES: [f64; 3] = [3., 2., 1.];
+/// FAA runway visual range threshold in feet
+const FAA_RUNWAY_VISUAL_RANGE: f64 = 2400.;
+/// A cloud layer appearing below this height in feet is a trigger for the FAA
+const FAA_LOW_LAYER: f64 = 1000.;
+const FAA_WIND_SHIFT_DEGREES: f64 = 45.;
+
+/// ICAO ceiling thresholds in feet
+const ICAO_CEILINGS: [f64; 5] = [1500., 1000., 500., 200., 100.];
+/// ICAO visibility thresholds in meters
+const ICAO_VISIBILITIES: [f64; 4] = [5000., 3000., 1500., 800.];
+/// ICAO runway visual range thresholds in meters
+const ICAO_RUNWAY_VISUAL_RANGES: [f64; 5] = [800., 550., 300., 175., 50.];
+const ICAO_WIND_SHIFT_DEGREES: f64 = 60.;
+const ICAO_WIND_SPEED_CHANGE_KNOTS: f64 = 10.;
+const ICAO_GUST_INCREASE_KNOTS: f64 = 10.;
+/// Gust increases are only significant when the mean speed is at least this before or after
+const ICAO_GUST_MINIMUM_SPEED_KNOTS: f64 = 15.;
+
+const RELATIVE_TOLERANCE: f64 = 1e-9;
+
+/// Wind shifts are only significant when the mean speed is at least this
+const WIND_SHIFT_MINIMUM_SPEED_KNOTS: f64 = 10.;
+
+/// Which set of thresholds to apply
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpeciCriteria {
+    Faa,
+    Icao,
+}
+
+/// Whether a value fell below a threshold or rose to meet or exceed it
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Crossing {
+    Below,
+    AtOrAbove,
+}
+
+/// How a weather phenomenon changed
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Onset {
+    Began,
+    Ended,
+    IntensityChanged,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpeciTrigger<'input> {
+    Ceiling {
+        threshold: Length,
+        crossing: Crossing,
+    },
+    /// A layer appeared below 1,000 feet where there was none before (FAA only)
+    LowCloudLayer {
+        base: Length,
+    },
+    Visibility {
+        threshold: Length,
+        crossing: Crossing,
+    },
+    RunwayVisualRange {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        runway: Cow<'input, str>,
+        threshold: Length,
+        crossing: Crossing,
+    },
+    WindShift {
+        from: Angle,
+        to: Angle,
+    },
+    /// Change in mean wind speed of 10 knots or more (ICAO only)
+    WindSpeed,
+    /// Gusts increased by 10 knots or more (ICAO only)
+    Gust,
+    Thunderstorm(Onset),
+    FreezingPrecipitation(Onset),
+    /// Moderate or heavy precipitation (ICAO only)
+    Precipitation(Onset),
+    /// FAA only
+    Hail(Onset),
+    /// FAA only
+    IcePellets(Onset),
+    /// Includes tornadoes and waterspouts (`+FC`)
+    FunnelCloud(Onset),
+    Squall(Onset),
+    /// ICAO only
+    DuststormOrSandstorm(Onset),
+}
+
+impl<'input> MetarReport<'input> {
+    /// SPECI criteria met by this report relative to the `previous` report from the same station
+    ///
+    /// Elements missing from either report are not compared.
+    pub fn speci_triggers(
+        &self,
+        previous: &MetarReport<'_>,
+        criteria: SpeciCriteria,
+    ) -> Vec<SpeciTrigger<'input>> {
+        let mut triggers = vec![];
+        wind_triggers(previous, self, criteria, &mut triggers);
+
+        let visibilities: Vec<Length> = match criteria {
+            SpeciCriteria::Faa => FAA_VISIBILITIES
+                .iter()
+                .map(|miles| Length::new::<mile>(*miles))
+                .collect(),
+            SpeciCriteria::Icao => ICAO_VISIBILITIES
+                .iter()
+                .map(|meters| Length::new::<meter>(*meters))
+                .collect(),
+        };
+        if let (Some(before), Some(after)) =
+            (prevailing_visibility(previous), prevailing_visibility(self))
+        {
+            triggers.extend(crossings(&visibilities, before, after).map(
+                |(threshold, crossing)| SpeciTrigger::Visibility {
+                    threshold,
+                    crossing,
+                },
+            ));
+        }
+
+        let runway_visual_ranges: Vec<Length> = match criteria {
+            SpeciCriteria::Faa => vec![Length::new::<foot>(FAA_RUNWAY_VISUAL_RANGE)],
+            SpeciCriteria::Icao => ICAO_RUNWAY_VISUAL_RANGES
+                .iter()
+                .map(|meters| Length::new::<meter>(*meters))
+                .collect(),
+        };
+        for runway in &self.runway_visibilities {
+            let before = previous
+                .runway_visibilities
+                .iter()
+                .find(|before| before.designator == runway.designator);
+            if let (Some(before), Some(after)) = (
+                before.and_then(|before| runway_visual_range(before, criteria)),
+                runway_visual_range(runway, criteria),
+            ) {
+                triggers.extend(crossings(&runway_visual_ranges, before, after).map(
+                    |(threshold, crossing)| SpeciTrigger::RunwayVisualRange {
+                        runway: runway.designator.clone(),
+                        threshold,
+                        crossing,
+                    },
+                ));
+            }
+        }
+
+        weather_triggers(previous, self, criteria, &mut triggers);
+
+        if let (Some(before), Some(after)) = (ceiling(previous), ceiling(self)) {
+            let ceilings = match criteria {
+                SpeciCriteria::Faa => &FAA_CEILINGS[..],
+                SpeciCriteria::Icao => &ICAO_CEILINGS[..],
+            }
+            .iter()
+            .map(|feet| Length::new::<foot>(*feet))
+            .collect::<Vec<_>>();
+            // No ceiling is treated as unlimited
+            let unlimited = Length::new::<foot>(f64::INFINITY);
+            triggers.extend(
+                crossings(
+                    &ceilings,
+                    before.unwrap_or(unlimited),
+                    after.unwrap_or(unlimited),
+                )
+                .map(|(threshold, crossing)| SpeciTrigger::Ceiling {
+                    threshold,
+                    crossing,
+                }),
+            );
+        }
+        if criteria == SpeciCriteria::Faa {
+            let low_layer = |report: &MetarReport<'_>| {
+                lowest_base(&report.cloud_cover, clouds::is_cloud)
+                    .map(|base| base.filter(|base| *base < Length::new::<foot>(FAA_LOW_LAYER)))
+            };
+            if let (Some(None), Some(Some(base))) = (low_layer(previous), low_layer(self)) {
+                triggers.push(SpeciTrigger::LowCloudLayer { base });
+            }
+        }
+        triggers
+    }
+}
+
+/// Thresholds crossed going from `before` to `after`, from highest to lowest
+///
+/// Values within [RELATIVE_TOLERANCE] of a threshold count as meeting it, since unit conversions are inexact.
+fn crossings(
+    thresholds: &[Length],
+    before: Length,
+    after: Length,
+) -> impl Iterator<Item = (Length, Crossing)> + '_ {
+    let is_below = |value: Length, threshold: Length| {
+        value.get::<meter>() < threshold.get::<meter>() * (1. - RELATIVE_TOLERANCE)
+    };
+    thresholds.iter().filter_map(move |threshold| {
+        match (is_below(before, *threshold), is_below(after, *threshold)) {
+            (false, true) => Some((*threshold, Crossing::Below)),
+            (true, false) => Some((*threshold, Crossing::AtOrAbove)),
+            _ => None,
+        }
+    })
+}
+
+fn prevailing_visibility(report: &MetarReport<'_>) -> Option<Length> {
+    if report.cavok {
+        return Some(Length::new::<meter>(10_000.));
+    }
+    report
+        .visibility
+        .as_ref()?
+        .prevailing
+        .map(raw_visibility_value)
+}
+
+/// Values reported as less than (`M`) are nudged below the reported distance so they cross it
+fn raw_visibility_value(visibility: RawVisibility) -> Length {
+    match visibility.out_of_range {
+        Some(OutOfRange::Below) => visibility.distance * (1. - 2. * RELATIVE_TOLERANCE),
+        _ => visibility.distance,
+    }
+}
+
+/// The FAA uses the highest value during the preceding 10 minutes, and ICAO the lowest
+fn runway_visual_range(runway: &RunwayVisibility<'_>, criteria: SpeciCriteria) -> Option<Length> {
+    let visibility = match (runway.visibility, criteria) {
+        (VisibilityType::Fixed(visibility), _) => visibility,
+        (VisibilityType::Varying { upper, .. }, SpeciCriteria::Faa) => upper,
+        (VisibilityType::Varying { lower, .. }, SpeciCriteria::Icao) => lower,
+    };
+    Some(raw_visibility_value(visibility))
+}
+
+/// `Some(None)` when there is no ceiling
+fn ceiling(report: &MetarReport<'_>) -> Option<Option<Length>> {
+    if report.cavok {
+        return Some(None);
+    }
+    lowest_base(&report.cloud_cover, clouds::is_ceiling)
+}
+
+fn wind_triggers(
+    previous: &MetarReport<'_>,
+    current: &MetarReport<'_>,
+    criteria: SpeciCriteria,
+    triggers: &mut Vec<SpeciTrigger<'_>>,
+) {
+    let (before, after) = match (previous.wind.as_ref(), current.wind.as_ref()) {
+        (Some(before), Some(after)) => (before, after),
+        _ => return,
+    };
+    let (before_speed, after_speed) = match (before.speed, after.speed) {
+        (Some(before), Some(after)) => (before.get::<knot>(), after.get::<knot>()),
+        _ => return,
+    };
+
+    if let (Some(from), Some(to)) = (before.direction, after.direction) {
+        let difference = (to.get::<degree>() - from.get::<degree>()).rem_euclid(360.);
+        let difference = difference.min(360. - difference);
+        let is_shift = match criteria {
+            // Speed must be at least 10 knots throughout
+            SpeciCriteria::Faa => {
+                before_speed.min(after_speed) >= WIND_SHIFT_MINIMUM_SPEED_KNOTS
+                    && difference >= FAA_WIND_SHIFT_DEGREES
+            }
+            SpeciCriteria::Icao => {
+                before_speed.max(after_speed) >= WIND_SHIFT_MINIMUM_SPEED_KNOTS
+                    && difference >= ICAO_WIND_SHIFT_DEGREES
+            }
+        };
+        if is_shift {
+            triggers.push(SpeciTrigger::WindShift { from, to });
+        }
+    }
+
+    if criteria == SpeciCriteria::Icao {
+        if (after_speed - before_speed).abs() >= ICAO_WIND_SPEED_CHANGE_KNOTS {
+            triggers.push(SpeciTrigger::WindSpeed);
+        }
+        let gust_before = before
+            .peak_gust
+            .map(|gust| gust.get::<knot>())
+            .unwrap_or(before_speed);
+        if let Some(gust_after) = after.peak_gust {
+            if before_speed.max(after_speed) >= ICAO_GUST_MINIMUM_SPEED_KNOTS
+                && gust_after.get::<knot>() - gust_before >= ICAO_GUST_INCREASE_KNOTS
+            {
+                triggers.push(SpeciTrigger::Gust);
+            }
+        }
+    }
+}
+
+/// Ordered from lightest to heaviest
+fn intensity_rank(intensity: Intensity) -> u8 {
+    match intensity {
+        Intensity::Light => 0,
+        Intensity::Moderate => 1,
+        Intensity::Heavy => 2,
+    }
+}
+
+/// Highest intensity of the weather at the station matching `is_phenomenon`
+///
+/// Weather in the vicinity (`VC`) is ignored, so `VCTS` and `VCFC` do not count as a thunderstorm or funnel cloud.
+fn strongest(weather: &[Weather], is_phenomenon: impl Fn(&Weather) -> bool) -> Option<u8> {
+    weather
+        .iter()
+        .filter(|weather| !weather.vicinity && is_phenomenon(weather))
+        .map(|weather| intensity_rank(weather.intensity))
+        .max()
+}
+
+fn onset(before: Option<u8>, after: Option<u8>, track_intensity: bool) -> Option<Onset> {
+    match (before, after) {
+        (None, Some(_)) => Some(Onset::Began),
+        (Some(_), None) => Some(Onset::Ended),
+        (Some(before), Some(after)) if track_intensity && before != after => {
+            Some(Onset::IntensityChanged)
+        }
+        _ => None,
+    }
+}
+
+fn weather_triggers(
+    previous: &MetarReport<'_>,
+    current: &MetarReport<'_>,
+    criteria: SpeciCriteria,
+    triggers: &mut Vec<SpeciTrigger<'_>>,
+) {
+    let is_icao = criteria == SpeciCriteria::Icao;
+    let is_faa = criteria == SpeciCriteria::Faa;
+    let phenomenon = |is_phenomenon: &dyn Fn(&Weather) -> bool, track_intensity: bool| {
+        onset(
+            strongest(&previous.weather, is_phenomenon),
+            strongest(&current.weather, is_phenomenon),
+            track_intensity,
+        )
+    };
+    let is_precipitation =
+        |weather: &Weather| matches!(weather.condition, Some(Condition::Precipitation(_)));
+    let has_precipitation = |precipitation: Precipitation| {
+        move |weather: &Weather| match &weather.condition {
+            Some(Condition::Precipitation(precipitations)) => {
+                precipitations.contains(&precipitation)
+            }
+            _ => false,
+        }
+    };
+    let is_other =
+        |other: Other| move |weather: &Weather| weather.condition == Some(Condition::Other(other));
+
+    if let Some(onset) = phenomenon(
+        &|weather| weather.descriptor == Some(Descriptor::Thunderstorm),
+        false,
+    ) {
+        triggers.push(SpeciTrigger::Thunderstorm(onset));
+    }
+    if let Some(onset) = phenomenon(
+        &|weather| weather.descriptor == Some(Descriptor::Freezing) && is_precipitation(weather),
+        true,
+    ) {
+        triggers.push(SpeciTrigger::FreezingPrecipitation(onset));
+    }
+    if is_icao {
+        if let Some(onset) = phenomenon(
+            &|weather| {
+                weather.intensity != Intensity::Light
+                    && weather.descriptor != Some(Descriptor::Freezing)
+                    && is_precipitation(weather)
+            },
+            true,
+        ) {
+            triggers.push(SpeciTrigger::Precipitation(onset));
+        }
+    }
+    if is_faa {
+        if let Some(onset) = phenomenon(&has_precipitation(Precipitation::Hail), false) {
+            triggers.push(SpeciTrigger::Hail(onset));
+        }
+        if let Some(onset) = phenomenon(&has_precipitation(Precipitation::IcePellets), true) {
+            triggers.push(SpeciTrigger::IcePellets(onset));
+        }
+    }
+    if let Some(onset) = phenomenon(&is_other(Other::FunnelCloud), false) {
+        triggers.push(SpeciTrigger::FunnelCloud(onset));
+    }
+    if let Some(onset) = phenomenon(&is_other(Other::Squall), false) {
+        triggers.push(SpeciTrigger::Squall(onset));
+    }
+    if is_icao {
+        if let Some(onset) = phenomenon(
+            &|weather| is_other(Other::Duststorm)(weather) || is_other(Other::Sandstorm)(weather),
+            false,
+        ) {
+            triggers.push(SpeciTrigger::DuststormOrSandstorm(onset));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metar::parse::metar;
+
+    fn triggers(
+        previous: &str,
+        current: &str,
+        criteria: SpeciCriteria,
+    ) -> Vec<SpeciTrigger<'static>> {
+        let previous = metar(previous).unwrap();
+        metar(current)
+            .unwrap()
+            .into_owned()
+            .speci_triggers(&previous, criteria)
+    }
+
+    #[test]
+    fn faa_thresholds() {
+        let triggers = triggers(
+            "KSEA 290353Z 01012KT 10SM FEW040 BKN250 19/09 A3004",
+            "KSEA 290412Z 07015G25KT 2SM -FZRA BR FEW008 OVC012 17/11 A3002",
+            SpeciCriteria::Faa,
+        );
+        assert_eq!(
+            triggers,
+            vec![
+                SpeciTrigger::WindShift {
+                    from: Angle::new::<degree>(10.),
+                    to: Angle::new::<degree>(70.)
+                },
+                SpeciTrigger::Visibility {
+                    threshold: Length::new::<mile>(3.),
+                    crossing: Crossing::Below
+                },
+                SpeciTrigger::FreezingPrecipitation(Onset::Began),
+                SpeciTrigger::Ceiling {
+                    threshold: Length::new::<foot>(3000.),
+                    crossing: Crossing::Below
+                },
+                SpeciTrigger::Ceiling {
+                    threshold: Length::new::<foot>(1500.),
+                    crossing: Crossing::Below
+                },
+                SpeciTrigger::LowCloudLayer {
+                    base: Length::new::<foot>(800.)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn icao_thresholds() {
+        let triggers = triggers(
+            "EGLL 251450Z 27008KT 6000 R27L/1000U -RA BKN012 14/09 Q1002",
+            "EGLL 251512Z 27018G32KT 1200 R27L/0500D +TSRA FC SCT008CB BKN015 13/11 Q1001",
+            SpeciCriteria::Icao,
+        );
+        assert_eq!(
+            triggers,
+            vec![
+                SpeciTrigger::WindSpeed,
+                SpeciTrigger::Gust,
+                SpeciTrigger::Visibility {
+                    threshold: Length::new::<meter>(5000.),
+                    crossing: Crossing::Below
+                },
+                SpeciTrigger::Visibility {
+                    threshold: Length::new::<meter>(3000.),
+                    crossing: Crossing::Below
+                },
+                SpeciTrigger::Visibility {
+                    threshold: Length::new::<meter>(1500.),
+                    crossing: Crossing::Below
+                },
+                SpeciTrigger::RunwayVisualRange {
+                    runway: "27L".into(),
+                    threshold: Length::new::<meter>(800.),
+                    crossing: Crossing::Below
+                },
+                SpeciTrigger::RunwayVisualRange {
+                    runway: "27L".into(),
+                    threshold: Length::new::<meter>(550.),
+                    crossing: Crossing::Below
+                },
+                SpeciTrigger::Thunderstorm(Onset::Began),
+                SpeciTrigger::Precipitation(Onset::Began),
+                SpeciTrigger::FunnelCloud(Onset::Began),
+                SpeciTrigger::Ceiling {
+                    threshold: Length::new::<foot>(1500.),
+                    crossing: Crossing::AtOrAbove
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn improvements_and_cessation() {
+        let triggers = triggers(
+            "KSEA 290353Z 18012KT 1/2SM +FZRA FG VV003 01/01 A2992",
+            "KSEA 290453Z 18012KT 4SM -FZRA BR OVC009 01/01 A2992",
+            SpeciCriteria::Faa,
+        );
+        assert_eq!(
+            triggers,
+            vec![
+                SpeciTrigger::Visibility {
+                    threshold: Length::new::<mile>(3.),
+                    crossing: Crossing::AtOrAbove
+                },
+                SpeciTrigger::Visibility {
+                    threshold: Length::new::<mile>(2.),
+                    crossing: Crossing::AtOrAbove
+                },
+                SpeciTrigger::Visibility {
+                    threshold: Length::new::<mile>(1.),
+                    crossing: Crossing::AtOrAbove
+                },
+                SpeciTrigger::FreezingPrecipitation(Onset::IntensityChanged),
+                SpeciTrigger::Ceiling {
+                    threshold: Length::new::<foot>(500.),
+                    crossing: Crossing::AtOrAbove
+                },
+            ]
+        );
+
+        assert!(self::triggers(
+            "KSEA 290353Z 18012KT 10SM FEW040 19/09 A3004",
+            "KSEA 290453Z 20014KT 10SM VCTS VCFC SCT045 19/09 A3004",
+            SpeciCriteria::Faa,
+        )
+        .is_empty());
+        assert!(self::triggers(
+            "KSEA 290353Z 18012KT 10SM FEW040 19/09 A3004",
+            "KSEA 290453Z 20014KT 10SM SCT045 19/09 A3004",
+            SpeciCriteria::Faa,
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn faa_squall() {
+        assert_eq!(
+            triggers(
+                "KOKC 291553Z 18012KT 10SM FEW040 24/14 A2992",
+                "KOKC 291612Z 18025G40KT 10SM SQ FEW040 22/14 A2994",
+                SpeciCriteria::Faa,
+            ),
+            vec![SpeciTrigger::Squall(Onset::Began)]
+        );
+    }
+
+    #[test]
+    fn faa_hail() {
+        assert_eq!(
+            triggers(
+                "KOKC 291553Z 18012KT 10SM TSRA FEW040CB 24/14 A2992",
+                "KOKC 291612Z 18012KT 10SM TSGRRA FEW040CB 24/14 A2992",
+                SpeciCriteria::Faa,
+            ),
+            vec![SpeciTrigger::Hail(Onset::Began)]
+        );
+        assert_eq!(
+            triggers(
+                "KOKC 291612Z 18012KT 10SM TSGRRA FEW040CB 24/14 A2992",
+                "KOKC 291653Z 18012KT 10SM TSRA FEW040CB 24/14 A2992",
+                SpeciCriteria::Faa,
+            ),
+            vec![SpeciTrigger::Hail(Onset::Ended)]
+        );
+        assert!(triggers(
+            "KOKC 291553Z 18012KT 10SM TSRA FEW040CB 24/14 A2992",
+            "KOKC 291612Z 18012KT 10SM TSGRRA FEW040CB 24/14 A2992",
+            SpeciCriteria::Icao,
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn faa_ice_pellets() {
+        assert_eq!(
+            triggers(
+                "KBOS 291553Z 04012KT 10SM OVC040 M01/M04 A2992",
+                "KBOS 291612Z 04012KT 10SM -PL OVC040 M01/M04 A2992",
+                SpeciCriteria::Faa,
+            ),
+            vec![SpeciTrigger::IcePellets(Onset::Began)]
+        );
+        assert_eq!(
+            triggers(
+                "KBOS 291612Z 04012KT 10SM -PL OVC040 M01/M04 A2992",
+                "KBOS 291653Z 04012KT 10SM PL OVC040 M01/M04 A2992",
+                SpeciCriteria::Faa,
+            ),
+            vec![SpeciTrigger::IcePellets(Onset::IntensityChanged)]
+        );
+        assert_eq!(
+            triggers(
+                "KBOS 291653Z 04012KT 10SM PL OVC040 M01/M04 A2992",
+                "KBOS 291712Z 04012KT 10SM OVC040 M01/M04 A2992",
+                SpeciCriteria::Faa,
+            ),
+            vec![SpeciTrigger::IcePellets(Onset::Ended)]
+        );
+    }
+}
@@ -0,0 +1,257 @@
+//! Offline station metadata keyed by [ICAO identifier](https://en.wikipedia.org/wiki/ICAO_airport_code)
+//!
+//! The embedded table is produced by `scripts/generate_stations.py` from the
+//! [mwgg/Airports](https://github.com/mwgg/Airports) dataset, which covers airports with an ICAO identifier.
+//! Weather stations that are not airports are not included, so lookups for them return [None].
+//! Use [stations] to list the stations currently embedded.
+
+mod table;
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use uom::si::f64::{Angle, Length};
+use uom::si::{angle::degree, length::meter};
+
+use crate::tokens::{Coordinates, MetarReport};
+use table::STATIONS;
+
+/// Mean radius of the Earth used for great-circle distances
+const EARTH_RADIUS_METERS: f64 = 6_371_008.8;
+
+/// Metadata for a reporting station
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Station {
+    /// [ICAO identifier](https://en.wikipedia.org/wiki/ICAO_airport_code)
+    pub icao: &'static str,
+    pub name: &'static str,
+    /// [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) country code
+    pub country: &'static str,
+    pub timezone: Tz,
+    latitude: f64,
+    longitude: f64,
+    elevation: f64,
+}
+
+impl Station {
+    const fn new(
+        icao: &'static str,
+        name: &'static str,
+        country: &'static str,
+        latitude: f64,
+        longitude: f64,
+        elevation: f64,
+        timezone: Tz,
+    ) -> Self {
+        Self {
+            icao,
+            name,
+            country,
+            timezone,
+            latitude,
+            longitude,
+            elevation,
+        }
+    }
+
+    pub fn coordinates(&self) -> Coordinates {
+        Coordinates {
+            latitude: Angle::new::<degree>(self.latitude),
+            longitude: Angle::new::<degree>(self.longitude),
+        }
+    }
+
+    /// Field elevation above mean sea level
+    pub fn elevation(&self) -> Length {
+        Length::new::<meter>(self.elevation)
+    }
+
+    /// Converts a time to the local time at this station
+    pub fn local_time(&self, time: DateTime<Utc>) -> DateTime<Tz> {
+        time.with_timezone(&self.timezone)
+    }
+
+    /// Great-circle distance to the given coordinates
+    pub fn distance(&self, coordinates: &Coordinates) -> Length {
+        great_circle_distance(&self.coordinates(), coordinates)
+    }
+}
+
+/// All known stations, sorted by ICAO identifier
+pub fn stations() -> &'static [Station] {
+    STATIONS
+}
+
+/// Looks up a station by its ICAO identifier, ignoring case
+pub fn station(icao: &str) -> Option<&'static Station> {
+    let icao = icao.to_ascii_uppercase();
+    STATIONS
+        .binary_search_by(|station| station.icao.cmp(icao.as_str()))
+        .ok()
+        .map(|index| &STATIONS[index])
+}
+
+/// Finds the known station closest to the given coordinates, along with its distance
+///
+/// Returns [None] if no station is within `max_distance`.
+pub fn nearest(
+    coordinates: &Coordinates,
+    max_distance: Length,
+) -> Option<(&'static Station, Length)> {
+    STATIONS
+        .iter()
+        .map(|station| (station, station.distance(coordinates)))
+        .filter(|(_, distance)| *distance <= max_distance)
+        .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+}
+
+/// Haversine distance between two points on a spherical Earth
+fn great_circle_distance(a: &Coordinates, b: &Coordinates) -> Length {
+    let (lat_a, lat_b) = (
+        a.latitude.get::<degree>().to_radians(),
+        b.latitude.get::<degree>().to_radians(),
+    );
+    let delta_lat = lat_b - lat_a;
+    let delta_lon = (b.longitude.get::<degree>() - a.longitude.get::<degree>()).to_radians();
+    let h =
+        (delta_lat / 2.).sin().powi(2) + lat_a.cos() * lat_b.cos() * (delta_lon / 2.).sin().powi(2);
+    Length::new::<meter>(2. * EARTH_RADIUS_METERS * h.sqrt().min(1.).asin())
+}
+
+impl<'input> MetarReport<'input> {
+    /// Metadata for the reporting station, if it is in the embedded table
+    pub fn station(&self) -> Option<&'static Station> {
+        station(&self.identifier)
+    }
+
+    /// Observation time in the local time of the reporting station
+    ///
+    /// See [crate::tokens::ZuluDateTime::resolve] for how `reference` is used.
+    /// Returns [None] if the station is unknown or the observation time is missing or invalid.
+    pub fn local_observation_time(&self, reference: DateTime<Utc>) -> Option<DateTime<Tz>> {
+        let observation_time = self.observation_time?.resolve(reference)?;
+        Some(self.station()?.local_time(observation_time))
+    }
+
+    /// [MetarReport::density_altitude] using the elevation of the reporting station
+    pub fn station_density_altitude(&self) -> Option<Length> {
+        self.density_altitude(self.station()?.elevation())
+    }
+
+    /// [MetarReport::pressure_altitude] using the elevation of the reporting station
+    pub fn station_pressure_altitude(&self) -> Option<Length> {
+        self.pressure_altitude(self.station()?.elevation())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Timelike};
+    use uom::si::{
+        f64::{Angle, Length},
+        length::{foot, kilometer},
+    };
+
+    use super::*;
+    use crate::metar::parse::metar;
+
+    #[test]
+    fn table_is_sorted_and_unique() {
+        for pair in stations().windows(2) {
+            assert!(
+                pair[0].icao < pair[1].icao,
+                "{} is duplicated or out of order",
+                pair[1].icao
+            );
+        }
+        for station in stations() {
+            assert_eq!(station.icao.len(), 4, "{}", station.icao);
+            assert_eq!(station.country.len(), 2, "{}", station.icao);
+            let coordinates = station.coordinates();
+            assert!(coordinates.latitude.get::<degree>().abs() <= 90.);
+            assert!(coordinates.longitude.get::<degree>().abs() <= 180.);
+        }
+    }
+
+    #[test]
+    fn lookup_by_identifier() {
+        let seattle = station("ksea").unwrap();
+        assert_eq!(seattle.icao, "KSEA");
+        assert_eq!(seattle.country, "US");
+        assert_eq!(seattle.timezone, Tz::America__Los_Angeles);
+        assert_eq!(seattle.elevation().get::<foot>().round(), 430.);
+        assert_eq!(station("ZZZZ"), None);
+        assert_eq!(station(""), None);
+    }
+
+    #[test]
+    fn nearest_station() {
+        // Central London
+        let london = Coordinates {
+            latitude: Angle::new::<degree>(51.5074),
+            longitude: Angle::new::<degree>(-0.1278),
+        };
+        let (station, distance) = nearest(&london, Length::new::<kilometer>(50.)).unwrap();
+        assert_eq!(station.icao, "EGLL");
+        assert!(
+            (distance.get::<kilometer>() - 23.).abs() < 1.,
+            "{:?}",
+            distance
+        );
+        // Point Nemo, the furthest point from land
+        let ocean = Coordinates {
+            latitude: Angle::new::<degree>(-48.8767),
+            longitude: Angle::new::<degree>(-123.3933),
+        };
+        assert_eq!(nearest(&ocean, Length::new::<kilometer>(100.)), None);
+
+        let heathrow = super::station("EGLL").unwrap();
+        let kennedy = super::station("KJFK").unwrap();
+        let distance: Length = heathrow.distance(&kennedy.coordinates());
+        assert!(
+            (distance.get::<kilometer>() - 5540.).abs() < 10.,
+            "{:?}",
+            distance
+        );
+    }
+
+    #[test]
+    fn local_observation_time() {
+        let reference = Utc.with_ymd_and_hms(2021, 8, 30, 0, 0, 0).unwrap();
+        let report = metar("KSEA 290353Z 01008KT 10SM SCT200 20/10 A2992").unwrap();
+        let local = report.local_observation_time(reference).unwrap();
+        assert_eq!(local.timezone(), Tz::America__Los_Angeles);
+        assert_eq!((local.hour(), local.minute()), (20, 53));
+        assert_eq!(
+            local.with_timezone(&Utc),
+            Utc.with_ymd_and_hms(2021, 8, 29, 3, 53, 0).unwrap()
+        );
+
+        let elevation = report.station().unwrap().elevation();
+        assert_eq!(
+            report.station_pressure_altitude(),
+            report.pressure_altitude(elevation)
+        );
+        assert_eq!(
+            metar("ZZZZ 290353Z 01008KT 10SM SCT200 20/10 A2992")
+                .unwrap()
+                .local_observation_time(reference),
+            None
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let station = station("KSEA").unwrap();
+        // Identifiers and names are borrowed for 'static, so the JSON has to outlive the test
+        let json: &'static str =
+            Box::leak(serde_json::to_string(station).unwrap().into_boxed_str());
+        assert!(
+            json.contains(r#""timezone":"America/Los_Angeles""#),
+            "{}",
+            json
+        );
+        assert_eq!(&serde_json::from_str::<Station>(json).unwrap(), station);
+    }
+}
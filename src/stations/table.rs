@@ -0,0 +1,122 @@
+//! Stations known to [super::station], sorted by ICAO identifier
+//!
+//! Seed subset of major airports in the format produced by `scripts/generate_stations.py`.
+//! Run the script to regenerate the full table from the mwgg/Airports dataset.
+
+use chrono_tz::Tz;
+
+use super::Station;
+
+#[rustfmt::skip]
+pub(super) const STATIONS: &[Station] = &[
+    Station::new("BIKF", "Keflavik Intl", "IS", 63.9850, -22.6056, 52.0, Tz::Atlantic__Reykjavik),
+    Station::new("CYQB", "Quebec Jean Lesage Intl", "CA", 46.7911, -71.3933, 74.0, Tz::America__Toronto),
+    Station::new("CYUL", "Montreal Trudeau Intl", "CA", 45.4706, -73.7408, 36.0, Tz::America__Toronto),
+    Station::new("CYVR", "Vancouver Intl", "CA", 49.1939, -123.1844, 4.0, Tz::America__Vancouver),
+    Station::new("CYYC", "Calgary Intl", "CA", 51.1139, -114.0203, 1084.0, Tz::America__Edmonton),
+    Station::new("CYYZ", "Toronto Pearson Intl", "CA", 43.6772, -79.6306, 173.0, Tz::America__Toronto),
+    Station::new("DNMM", "Lagos Murtala Muhammed Intl", "NG", 6.5774, 3.3212, 41.0, Tz::Africa__Lagos),
+    Station::new("EBBR", "Brussels", "BE", 50.9014, 4.4844, 56.0, Tz::Europe__Brussels),
+    Station::new("EDDB", "Berlin Brandenburg", "DE", 52.3667, 13.5033, 48.0, Tz::Europe__Berlin),
+    Station::new("EDDF", "Frankfurt am Main", "DE", 50.0333, 8.5706, 111.0, Tz::Europe__Berlin),
+    Station::new("EDDM", "Munich", "DE", 48.3538, 11.7861, 448.0, Tz::Europe__Berlin),
+    Station::new("EFHK", "Helsinki Vantaa", "FI", 60.3172, 24.9633, 55.0, Tz::Europe__Helsinki),
+    Station::new("EGCC", "Manchester", "GB", 53.3537, -2.2750, 78.0, Tz::Europe__London),
+    Station::new("EGKK", "London Gatwick", "GB", 51.1481, -0.1903, 62.0, Tz::Europe__London),
+    Station::new("EGLL", "London Heathrow", "GB", 51.4775, -0.4614, 25.0, Tz::Europe__London),
+    Station::new("EGPH", "Edinburgh", "GB", 55.9500, -3.3725, 41.0, Tz::Europe__London),
+    Station::new("EHAM", "Amsterdam Schiphol", "NL", 52.3086, 4.7639, -3.0, Tz::Europe__Amsterdam),
+    Station::new("EIDW", "Dublin", "IE", 53.4213, -6.2701, 74.0, Tz::Europe__Dublin),
+    Station::new("EKCH", "Copenhagen Kastrup", "DK", 55.6179, 12.6560, 5.0, Tz::Europe__Copenhagen),
+    Station::new("ENGM", "Oslo Gardermoen", "NO", 60.1939, 11.1004, 208.0, Tz::Europe__Oslo),
+    Station::new("EPWA", "Warsaw Chopin", "PL", 52.1657, 20.9671, 110.0, Tz::Europe__Warsaw),
+    Station::new("ESSA", "Stockholm Arlanda", "SE", 59.6519, 17.9186, 42.0, Tz::Europe__Stockholm),
+    Station::new("FACT", "Cape Town Intl", "ZA", -33.9649, 18.6017, 46.0, Tz::Africa__Johannesburg),
+    Station::new("FAOR", "Johannesburg O R Tambo Intl", "ZA", -26.1392, 28.2460, 1694.0, Tz::Africa__Johannesburg),
+    Station::new("GMMN", "Casablanca Mohammed V Intl", "MA", 33.3675, -7.5900, 200.0, Tz::Africa__Casablanca),
+    Station::new("HECA", "Cairo Intl", "EG", 30.1219, 31.4056, 116.0, Tz::Africa__Cairo),
+    Station::new("HKJK", "Nairobi Jomo Kenyatta Intl", "KE", -1.3192, 36.9278, 1624.0, Tz::Africa__Nairobi),
+    Station::new("KATL", "Hartsfield-Jackson Atlanta Intl", "US", 33.6367, -84.4281, 313.0, Tz::America__New_York),
+    Station::new("KBGR", "Bangor Intl", "US", 44.8074, -68.8281, 59.0, Tz::America__New_York),
+    Station::new("KBOS", "Boston Logan Intl", "US", 42.3643, -71.0052, 6.0, Tz::America__New_York),
+    Station::new("KBWI", "Baltimore/Washington Intl", "US", 39.1754, -76.6683, 44.0, Tz::America__New_York),
+    Station::new("KCLT", "Charlotte Douglas Intl", "US", 35.2140, -80.9431, 228.0, Tz::America__New_York),
+    Station::new("KDCA", "Ronald Reagan Washington National", "US", 38.8521, -77.0377, 5.0, Tz::America__New_York),
+    Station::new("KDEN", "Denver Intl", "US", 39.8617, -104.6731, 1656.0, Tz::America__Denver),
+    Station::new("KDFW", "Dallas/Fort Worth Intl", "US", 32.8968, -97.0380, 185.0, Tz::America__Chicago),
+    Station::new("KDTW", "Detroit Metropolitan Wayne County", "US", 42.2124, -83.3534, 197.0, Tz::America__Detroit),
+    Station::new("KEWR", "Newark Liberty Intl", "US", 40.6925, -74.1687, 5.0, Tz::America__New_York),
+    Station::new("KIAD", "Washington Dulles Intl", "US", 38.9445, -77.4558, 95.0, Tz::America__New_York),
+    Station::new("KIAH", "Houston George Bush Intercontinental", "US", 29.9844, -95.3414, 30.0, Tz::America__Chicago),
+    Station::new("KICT", "Wichita Dwight D Eisenhower National", "US", 37.6499, -97.4331, 405.0, Tz::America__Chicago),
+    Station::new("KJFK", "New York John F Kennedy Intl", "US", 40.6398, -73.7789, 4.0, Tz::America__New_York),
+    Station::new("KLAS", "Las Vegas Harry Reid Intl", "US", 36.0801, -115.1522, 665.0, Tz::America__Los_Angeles),
+    Station::new("KLAX", "Los Angeles Intl", "US", 33.9425, -118.4081, 38.0, Tz::America__Los_Angeles),
+    Station::new("KLGA", "New York LaGuardia", "US", 40.7772, -73.8726, 6.0, Tz::America__New_York),
+    Station::new("KMCO", "Orlando Intl", "US", 28.4294, -81.3090, 30.0, Tz::America__New_York),
+    Station::new("KMDW", "Chicago Midway Intl", "US", 41.7868, -87.7522, 189.0, Tz::America__Chicago),
+    Station::new("KMIA", "Miami Intl", "US", 25.7932, -80.2906, 3.0, Tz::America__New_York),
+    Station::new("KMSP", "Minneapolis-St Paul Intl", "US", 44.8820, -93.2218, 256.0, Tz::America__Chicago),
+    Station::new("KOKC", "Oklahoma City Will Rogers World", "US", 35.3931, -97.6007, 396.0, Tz::America__Chicago),
+    Station::new("KORD", "Chicago O'Hare Intl", "US", 41.9786, -87.9048, 204.0, Tz::America__Chicago),
+    Station::new("KPDX", "Portland Intl", "US", 45.5887, -122.5975, 9.0, Tz::America__Los_Angeles),
+    Station::new("KPHL", "Philadelphia Intl", "US", 39.8719, -75.2411, 11.0, Tz::America__New_York),
+    Station::new("KPHX", "Phoenix Sky Harbor Intl", "US", 33.4343, -112.0116, 345.0, Tz::America__Phoenix),
+    Station::new("KSAN", "San Diego Intl", "US", 32.7336, -117.1897, 5.0, Tz::America__Los_Angeles),
+    Station::new("KSEA", "Seattle-Tacoma Intl", "US", 47.4490, -122.3093, 131.0, Tz::America__Los_Angeles),
+    Station::new("KSFO", "San Francisco Intl", "US", 37.6190, -122.3749, 4.0, Tz::America__Los_Angeles),
+    Station::new("KSLC", "Salt Lake City Intl", "US", 40.7884, -111.9778, 1288.0, Tz::America__Denver),
+    Station::new("KTPA", "Tampa Intl", "US", 27.9755, -82.5332, 8.0, Tz::America__New_York),
+    Station::new("LEBL", "Barcelona El Prat", "ES", 41.2971, 2.0785, 4.0, Tz::Europe__Madrid),
+    Station::new("LEMD", "Madrid Barajas", "ES", 40.4719, -3.5626, 610.0, Tz::Europe__Madrid),
+    Station::new("LFMN", "Nice Cote d'Azur", "FR", 43.6584, 7.2159, 4.0, Tz::Europe__Paris),
+    Station::new("LFPG", "Paris Charles de Gaulle", "FR", 49.0097, 2.5479, 119.0, Tz::Europe__Paris),
+    Station::new("LFPO", "Paris Orly", "FR", 48.7233, 2.3794, 89.0, Tz::Europe__Paris),
+    Station::new("LGAV", "Athens Eleftherios Venizelos Intl", "GR", 37.9364, 23.9445, 94.0, Tz::Europe__Athens),
+    Station::new("LHBP", "Budapest Ferenc Liszt Intl", "HU", 47.4369, 19.2556, 151.0, Tz::Europe__Budapest),
+    Station::new("LIMC", "Milan Malpensa", "IT", 45.6306, 8.7281, 234.0, Tz::Europe__Rome),
+    Station::new("LIRF", "Rome Fiumicino", "IT", 41.8003, 12.2389, 5.0, Tz::Europe__Rome),
+    Station::new("LKPR", "Prague Vaclav Havel", "CZ", 50.1008, 14.2600, 380.0, Tz::Europe__Prague),
+    Station::new("LLBG", "Tel Aviv Ben Gurion", "IL", 32.0114, 34.8867, 41.0, Tz::Asia__Jerusalem),
+    Station::new("LOWW", "Vienna Intl", "AT", 48.1103, 16.5697, 183.0, Tz::Europe__Vienna),
+    Station::new("LPPT", "Lisbon Humberto Delgado", "PT", 38.7813, -9.1359, 114.0, Tz::Europe__Lisbon),
+    Station::new("LSGG", "Geneva", "CH", 46.2381, 6.1089, 430.0, Tz::Europe__Zurich),
+    Station::new("LSZH", "Zurich", "CH", 47.4647, 8.5492, 432.0, Tz::Europe__Zurich),
+    Station::new("LTFM", "Istanbul", "TR", 41.2753, 28.7519, 99.0, Tz::Europe__Istanbul),
+    Station::new("MMMX", "Mexico City Intl", "MX", 19.4363, -99.0721, 2230.0, Tz::America__Mexico_City),
+    Station::new("NZAA", "Auckland", "NZ", -37.0081, 174.7917, 7.0, Tz::Pacific__Auckland),
+    Station::new("NZCH", "Christchurch", "NZ", -43.4894, 172.5322, 37.0, Tz::Pacific__Auckland),
+    Station::new("OERK", "Riyadh King Khalid Intl", "SA", 24.9576, 46.6988, 625.0, Tz::Asia__Riyadh),
+    Station::new("OMDB", "Dubai Intl", "AE", 25.2528, 55.3644, 19.0, Tz::Asia__Dubai),
+    Station::new("OTHH", "Doha Hamad Intl", "QA", 25.2731, 51.6081, 4.0, Tz::Asia__Qatar),
+    Station::new("PANC", "Anchorage Ted Stevens Intl", "US", 61.1744, -149.9964, 46.0, Tz::America__Anchorage),
+    Station::new("PHNL", "Honolulu Daniel K Inouye Intl", "US", 21.3187, -157.9225, 4.0, Tz::Pacific__Honolulu),
+    Station::new("RCTP", "Taipei Taoyuan Intl", "TW", 25.0777, 121.2328, 33.0, Tz::Asia__Taipei),
+    Station::new("RJAA", "Tokyo Narita Intl", "JP", 35.7647, 140.3864, 43.0, Tz::Asia__Tokyo),
+    Station::new("RJBB", "Osaka Kansai Intl", "JP", 34.4273, 135.2440, 8.0, Tz::Asia__Tokyo),
+    Station::new("RJTT", "Tokyo Haneda", "JP", 35.5523, 139.7798, 6.0, Tz::Asia__Tokyo),
+    Station::new("RKSI", "Seoul Incheon Intl", "KR", 37.4691, 126.4510, 7.0, Tz::Asia__Seoul),
+    Station::new("RPLL", "Manila Ninoy Aquino Intl", "PH", 14.5086, 121.0194, 23.0, Tz::Asia__Manila),
+    Station::new("SAEZ", "Buenos Aires Ezeiza Intl", "AR", -34.8222, -58.5358, 20.0, Tz::America__Argentina__Buenos_Aires),
+    Station::new("SBGR", "Sao Paulo Guarulhos Intl", "BR", -23.4356, -46.4731, 750.0, Tz::America__Sao_Paulo),
+    Station::new("SCEL", "Santiago Arturo Merino Benitez Intl", "CL", -33.3930, -70.7858, 474.0, Tz::America__Santiago),
+    Station::new("SKBO", "Bogota El Dorado Intl", "CO", 4.7016, -74.1469, 2548.0, Tz::America__Bogota),
+    Station::new("SPJC", "Lima Jorge Chavez Intl", "PE", -12.0219, -77.1143, 34.0, Tz::America__Lima),
+    Station::new("UUDD", "Moscow Domodedovo", "RU", 55.4088, 37.9063, 179.0, Tz::Europe__Moscow),
+    Station::new("UUEE", "Moscow Sheremetyevo", "RU", 55.9726, 37.4146, 190.0, Tz::Europe__Moscow),
+    Station::new("UUWW", "Moscow Vnukovo", "RU", 55.5915, 37.2615, 209.0, Tz::Europe__Moscow),
+    Station::new("VABB", "Mumbai Chhatrapati Shivaji Maharaj Intl", "IN", 19.0887, 72.8679, 11.0, Tz::Asia__Kolkata),
+    Station::new("VHHH", "Hong Kong Intl", "HK", 22.3089, 113.9146, 9.0, Tz::Asia__Hong_Kong),
+    Station::new("VIDP", "Delhi Indira Gandhi Intl", "IN", 28.5665, 77.1031, 237.0, Tz::Asia__Kolkata),
+    Station::new("VTBS", "Bangkok Suvarnabhumi", "TH", 13.6811, 100.7475, 2.0, Tz::Asia__Bangkok),
+    Station::new("WIII", "Jakarta Soekarno-Hatta Intl", "ID", -6.1256, 106.6559, 10.0, Tz::Asia__Jakarta),
+    Station::new("WMKK", "Kuala Lumpur Intl", "MY", 2.7456, 101.7099, 21.0, Tz::Asia__Kuala_Lumpur),
+    Station::new("WSSS", "Singapore Changi", "SG", 1.3502, 103.9944, 7.0, Tz::Asia__Singapore),
+    Station::new("YBBN", "Brisbane", "AU", -27.3842, 153.1175, 4.0, Tz::Australia__Brisbane),
+    Station::new("YMML", "Melbourne", "AU", -37.6733, 144.8433, 132.0, Tz::Australia__Melbourne),
+    Station::new("YPPH", "Perth", "AU", -31.9403, 115.9669, 20.0, Tz::Australia__Perth),
+    Station::new("YSSY", "Sydney Kingsford Smith", "AU", -33.9461, 151.1772, 6.0, Tz::Australia__Sydney),
+    Station::new("ZBAA", "Beijing Capital Intl", "CN", 40.0801, 116.5846, 35.0, Tz::Asia__Shanghai),
+    Station::new("ZGGG", "Guangzhou Baiyun Intl", "CN", 23.3924, 113.2988, 15.0, Tz::Asia__Shanghai),
+    Station::new("ZSPD", "Shanghai Pudong Intl", "CN", 31.1434, 121.8052, 4.0, Tz::Asia__Shanghai),
+];
@@ -0,0 +1,173 @@
+pub(crate) mod parser;
+
+use crate::error::{ParseError, ParseErrorKind};
+use crate::tokens::SynopReport;
+
+#[cfg(feature = "codespan_helpers")]
+pub use crate::error::into_diagnostic;
+
+/// Parses a SYNOP report from a land station, starting with `AAXX`
+pub fn synop(input: &str) -> Result<SynopReport<'_>, ParseError> {
+    parser::synop_reports::synop(input)
+        .map_err(|err| ParseError::new(input, err).reclassify(ParseErrorKind::SynopGroup))
+}
+
+#[cfg(test)]
+mod tests {
+    use uom::si::{
+        length::meter, pressure::hectopascal, thermodynamic_temperature::degree_celsius,
+        time::hour, velocity::knot,
+    };
+
+    use super::synop;
+    use crate::error::ParseErrorKind;
+    use crate::tokens::*;
+
+    /// Rounds away floating point noise from unit conversions
+    fn tenths(value: f64) -> f64 {
+        (value * 10.).round() / 10.
+    }
+
+    #[test]
+    fn parse_synop() {
+        let report = synop("AAXX 01004 88889 12782 61506 10094 20047 30111 40197 53007 60001 76162 81541 333 10112 20051 55083 81656 86070 =").unwrap();
+        assert_eq!(report.station, "88889");
+        assert_eq!(report.observation_time.day_of_month, 1);
+        assert_eq!(report.observation_time.time.hour, 0);
+        assert_eq!(
+            report.wind_speed_source,
+            Some(WindSpeedSource::MeasuredKnots)
+        );
+        assert_eq!(
+            report.station_operation,
+            Some(StationOperation::MannedNoSignificantWeather)
+        );
+        assert_eq!(
+            tenths(
+                report
+                    .visibility
+                    .unwrap()
+                    .prevailing
+                    .unwrap()
+                    .distance
+                    .get::<meter>()
+            ),
+            40000.
+        );
+        assert_eq!(report.total_cloud_cover, Some(6));
+        let wind = report.wind.unwrap();
+        assert_eq!(tenths(wind.speed.unwrap().get::<knot>()), 6.);
+        let temperatures = report.temperatures.unwrap();
+        assert_eq!(tenths(temperatures.air.get::<degree_celsius>()), 9.4);
+        assert_eq!(
+            tenths(temperatures.dewpoint.unwrap().get::<degree_celsius>()),
+            4.7
+        );
+        assert_eq!(
+            tenths(report.station_pressure.unwrap().get::<hectopascal>()),
+            1011.1
+        );
+        assert_eq!(
+            tenths(report.sea_level_pressure.unwrap().get::<hectopascal>()),
+            1019.7
+        );
+        let tendency = report.pressure_tendency.unwrap();
+        assert_eq!(
+            tendency.characteristic,
+            PressureTendencyCharacteristic::DecreasingOrSteadyThenIncreasing
+        );
+        assert_eq!(report.precipitation.unwrap().period_hours, Some(6));
+        assert_eq!(report.present_weather_code, Some(61));
+        assert_eq!(
+            report.weather,
+            vec![Weather {
+                intensity: Intensity::Light,
+                vicinity: false,
+                descriptor: None,
+                condition: Some(Condition::Precipitation(vec![Precipitation::Rain])),
+            }]
+        );
+        assert_eq!(
+            report.cloud_genera,
+            Some(CloudGenera {
+                amount: Some(1),
+                low: Some(CloudGenus::Stratocumulus),
+                middle: Some(CloudGenus::Altocumulus),
+                high: Some(CloudGenus::Cirrus),
+            })
+        );
+
+        let section = report.climatological_section.unwrap();
+        assert_eq!(
+            tenths(section.maximum_temperature.unwrap().get::<degree_celsius>()),
+            11.2
+        );
+        assert_eq!(
+            tenths(section.sunshine_duration.unwrap().get::<hour>()),
+            8.3
+        );
+        assert_eq!(section.cloud_layers.len(), 2);
+        assert_eq!(section.cloud_layers[0].cover.coverage, CloudCoverage::Few);
+        assert_eq!(
+            tenths(section.cloud_layers[0].cover.base.unwrap().get::<meter>()),
+            1800.
+        );
+        assert_eq!(section.cloud_layers[1].genus, Some(CloudGenus::Cirrus));
+        assert_eq!(
+            section.cloud_layers[1].cover.cloud_type,
+            Some(CloudType::Cirrus)
+        );
+    }
+
+    #[test]
+    fn parse_synop_variants() {
+        let report = synop("AAXX 25121 03772 NIL=").unwrap();
+        assert!(report.nil);
+
+        // Relative humidity instead of dewpoint, extended wind speed, missing groups and coastal section
+        let report = synop("AAXX 25121 03772 41/96 /9999 00105 1//// 29085 3//// 4//// 222// 00123 20304 333 58/// 555 10025").unwrap();
+        assert_eq!(report.lowest_cloud_base, None);
+        assert_eq!(report.total_cloud_cover, None);
+        assert_eq!(report.relative_humidity, Some(85));
+        assert_eq!(report.temperatures, None);
+        assert_eq!(report.sea_level_pressure, None);
+        let wind = report.wind.unwrap();
+        assert_eq!(wind.direction, None);
+        assert_eq!(wind.speed.unwrap().value, 105.);
+        let water_conditions = report.water_conditions.unwrap();
+        assert_eq!(
+            tenths(
+                water_conditions
+                    .temperature
+                    .unwrap()
+                    .get::<degree_celsius>()
+            ),
+            12.3
+        );
+        assert_eq!(
+            tenths(
+                water_conditions
+                    .significant_wave_height
+                    .unwrap()
+                    .get::<meter>()
+            ),
+            2.
+        );
+        assert_eq!(
+            report.climatological_section.unwrap().unparsed,
+            vec!["58///"]
+        );
+        assert_eq!(report.regional_sections.as_deref(), Some("555 10025"));
+    }
+
+    #[test]
+    fn report_synop_errors() {
+        let val = "AAXX 01004 88889 12782 61506 1X094 20047";
+        let err = synop(val).unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::SynopGroup);
+        assert_eq!(&val[err.span.clone()], "1X094");
+
+        let err = synop("AAXX 01004 88889").unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::UnexpectedEnd);
+    }
+}
@@ -0,0 +1,685 @@
+use std::convert::TryFrom;
+use uom::{
+    si::angle::degree,
+    si::f64::{Angle, Length, Pressure, ThermodynamicTemperature, Time, Velocity},
+    si::length::{centimeter, meter, millimeter},
+    si::pressure::hectopascal,
+    si::thermodynamic_temperature::degree_celsius,
+    si::time::hour as hours,
+    si::velocity::{knot, meter_per_second},
+};
+
+use crate::tokens::*;
+
+/// A single group of section 1
+enum Section1Group {
+    Indicators {
+        precipitation_indicator: Option<PrecipitationIndicator>,
+        station_operation: Option<StationOperation>,
+        lowest_cloud_base: Option<CloudBaseRange>,
+        visibility: Option<Visibility>,
+    },
+    CloudCoverAndWind {
+        total_cloud_cover: Option<u8>,
+        wind: Option<Wind>,
+    },
+    AirTemperature(Option<ThermodynamicTemperature>),
+    Dewpoint(Option<ThermodynamicTemperature>),
+    RelativeHumidity(u8),
+    StationPressure(Option<Pressure>),
+    SeaLevelPressure(Option<Pressure>),
+    GeopotentialHeight(GeopotentialHeight),
+    PressureTendency(Option<PressureTendency>),
+    Precipitation(Option<PrecipitationAmount>),
+    Weather {
+        present: Option<u8>,
+        past: [Option<u8>; 2],
+    },
+    CloudGenera(CloudGenera),
+    ExactObservationTime(ZuluTime),
+}
+
+/// A single group of section 2
+enum Section2Group {
+    WaterTemperature(ThermodynamicTemperature),
+    WaveHeight(Option<Length>),
+    Unparsed,
+}
+
+/// A single group of section 3
+enum Section3Group<'input> {
+    MaximumTemperature(Option<ThermodynamicTemperature>),
+    MinimumTemperature(Option<ThermodynamicTemperature>),
+    SnowDepth(Option<Length>),
+    SunshineDuration(Time),
+    HourlySunshineDuration(Time),
+    Precipitation(Option<PrecipitationAmount>),
+    Precipitation24Hour(Option<PrecipitationAmount>),
+    CloudLayer(Option<SynopCloudLayer>),
+    Unparsed(&'input str),
+}
+
+peg::parser! {
+    pub grammar synop_reports() for str {
+        /// [SYNOP](https://en.wikipedia.org/wiki/SYNOP) parser for reports from land stations
+        pub rule synop() -> SynopReport<'input> =
+                    whitespace()
+                    "AAXX" required_whitespace()
+                    day_of_month:number(2) hour:number(2) wind_speed_source:wind_speed_source() group_end() required_whitespace()
+                    station:$(quiet!{digit()*<5>} / expected!("station number")) group_end() whitespace()
+                    body:(
+                        "NIL" group_end() { None }
+                        / section_1:section_1(wind_speed_source) whitespace()
+                          section_2:section_2()? whitespace()
+                          section_3:section_3()? whitespace()
+                          regional_sections:$(("444" / "555") group_end() [^'=']*)? {
+                            Some((section_1, section_2, section_3, regional_sections))
+                        }
+                    ) whitespace()
+                    // Some machines use = to indicate end of message
+                    quiet!{"=" [_]*}? whitespace()
+                    {
+                let mut report = SynopReport {
+                    station: station.into(),
+                    observation_time: ZuluDateTime {
+                        day_of_month: day_of_month.unwrap_or_default() as u8,
+                        time: ZuluTime {
+                            hour: hour.unwrap_or_default() as u8,
+                            minute: 0,
+                        },
+                        is_zulu: true,
+                    },
+                    wind_speed_source,
+                    nil: body.is_none(),
+                    precipitation_indicator: None,
+                    station_operation: None,
+                    lowest_cloud_base: None,
+                    visibility: None,
+                    total_cloud_cover: None,
+                    wind: None,
+                    temperatures: None,
+                    relative_humidity: None,
+                    station_pressure: None,
+                    sea_level_pressure: None,
+                    geopotential_height: None,
+                    pressure_tendency: None,
+                    precipitation: None,
+                    present_weather_code: None,
+                    past_weather_codes: [None, None],
+                    weather: vec![],
+                    cloud_genera: None,
+                    exact_observation_time: None,
+                    water_conditions: None,
+                    climatological_section: None,
+                    regional_sections: None,
+                };
+                let (section_1, section_2, section_3, regional_sections) = match body {
+                    Some(body) => body,
+                    None => return report,
+                };
+                let mut air_temperature = None;
+                let mut dewpoint = None;
+                for group in section_1 {
+                    match group {
+                        Section1Group::Indicators {
+                            precipitation_indicator,
+                            station_operation,
+                            lowest_cloud_base,
+                            visibility,
+                        } => {
+                            report.precipitation_indicator = precipitation_indicator;
+                            report.station_operation = station_operation;
+                            report.lowest_cloud_base = lowest_cloud_base;
+                            report.visibility = visibility;
+                        }
+                        Section1Group::CloudCoverAndWind { total_cloud_cover, wind } => {
+                            report.total_cloud_cover = total_cloud_cover;
+                            report.wind = wind;
+                        }
+                        Section1Group::AirTemperature(temperature) => air_temperature = temperature,
+                        Section1Group::Dewpoint(temperature) => dewpoint = temperature,
+                        Section1Group::RelativeHumidity(humidity) => report.relative_humidity = Some(humidity),
+                        Section1Group::StationPressure(pressure) => report.station_pressure = pressure,
+                        Section1Group::SeaLevelPressure(pressure) => report.sea_level_pressure = pressure,
+                        Section1Group::GeopotentialHeight(height) => report.geopotential_height = Some(height),
+                        Section1Group::PressureTendency(tendency) => report.pressure_tendency = tendency,
+                        Section1Group::Precipitation(precipitation) => report.precipitation = precipitation,
+                        Section1Group::Weather { present, past } => {
+                            report.present_weather_code = present;
+                            report.past_weather_codes = past;
+                        }
+                        Section1Group::CloudGenera(genera) => report.cloud_genera = Some(genera),
+                        Section1Group::ExactObservationTime(time) => report.exact_observation_time = Some(time),
+                    }
+                }
+                report.temperatures = air_temperature.map(|air| Temperatures { air, dewpoint });
+                // Automatic stations may use a different table for present weather
+                if report.station_operation != Some(StationOperation::AutomaticWithAutomaticWeather) {
+                    report.weather = report.present_weather_code.and_then(present_weather).into_iter().collect();
+                }
+                report.water_conditions = section_2.flatten();
+                report.climatological_section = section_3;
+                report.regional_sections = regional_sections.map(str::trim_end).map(Into::into);
+                report
+            }
+
+        /// `iRixhVV Nddff (00fff)` followed by the optional groups
+        rule section_1(wind_speed_source: Option<WindSpeedSource>) -> Vec<Section1Group> =
+            indicators:indicators() required_whitespace()
+            cloud_cover_and_wind:cloud_cover_and_wind(wind_speed_source)
+            groups:(required_whitespace() group:section_1_group() { group })* {
+                vec![indicators, cloud_cover_and_wind].into_iter().chain(groups).collect()
+            }
+
+        rule indicators() -> Section1Group =
+            precipitation_indicator:$(code()) station_operation:$(code()) lowest_cloud_base:number(1) visibility:number(2) group_end() {
+                Section1Group::Indicators {
+                    precipitation_indicator: PrecipitationIndicator::try_from(precipitation_indicator).ok(),
+                    station_operation: StationOperation::try_from(station_operation).ok(),
+                    lowest_cloud_base: lowest_cloud_base.map(|code| cloud_base_range(code as u8)),
+                    visibility: visibility.and_then(|code| raw_visibility(code as u8)).map(|prevailing| Visibility {
+                        prevailing: Some(prevailing),
+                        minimum_directional: None,
+                        maximum_directional: None,
+                    }),
+                }
+            }
+
+        rule cloud_cover_and_wind(wind_speed_source: Option<WindSpeedSource>) -> Section1Group =
+            total_cloud_cover:number(1) direction:number(2) speed:number(2) group_end()
+            extended_speed:(required_whitespace() "00" speed:number(3) group_end() { speed })? {
+                let speed = if speed == Some(99) { extended_speed.flatten() } else { speed };
+                let wind = Wind {
+                    // 99 indicates variable
+                    direction: direction.filter(|direction| *direction <= 36).map(|direction| Angle::new::<degree>(direction as f64 * 10.)),
+                    speed: speed.and_then(|speed| match wind_speed_source? {
+                        WindSpeedSource::EstimatedMetersPerSecond | WindSpeedSource::MeasuredMetersPerSecond => Some(Velocity::new::<meter_per_second>(speed as f64)),
+                        WindSpeedSource::EstimatedKnots | WindSpeedSource::MeasuredKnots => Some(Velocity::new::<knot>(speed as f64)),
+                    }),
+                    peak_gust: None,
+                    variance: None,
+                };
+                Section1Group::CloudCoverAndWind {
+                    total_cloud_cover: total_cloud_cover.map(|oktas| oktas as u8),
+                    wind: if wind.direction.is_none() && wind.speed.is_none() { None } else { Some(wind) },
+                }
+            }
+
+        rule section_1_group() -> Section1Group =
+            "1" temperature:temperature() group_end() { Section1Group::AirTemperature(temperature) }
+            / "29" humidity:$(digit()*<3>) group_end() { Section1Group::RelativeHumidity(humidity.parse().unwrap()) }
+            / "2" temperature:temperature() group_end() { Section1Group::Dewpoint(temperature) }
+            / "3" pressure:pressure() group_end() { Section1Group::StationPressure(pressure) }
+            / "4" pressure:$(['0' | '9'] digit()*<3>) group_end() { Section1Group::SeaLevelPressure(Some(hectopascals(pressure))) }
+            / "4////" group_end() { Section1Group::SeaLevelPressure(None) }
+            / "4" surface:$(['1' | '2' | '5' | '7' | '8']) height:$(digit()*<3>) group_end() {
+                Section1Group::GeopotentialHeight(geopotential_height(surface, height.parse().unwrap()))
+            }
+            / "5" tendency:pressure_tendency() group_end() { Section1Group::PressureTendency(tendency) }
+            / "6" precipitation:precipitation() group_end() { Section1Group::Precipitation(precipitation) }
+            / "7" present:number(2) first_past:number(1) second_past:number(1) group_end() {
+                Section1Group::Weather {
+                    present: present.map(|code| code as u8),
+                    past: [first_past.map(|code| code as u8), second_past.map(|code| code as u8)],
+                }
+            }
+            / "8" amount:number(1) low:number(1) middle:number(1) high:number(1) group_end() {
+                Section1Group::CloudGenera(CloudGenera {
+                    amount: amount.map(|oktas| oktas as u8),
+                    low: low.and_then(|code| low_cloud_genus(code as u8)),
+                    middle: middle.and_then(|code| middle_cloud_genus(code as u8)),
+                    high: high.and_then(|code| high_cloud_genus(code as u8)),
+                })
+            }
+            / "9" hour:number(2) minute:number(2) group_end() {
+                Section1Group::ExactObservationTime(ZuluTime {
+                    hour: hour.unwrap_or_default() as u8,
+                    minute: minute.unwrap_or_default() as u8,
+                })
+            }
+
+        /// Sea surface temperature and waves, as reported by coastal stations
+        rule section_2() -> Option<WaterConditions> =
+            "222" code()*<2> group_end()
+            groups:(required_whitespace() group:section_2_group() { group })* {
+                let mut water_conditions = WaterConditions {
+                    temperature: None,
+                    surface_state: None,
+                    significant_wave_height: None,
+                };
+                for group in groups {
+                    match group {
+                        Section2Group::WaterTemperature(temperature) => water_conditions.temperature = Some(temperature),
+                        // Instrumental measurements come first and are preferred over estimates
+                        Section2Group::WaveHeight(height) => water_conditions.significant_wave_height = water_conditions.significant_wave_height.or(height),
+                        Section2Group::Unparsed => {}
+                    }
+                }
+                if water_conditions.temperature.is_none() && water_conditions.significant_wave_height.is_none() {
+                    None
+                } else {
+                    Some(water_conditions)
+                }
+            }
+
+        rule section_2_group() -> Section2Group =
+            "0" sign:$(['0'..='7']) tenths:$(digit()*<3>) group_end() {
+                // Odd signs are negative, the rest indicate how the temperature was measured
+                let temperature = tenths.parse::<f64>().unwrap() / 10.;
+                Section2Group::WaterTemperature(ThermodynamicTemperature::new::<degree_celsius>(if sign.parse::<u8>().unwrap() % 2 == 1 { -temperature } else { temperature }))
+            }
+            / ['1' | '2'] code()*<2> height:number(2) group_end() {
+                Section2Group::WaveHeight(height.map(|half_meters| Length::new::<meter>(half_meters as f64 / 2.)))
+            }
+            / code()*<5> group_end() { Section2Group::Unparsed }
+
+        rule section_3() -> SynopClimatologicalSection<'input> =
+            "333" group_end()
+            groups:(required_whitespace() group:section_3_group() { group })* {
+                let mut section = SynopClimatologicalSection::default();
+                for group in groups {
+                    match group {
+                        Section3Group::MaximumTemperature(temperature) => section.maximum_temperature = temperature,
+                        Section3Group::MinimumTemperature(temperature) => section.minimum_temperature = temperature,
+                        Section3Group::SnowDepth(depth) => section.snow_depth = depth,
+                        Section3Group::SunshineDuration(duration) => section.sunshine_duration = Some(duration),
+                        Section3Group::HourlySunshineDuration(duration) => section.hourly_sunshine_duration = Some(duration),
+                        Section3Group::Precipitation(precipitation) => section.precipitation = precipitation,
+                        Section3Group::Precipitation24Hour(precipitation) => section.precipitation_24_hour = precipitation,
+                        Section3Group::CloudLayer(layer) => section.cloud_layers.extend(layer),
+                        Section3Group::Unparsed(group) => section.unparsed.push(group.into()),
+                    }
+                }
+                section
+            }
+
+        rule section_3_group() -> Section3Group<'input> =
+            "1" temperature:temperature() group_end() { Section3Group::MaximumTemperature(temperature) }
+            / "2" temperature:temperature() group_end() { Section3Group::MinimumTemperature(temperature) }
+            / "4" code() depth:number(3) group_end() {
+                Section3Group::SnowDepth(match depth {
+                    Some(997) => Some(Length::new::<centimeter>(0.)),
+                    // 998 is a discontinuous cover and 999 means it could not be measured
+                    Some(depth) if depth < 997 => Some(Length::new::<centimeter>(depth as f64)),
+                    _ => None,
+                })
+            }
+            / "553" tenths:$(digit()*<2>) group_end() {
+                Section3Group::HourlySunshineDuration(Time::new::<hours>(tenths.parse::<f64>().unwrap() / 10.))
+            }
+            / "55" tenths:$(['0'..='2'] digit()*<2>) group_end() {
+                Section3Group::SunshineDuration(Time::new::<hours>(tenths.parse::<f64>().unwrap() / 10.))
+            }
+            / "6" precipitation:precipitation() group_end() { Section3Group::Precipitation(precipitation) }
+            / "7" tenths:number(4) group_end() {
+                Section3Group::Precipitation24Hour(tenths.map(|tenths| PrecipitationAmount {
+                    amount: Length::new::<millimeter>(if tenths == 9999 { 0. } else { tenths as f64 / 10. }),
+                    trace: tenths == 9999,
+                    period_hours: Some(24),
+                }))
+            }
+            / "8" amount:number(1) genus:number(1) height:number(2) group_end() {
+                let genus = genus.map(|code| CLOUD_GENERA[code as usize]);
+                Section3Group::CloudLayer(amount.and_then(|oktas| cloud_coverage(oktas as u8)).map(|coverage| SynopCloudLayer {
+                    genus,
+                    cover: CloudCover {
+                        coverage,
+                        base: height.and_then(|code| cloud_height(code as u8)),
+                        cloud_type: genus.and_then(|genus| genus.cloud_type()),
+                    },
+                }))
+            }
+            / group:$(code()*<5>) group_end() { Section3Group::Unparsed(group) }
+
+        /// Sign digit followed by tenths of a degree celsius
+        rule temperature() -> Option<ThermodynamicTemperature> =
+            sign:$(['0' | '1']) tenths:$(digit()*<3>) {
+                let temperature = tenths.parse::<f64>().unwrap() / 10.;
+                Some(ThermodynamicTemperature::new::<degree_celsius>(if sign == "1" { -temperature } else { temperature }))
+            }
+            / "////" { None }
+
+        /// Tenths of a hectopascal without the thousands digit
+        rule pressure() -> Option<Pressure> =
+            pressure:$(digit()*<4>) { Some(hectopascals(pressure)) }
+            / "////" { None }
+
+        rule pressure_tendency() -> Option<PressureTendency> =
+            characteristic:$(['0'..='8']) change:$(digit()*<3>) {
+                let characteristic = PressureTendencyCharacteristic::try_from(characteristic).unwrap();
+                let change = Pressure::new::<hectopascal>(change.parse::<f64>().unwrap() / 10.);
+                Some(PressureTendency {
+                    characteristic,
+                    change: match characteristic {
+                        PressureTendencyCharacteristic::DecreasingThenIncreasing
+                        | PressureTendencyCharacteristic::DecreasingThenSteady
+                        | PressureTendencyCharacteristic::Decreasing
+                        | PressureTendencyCharacteristic::SteadyOrIncreasingThenDecreasing => -change,
+                        _ => change,
+                    },
+                })
+            }
+            / code()*<4> { None }
+
+        /// `RRRtR`: amount in millimeters and the period it covers
+        rule precipitation() -> Option<PrecipitationAmount> =
+            amount:$(digit()*<3>) period:number(1) {
+                let amount = amount.parse::<u16>().unwrap();
+                Some(PrecipitationAmount {
+                    amount: Length::new::<millimeter>(match amount {
+                        990 => 0.,
+                        991..=999 => (amount - 990) as f64 / 10.,
+                        _ => amount as f64,
+                    }),
+                    trace: amount == 990,
+                    period_hours: period.and_then(|code| precipitation_period(code as u8)),
+                })
+            }
+            / code()*<4> { None }
+
+        rule wind_speed_source() -> Option<WindSpeedSource> =
+            val:$(quiet!{['0' | '1' | '3' | '4']} / expected!("wind speed indicator")) { WindSpeedSource::try_from(val).ok() }
+            / "/" { None }
+
+        /// A number of `n` digits, or slashes if it is missing
+        rule number(n: usize) -> Option<u16> =
+            val:$(quiet!{digit()*<{n}>} / expected!("number")) { Some(val.parse().unwrap()) }
+            / quiet!{"/"*<{n}>} { None }
+            / expected!("number or slashes")
+
+        rule code() -> &'input str = quiet!{$(['0'..='9' | '/'])} / expected!("digit or slash");
+        rule digit() -> &'input str = quiet!{$(['0'..='9'])} / expected!("digit");
+        rule group_end() = &(required_whitespace() / "=" / ![_])
+        rule whitespace() = required_whitespace()?
+        rule required_whitespace() = quiet!{[' ' | '\r' | '\n' | '\t']+} / expected!("whitespace")
+    }
+}
+
+/// Code table 0500
+const CLOUD_GENERA: [CloudGenus; 10] = [
+    CloudGenus::Cirrus,
+    CloudGenus::Cirrocumulus,
+    CloudGenus::Cirrostratus,
+    CloudGenus::Altocumulus,
+    CloudGenus::Altostratus,
+    CloudGenus::Nimbostratus,
+    CloudGenus::Stratocumulus,
+    CloudGenus::Stratus,
+    CloudGenus::Cumulus,
+    CloudGenus::Cumulonimbus,
+];
+
+/// Tenths of a hectopascal, where the thousands digit is omitted
+fn hectopascals(tenths: &str) -> Pressure {
+    let tenths = tenths.parse::<f64>().unwrap();
+    Pressure::new::<hectopascal>(if tenths < 5000. { 1000. } else { 0. } + tenths / 10.)
+}
+
+/// `a3hhh`: standard isobaric surface and its height in meters without the leading digit
+fn geopotential_height(surface: &str, height: u16) -> GeopotentialHeight {
+    let height = height as f64;
+    let (isobaric_surface, height) = match surface {
+        "1" => (1000., height),
+        "2" => (925., height),
+        "5" => (500., 5000. + height),
+        "7" => (700., if height < 500. { 3000. } else { 2000. } + height),
+        "8" => (850., 1000. + height),
+        _ => unreachable!(),
+    };
+    GeopotentialHeight {
+        isobaric_surface: Pressure::new::<hectopascal>(isobaric_surface),
+        height: Length::new::<meter>(height),
+    }
+}
+
+/// Code table 1600
+fn cloud_base_range(code: u8) -> CloudBaseRange {
+    const BOUNDS: [f64; 10] = [0., 50., 100., 200., 300., 600., 1000., 1500., 2000., 2500.];
+    let code = code as usize;
+    CloudBaseRange {
+        lower: Length::new::<meter>(BOUNDS[code]),
+        upper: BOUNDS
+            .get(code + 1)
+            .map(|upper| Length::new::<meter>(*upper)),
+    }
+}
+
+/// Code table 1677, taking the lower bound of ranges
+fn cloud_height(code: u8) -> Option<Length> {
+    let meters = match code {
+        0..=50 => code as f64 * 30.,
+        56..=80 => (code - 50) as f64 * 300.,
+        81..=88 => (code - 80) as f64 * 1500. + 9000.,
+        89 => 21000.,
+        90..=99 => cloud_base_range(code - 90).lower.get::<meter>(),
+        _ => return None,
+    };
+    Some(Length::new::<meter>(meters))
+}
+
+/// Code table 4377
+fn raw_visibility(code: u8) -> Option<RawVisibility> {
+    let (out_of_range, meters) = match code {
+        0 => (Some(OutOfRange::Below), 100.),
+        1..=50 => (None, code as f64 * 100.),
+        56..=80 => (None, (code - 50) as f64 * 1000.),
+        81..=88 => (None, ((code - 80) as f64 * 5. + 30.) * 1000.),
+        89 => (Some(OutOfRange::Above), 70000.),
+        90 => (Some(OutOfRange::Below), 50.),
+        91 => (None, 50.),
+        92 => (None, 200.),
+        93 => (None, 500.),
+        94 => (None, 1000.),
+        95 => (None, 2000.),
+        96 => (None, 4000.),
+        97 => (None, 10000.),
+        98 => (None, 20000.),
+        99 => (Some(OutOfRange::Above), 50000.),
+        _ => return None,
+    };
+    Some(RawVisibility {
+        out_of_range,
+        distance: Length::new::<meter>(meters),
+    })
+}
+
+/// Code table 2700
+fn cloud_coverage(oktas: u8) -> Option<CloudCoverage> {
+    match oktas {
+        0 => Some(CloudCoverage::NoCloud),
+        1 | 2 => Some(CloudCoverage::Few),
+        3 | 4 => Some(CloudCoverage::Scattered),
+        5..=7 => Some(CloudCoverage::Broken),
+        8 => Some(CloudCoverage::Overcast),
+        9 => Some(CloudCoverage::VerticalVisibility),
+        _ => None,
+    }
+}
+
+/// Code table 4019
+fn precipitation_period(code: u8) -> Option<u8> {
+    [
+        None,
+        Some(6),
+        Some(12),
+        Some(18),
+        Some(24),
+        Some(1),
+        Some(2),
+        Some(3),
+        Some(9),
+        Some(15),
+    ]
+    .get(code as usize)
+    .copied()
+    .flatten()
+}
+
+/// Code table 0513, taking the predominant genus
+fn low_cloud_genus(code: u8) -> Option<CloudGenus> {
+    match code {
+        1 | 2 | 8 => Some(CloudGenus::Cumulus),
+        3 | 9 => Some(CloudGenus::Cumulonimbus),
+        4 | 5 => Some(CloudGenus::Stratocumulus),
+        6 | 7 => Some(CloudGenus::Stratus),
+        _ => None,
+    }
+}
+
+/// Code table 0515, taking the predominant genus
+fn middle_cloud_genus(code: u8) -> Option<CloudGenus> {
+    match code {
+        1 => Some(CloudGenus::Altostratus),
+        2 => Some(CloudGenus::Nimbostratus),
+        3..=9 => Some(CloudGenus::Altocumulus),
+        _ => None,
+    }
+}
+
+/// Code table 0509, taking the predominant genus
+fn high_cloud_genus(code: u8) -> Option<CloudGenus> {
+    match code {
+        1..=4 => Some(CloudGenus::Cirrus),
+        5..=8 => Some(CloudGenus::Cirrostratus),
+        9 => Some(CloudGenus::Cirrocumulus),
+        _ => None,
+    }
+}
+
+/// Present weather from code table 4677
+///
+/// Codes describing weather in the past hour or without a METAR equivalent are skipped.
+fn present_weather(code: u8) -> Option<Weather> {
+    use Intensity::*;
+
+    let weather = |intensity, descriptor, condition| {
+        Some(Weather {
+            intensity,
+            vicinity: false,
+            descriptor,
+            condition,
+        })
+    };
+    let precipitation = |intensity, descriptor, precipitation: &[Precipitation]| {
+        weather(
+            intensity,
+            descriptor,
+            Some(Condition::Precipitation(precipitation.to_vec())),
+        )
+    };
+    let obscuration = |descriptor, obscuration| {
+        weather(
+            Moderate,
+            descriptor,
+            Some(Condition::Obscuration(obscuration)),
+        )
+    };
+    let other = |intensity, other| weather(intensity, None, Some(Condition::Other(other)));
+    // Most codes come in pairs of increasing intensity
+    let paired = |first: u8| match (code - first) / 2 {
+        0 => Light,
+        1 => Moderate,
+        _ => Heavy,
+    };
+
+    match code {
+        4 => obscuration(None, Obscuration::Smoke),
+        5 => obscuration(None, Obscuration::Haze),
+        6 => obscuration(None, Obscuration::WidespreadDust),
+        10 => obscuration(None, Obscuration::Mist),
+        11 | 12 => obscuration(Some(Descriptor::Shallow), Obscuration::Fog),
+        17 => weather(Moderate, Some(Descriptor::Thunderstorm), None),
+        18 => other(Moderate, Other::Squall),
+        19 => other(Moderate, Other::FunnelCloud),
+        30..=32 => other(Moderate, Other::Duststorm),
+        33..=35 => other(Heavy, Other::Duststorm),
+        36 => precipitation(
+            Moderate,
+            Some(Descriptor::LowDrifting),
+            &[Precipitation::Snow],
+        ),
+        37 => precipitation(Heavy, Some(Descriptor::LowDrifting), &[Precipitation::Snow]),
+        38 => precipitation(Moderate, Some(Descriptor::Blowing), &[Precipitation::Snow]),
+        39 => precipitation(Heavy, Some(Descriptor::Blowing), &[Precipitation::Snow]),
+        40 => Some(Weather {
+            intensity: Moderate,
+            vicinity: true,
+            descriptor: None,
+            condition: Some(Condition::Obscuration(Obscuration::Fog)),
+        }),
+        41 => obscuration(Some(Descriptor::Patches), Obscuration::Fog),
+        42..=47 => obscuration(None, Obscuration::Fog),
+        48 | 49 => obscuration(Some(Descriptor::Freezing), Obscuration::Fog),
+        50..=55 => precipitation(paired(50), None, &[Precipitation::Drizzle]),
+        56 => precipitation(Light, Some(Descriptor::Freezing), &[Precipitation::Drizzle]),
+        57 => precipitation(
+            Moderate,
+            Some(Descriptor::Freezing),
+            &[Precipitation::Drizzle],
+        ),
+        58 => precipitation(Light, None, &[Precipitation::Drizzle, Precipitation::Rain]),
+        59 => precipitation(
+            Moderate,
+            None,
+            &[Precipitation::Drizzle, Precipitation::Rain],
+        ),
+        60..=65 => precipitation(paired(60), None, &[Precipitation::Rain]),
+        66 => precipitation(Light, Some(Descriptor::Freezing), &[Precipitation::Rain]),
+        67 => precipitation(Moderate, Some(Descriptor::Freezing), &[Precipitation::Rain]),
+        68 => precipitation(Light, None, &[Precipitation::Rain, Precipitation::Snow]),
+        69 => precipitation(Moderate, None, &[Precipitation::Rain, Precipitation::Snow]),
+        70..=75 => precipitation(paired(70), None, &[Precipitation::Snow]),
+        76 => precipitation(Moderate, None, &[Precipitation::IceCrystals]),
+        77 => precipitation(Moderate, None, &[Precipitation::SnowGrains]),
+        79 => precipitation(Moderate, None, &[Precipitation::IcePellets]),
+        80 => precipitation(Light, Some(Descriptor::Showers), &[Precipitation::Rain]),
+        81 => precipitation(Moderate, Some(Descriptor::Showers), &[Precipitation::Rain]),
+        82 => precipitation(Heavy, Some(Descriptor::Showers), &[Precipitation::Rain]),
+        83 => precipitation(
+            Light,
+            Some(Descriptor::Showers),
+            &[Precipitation::Rain, Precipitation::Snow],
+        ),
+        84 => precipitation(
+            Moderate,
+            Some(Descriptor::Showers),
+            &[Precipitation::Rain, Precipitation::Snow],
+        ),
+        85 => precipitation(Light, Some(Descriptor::Showers), &[Precipitation::Snow]),
+        86 => precipitation(Moderate, Some(Descriptor::Showers), &[Precipitation::Snow]),
+        87 => precipitation(Light, Some(Descriptor::Showers), &[Precipitation::Graupel]),
+        88 => precipitation(
+            Moderate,
+            Some(Descriptor::Showers),
+            &[Precipitation::Graupel],
+        ),
+        89 => precipitation(Light, Some(Descriptor::Showers), &[Precipitation::Hail]),
+        90 => precipitation(Moderate, Some(Descriptor::Showers), &[Precipitation::Hail]),
+        95 => precipitation(
+            Moderate,
+            Some(Descriptor::Thunderstorm),
+            &[Precipitation::Rain],
+        ),
+        96 => precipitation(
+            Moderate,
+            Some(Descriptor::Thunderstorm),
+            &[Precipitation::Hail],
+        ),
+        97 => precipitation(
+            Heavy,
+            Some(Descriptor::Thunderstorm),
+            &[Precipitation::Rain],
+        ),
+        98 => weather(
+            Moderate,
+            Some(Descriptor::Thunderstorm),
+            Some(Condition::Other(Other::Duststorm)),
+        ),
+        99 => precipitation(
+            Heavy,
+            Some(Descriptor::Thunderstorm),
+            &[Precipitation::Hail],
+        ),
+        _ => None,
+    }
+}
@@ -0,0 +1,129 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::metar::encode::temperature;
+use crate::tokens::*;
+
+/// Renders a forecast back into TAF text
+///
+/// Groups are written on a single line; see [crate::metar::encode::metar] for how units are chosen.
+pub fn taf(report: &TafReport) -> String {
+    report.to_string()
+}
+
+impl Display for ZuluDayHour {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}{:02}", self.day_of_month, self.hour)
+    }
+}
+
+impl Display for ZuluDayHourRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.begin, self.end)
+    }
+}
+
+impl<'input> Display for TafForecast<'input> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut groups = vec![];
+        groups.extend(self.wind.iter().map(ToString::to_string));
+        if self.cavok {
+            groups.push("CAVOK".to_string());
+        }
+        groups.extend(self.visibility.iter().map(ToString::to_string));
+        groups.extend(self.weather.iter().map(ToString::to_string));
+        if self.no_significant_weather {
+            groups.push("NSW".to_string());
+        }
+        groups.extend(self.cloud_cover.iter().map(ToString::to_string));
+        groups.extend(self.wind_shear.iter().map(ToString::to_string));
+        write!(f, "{}", groups.join(" "))
+    }
+}
+
+impl Display for TafChangeIndicator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TafChangeIndicator::From(time) => write!(f, "FM{}", time),
+            TafChangeIndicator::Becoming(period) => write!(f, "BECMG {}", period),
+            TafChangeIndicator::Temporarily(period) => write!(f, "TEMPO {}", period),
+            TafChangeIndicator::Probability {
+                probability,
+                period,
+            } => write!(f, "PROB{:02} {}", probability, period),
+            TafChangeIndicator::ProbabilityTemporarily {
+                probability,
+                period,
+            } => write!(f, "PROB{:02} TEMPO {}", probability, period),
+        }
+    }
+}
+
+impl<'input> Display for TafChangeGroup<'input> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let forecast = self.forecast.to_string();
+        if forecast.is_empty() {
+            write!(f, "{}", self.indicator)
+        } else {
+            write!(f, "{} {}", self.indicator, forecast)
+        }
+    }
+}
+
+impl Display for TemperatureForecast {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}/{}Z",
+            <&str>::from(self.forecast_type),
+            temperature(self.temperature),
+            self.time
+        )
+    }
+}
+
+impl<'input> Display for TafReport<'input> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // NIL and CNL are written after the validity period, other flags before the identifier
+        let (post_flags, pre_flags): (Vec<TafFlag>, Vec<TafFlag>) = self
+            .flags
+            .iter()
+            .copied()
+            .partition(|flag| matches!(flag, TafFlag::Nil | TafFlag::Cancelled));
+
+        let mut groups = vec!["TAF".to_string()];
+        groups.extend(pre_flags.iter().map(|flag| <&str>::from(*flag).to_string()));
+        groups.push(self.identifier.to_string());
+        groups.extend(self.issue_time.iter().map(ToString::to_string));
+        groups.extend(self.validity.iter().map(ToString::to_string));
+        groups.extend(
+            post_flags
+                .iter()
+                .map(|flag| <&str>::from(*flag).to_string()),
+        );
+        groups.push(self.forecast.to_string());
+        groups.extend(self.temperature_forecasts.iter().map(ToString::to_string));
+        groups.extend(self.changes.iter().map(ToString::to_string));
+        groups.extend(self.remark.as_deref().map(str::to_string));
+        groups.retain(|group| !group.is_empty());
+        write!(f, "{}", groups.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::taf::parse::taf;
+
+    #[test]
+    fn encode_taf() {
+        for val in [
+            "TAF KSEA 251720Z 2518/2624 20008KT P6SM FEW040 BKN250 FM252000 22010KT P6SM SCT050 BKN200 TEMPO 2522/2602 5SM -SHRA BKN035 PROB30 2604/2608 3SM BR OVC008 PROB40 TEMPO 2610/2612 1/2SM FG VV002 BECMG 2612/2614 NSW SKC",
+            "TAF AMD EGLL 251058Z 2512/2618 24010KT 9999 SCT035 PROB30 TEMPO 2514/2518 7000 SHRA BECMG 2600/2603 VRB03KT",
+            "TAF LFPG 251100Z 2512/2618 CAVOK TX25/2515Z TNM02/2605Z",
+            "TAF EGLL 251100Z NIL",
+            "TAF KDEN 251720Z 2518/2624 18010KT P6SM SCT100 WS020/27045KT FM260300 VRB05KT P6SM SKC",
+        ] {
+            let report = taf(val).expect(val);
+            assert_eq!(report.to_string(), val);
+        }
+    }
+}
@@ -1,5 +1,9 @@
+pub mod encode;
 pub mod parse;
-pub mod builder;
+#[cfg(feature = "chrono_helpers")]
+pub mod timeline;
+#[cfg(feature = "chrono_helpers")]
+pub mod verification;
 
 #[cfg(test)]
 mod tests {
@@ -78,11 +82,7 @@ mod tests {
                 .map(|report| report.split_at(13).1)
                 .filter(|report| {
                     // Skip Canada SAO observations
-                    if report.contains("AUTO8") {
-                        false
-                    } else {
-                        true
-                    }
+                    !report.contains("AUTO8")
                 })
                 .filter_map(|report| {
                     acc += 1;
@@ -162,11 +162,7 @@ mod tests {
                     .filter(|report| {
                         // Skip SAO observations
                         let sao_name = path.get(1..=3).unwrap();
-                        if report.starts_with(&format!(" {} ", sao_name)) {
-                            false
-                        } else {
-                            true
-                        }
+                        !report.starts_with(&format!(" {} ", sao_name))
                     })
                     .filter_map(|report| {
                         acc += 1;
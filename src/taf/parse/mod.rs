@@ -1,43 +1,118 @@
-mod parser;
+use crate::error::ParseError;
+use crate::metar::parse::parser::weather_reports;
+use crate::tokens::TafReport;
 
-pub use parser::weather_reports::taf;
-
-/// Convenience function for converting a parsing error
-/// into a [codespan_reporting::diagnostic::Diagnostic] for displaying to a user.
 #[cfg(feature = "codespan_helpers")]
-pub fn into_diagnostic(
-    err: &peg::error::ParseError<peg::str::LineCol>,
-) -> codespan_reporting::diagnostic::Diagnostic<()> {
-    let expected_count = err.expected.tokens().count();
-    let label_msg = if expected_count == 0 {
-        "unclear cause".to_string()
-    } else if expected_count == 1 {
-        format!("expected {}", err.expected.tokens().next().unwrap())
-    } else {
-        let tokens = {
-            let mut tokens = err.expected.tokens().collect::<Vec<_>>();
-            tokens.sort_unstable();
-            tokens
-        };
-        let mut acc = "expected one of ".to_string();
-        for token in tokens.iter().take(expected_count - 1) {
-            acc += token;
-            acc += ", ";
-        }
-        acc += "or ";
-        acc += tokens.last().unwrap();
-        acc
-    };
-    codespan_reporting::diagnostic::Diagnostic::error()
-        .with_message("could not parse report")
-        .with_labels(vec![codespan_reporting::diagnostic::Label::primary(
-            (),
-            err.location.offset..err.location.offset,
-        )
-        .with_message(label_msg)])
+pub use crate::error::into_diagnostic;
+
+/// Parses a TAF report
+pub fn taf(input: &str) -> Result<TafReport<'_>, ParseError> {
+    weather_reports::taf(input).map_err(|err| ParseError::new(input, err))
 }
 
 #[cfg(test)]
 mod tests {
+    use super::taf;
+    use crate::tokens::*;
+
+    #[test]
+    fn parse_taf() {
+        for val in [
+            "TAF KSEA 251720Z 2518/2624 20008KT P6SM FEW040 BKN250",
+            "TAF AMD KTPA 300530Z 3006/3106 VRB03KT P6SM SCT040 FM301500 09008KT P6SM BKN050",
+            "TAF EGLL 251058Z 2512/2618 24010KT 9999 SCT035 PROB30 TEMPO 2514/2518 7000 SHRA BECMG 2600/2603 VRB03KT=",
+            "TAF LFPG 251100Z 2512/2618 CAVOK TX25/2515Z TN14/2605Z",
+            "TAF EGLL 251100Z NIL=",
+        ] {
+            taf(val).expect(val);
+        }
+    }
+
+    #[test]
+    fn parse_taf_change_groups() {
+        let report = taf("TAF KSEA 251720Z 2518/2624 20008KT P6SM FEW040 BKN250
+      FM252000 22010KT P6SM SCT050 BKN200
+      TEMPO 2522/2602 5SM -SHRA BKN035
+      PROB30 2604/2608 3SM BR OVC008
+      PROB40 TEMPO 2610/2612 1/2SM FG VV002
+      BECMG 2612/2614 NSW SKC").unwrap();
+        assert_eq!(report.identifier, "KSEA");
+        assert_eq!(
+            report.validity,
+            Some(ZuluDayHourRange {
+                begin: ZuluDayHour {
+                    day_of_month: 25,
+                    hour: 18
+                },
+                end: ZuluDayHour {
+                    day_of_month: 26,
+                    hour: 24
+                },
+            })
+        );
+        assert_eq!(report.forecast.cloud_cover.len(), 2);
+        assert_eq!(report.changes.len(), 5);
+        assert!(matches!(
+            report.changes[0].indicator,
+            TafChangeIndicator::From(ZuluDateTime {
+                day_of_month: 25,
+                time: ZuluTime { hour: 20, minute: 0 },
+                ..
+            })
+        ));
+        assert!(matches!(
+            report.changes[1].indicator,
+            TafChangeIndicator::Temporarily(_)
+        ));
+        assert_eq!(report.changes[1].forecast.weather.len(), 1);
+        assert!(matches!(
+            report.changes[2].indicator,
+            TafChangeIndicator::Probability { probability: 30, .. }
+        ));
+        assert!(matches!(
+            report.changes[3].indicator,
+            TafChangeIndicator::ProbabilityTemporarily { probability: 40, .. }
+        ));
+        assert!(matches!(
+            report.changes[4].indicator,
+            TafChangeIndicator::Becoming(_)
+        ));
+        assert!(report.changes[4].forecast.no_significant_weather);
+    }
+
+    #[test]
+    fn parse_taf_wind_shear() {
+        use uom::si::{angle::degree, length::foot, velocity::knot};
+
+        let report = taf("TAF KDEN 251720Z 2518/2624 18010KT P6SM SCT100 WS020/27045KT FM260300 VRB05KT P6SM SKC").unwrap();
+        match report.forecast.wind_shear {
+            Some(WindShear::LowLevel { height, wind }) => {
+                assert_eq!(height.get::<foot>().round(), 2000.);
+                assert_eq!(wind.direction.unwrap().get::<degree>().round(), 270.);
+                assert_eq!(wind.speed.unwrap().get::<knot>().round(), 45.);
+            }
+            wind_shear => panic!("unexpected wind shear {:?}", wind_shear),
+        }
+        assert_eq!(report.changes.len(), 1);
+        assert_eq!(report.changes[0].forecast.wind_shear, None);
+    }
+
+    #[cfg(feature = "chrono_helpers")]
+    #[test]
+    fn resolve_taf_validity_across_year_rollover() {
+        use chrono::TimeZone;
 
+        let report = taf("TAF KSEA 311720Z 3118/0124 20008KT P6SM FEW040").unwrap();
+        // Archived a few days later
+        let reference = chrono::Utc.with_ymd_and_hms(2022, 1, 3, 0, 0, 0).unwrap();
+        assert_eq!(
+            report.resolve_validity(reference),
+            Some(
+                chrono::Utc
+                    .with_ymd_and_hms(2021, 12, 31, 18, 0, 0)
+                    .unwrap()
+                    ..chrono::Utc.with_ymd_and_hms(2022, 1, 2, 0, 0, 0).unwrap()
+            )
+        );
+    }
 }
@@ -0,0 +1,410 @@
+//! Resolves the change groups of a TAF into the conditions forecasted at a given time
+//!
+//! `FM` groups replace the forecast entirely and `BECMG` groups are applied once their period ends.
+//! While a `BECMG` transition is in progress, the new conditions are offered as an alternative
+//! alongside `TEMPO` and `PROB` groups, each applied on top of the prevailing forecast.
+
+use std::ops::Range;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::flight_category::FlightConditions;
+use crate::tokens::*;
+
+/// How an [AlternativeForecast] relates to the prevailing forecast
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ChangeKind {
+    /// `FM`: replaces the prevailing forecast from the beginning of its period
+    From,
+    /// `BECMG`: becomes the prevailing forecast at the end of its period
+    Becoming,
+    /// `TEMPO`
+    Temporarily,
+    /// `PROB30`/`PROB40`, in percent
+    Probability(u8),
+    /// `PROB30 TEMPO`/`PROB40 TEMPO`, in percent
+    ProbabilityTemporarily(u8),
+}
+
+/// Conditions that may occur instead of the prevailing forecast
+#[derive(Clone, PartialEq, Debug)]
+pub struct AlternativeForecast<'input> {
+    pub kind: ChangeKind,
+    /// Period of the change group, ending with the validity period for `FM`
+    pub period: Range<DateTime<Utc>>,
+    /// The change group applied on top of the prevailing forecast
+    pub forecast: TafForecast<'input>,
+}
+
+/// Forecasted conditions at a time or over a period
+#[derive(Clone, PartialEq, Debug)]
+pub struct ForecastConditions<'input> {
+    /// Conditions at the beginning of the period
+    pub prevailing: TafForecast<'input>,
+    /// In the order they were reported
+    pub alternatives: Vec<AlternativeForecast<'input>>,
+}
+
+impl<'input> ForecastConditions<'input> {
+    /// The prevailing forecast followed by the alternatives
+    pub fn forecasts(&self) -> impl Iterator<Item = &TafForecast<'input>> {
+        std::iter::once(&self.prevailing).chain(
+            self.alternatives
+                .iter()
+                .map(|alternative| &alternative.forecast),
+        )
+    }
+
+    /// Worst [crate::flight_category::FlightCategory] among the prevailing and alternative forecasts
+    ///
+    /// Returns [None] if none of them can be classified.
+    pub fn worst_flight_category(&self) -> Option<FlightConditions> {
+        self.forecasts()
+            .filter_map(TafForecast::flight_category)
+            .min_by_key(|conditions| conditions.category)
+    }
+}
+
+/// A TAF with its validity period and change groups resolved to absolute times
+#[derive(Clone, PartialEq, Debug)]
+pub struct TafTimeline<'report, 'input> {
+    validity: Range<DateTime<Utc>>,
+    base: &'report TafForecast<'input>,
+    changes: Vec<(
+        ChangeKind,
+        Range<DateTime<Utc>>,
+        &'report TafForecast<'input>,
+    )>,
+}
+
+impl<'input> TafReport<'input> {
+    /// Resolves the report against `reference` so it can be queried by time
+    ///
+    /// See [TafReport::resolve_validity] for how `reference` is used.
+    /// Returns [None] if the validity period or any change group period is missing or invalid.
+    pub fn timeline(&self, reference: DateTime<Utc>) -> Option<TafTimeline<'_, 'input>> {
+        let validity = self.resolve_validity(reference)?;
+        let changes = self
+            .changes
+            .iter()
+            .map(|change| {
+                let (kind, period) = match change.indicator {
+                    TafChangeIndicator::From(time) => (
+                        ChangeKind::From,
+                        time.resolve(validity.start)?..validity.end,
+                    ),
+                    TafChangeIndicator::Becoming(period) => {
+                        (ChangeKind::Becoming, period.resolve(validity.start)?)
+                    }
+                    TafChangeIndicator::Temporarily(period) => {
+                        (ChangeKind::Temporarily, period.resolve(validity.start)?)
+                    }
+                    TafChangeIndicator::Probability {
+                        probability,
+                        period,
+                    } => (
+                        ChangeKind::Probability(probability),
+                        period.resolve(validity.start)?,
+                    ),
+                    TafChangeIndicator::ProbabilityTemporarily {
+                        probability,
+                        period,
+                    } => (
+                        ChangeKind::ProbabilityTemporarily(probability),
+                        period.resolve(validity.start)?,
+                    ),
+                };
+                Some((kind, period, &change.forecast))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(TafTimeline {
+            validity,
+            base: &self.forecast,
+            changes,
+        })
+    }
+}
+
+impl<'report, 'input> TafTimeline<'report, 'input> {
+    pub fn validity(&self) -> Range<DateTime<Utc>> {
+        self.validity.clone()
+    }
+
+    /// Conditions forecasted at `time`
+    ///
+    /// Returns [None] if `time` is outside of the validity period.
+    pub fn at(&self, time: DateTime<Utc>) -> Option<ForecastConditions<'input>> {
+        if !self.validity.contains(&time) {
+            return None;
+        }
+        Some(self.conditions(time..time))
+    }
+
+    /// Conditions forecasted over `period`, clamped to the validity period
+    ///
+    /// Changes that take effect after the beginning of the period are included as alternatives,
+    /// i.e. use `eta - 1h..eta + 1h` for the conditions around an estimated time of arrival.
+    /// Returns [None] if `period` does not overlap the validity period.
+    pub fn during(&self, period: Range<DateTime<Utc>>) -> Option<ForecastConditions<'input>> {
+        let period = period.start.max(self.validity.start)..period.end.min(self.validity.end);
+        if period.start >= period.end {
+            return None;
+        }
+        Some(self.conditions(period))
+    }
+
+    /// Consecutive periods of at most an hour covering the validity period, with their conditions
+    pub fn hourly(
+        &self,
+    ) -> impl Iterator<Item = (Range<DateTime<Utc>>, ForecastConditions<'input>)> + '_ {
+        let end = self.validity.end;
+        std::iter::successors(Some(self.validity.start), move |start| {
+            Some(*start + Duration::hours(1)).filter(|next| *next < end)
+        })
+        .map(move |start| {
+            let period = start..(start + Duration::hours(1)).min(end);
+            (period.clone(), self.conditions(period))
+        })
+    }
+
+    /// Applies `FM` and completed `BECMG` groups to the base forecast
+    fn prevailing_at(&self, time: DateTime<Utc>) -> TafForecast<'input> {
+        let mut prevailing = self.base.clone();
+        for (kind, period, forecast) in &self.changes {
+            match kind {
+                ChangeKind::From if period.start <= time => prevailing = (*forecast).clone(),
+                ChangeKind::Becoming if period.end <= time => prevailing.apply(forecast),
+                _ => {}
+            }
+        }
+        prevailing
+    }
+
+    /// An empty `period` is treated as an instant
+    fn conditions(&self, period: Range<DateTime<Utc>>) -> ForecastConditions<'input> {
+        let alternatives = self
+            .changes
+            .iter()
+            .filter(|(_, change_period, _)| {
+                (change_period.start < period.end || change_period.start == period.start)
+                    && change_period.end > period.start
+            })
+            .filter_map(|(kind, change_period, forecast)| {
+                let forecast = match kind {
+                    ChangeKind::From if change_period.start > period.start => {
+                        self.prevailing_at(change_period.start)
+                    }
+                    ChangeKind::From => return None,
+                    ChangeKind::Becoming => {
+                        let mut becoming = self.prevailing_at(change_period.start);
+                        becoming.apply(forecast);
+                        becoming
+                    }
+                    _ => {
+                        let mut temporary =
+                            self.prevailing_at(change_period.start.max(period.start));
+                        temporary.apply(forecast);
+                        temporary
+                    }
+                };
+                Some(AlternativeForecast {
+                    kind: *kind,
+                    period: change_period.clone(),
+                    forecast,
+                })
+            })
+            .collect();
+        ForecastConditions {
+            prevailing: self.prevailing_at(period.start),
+            alternatives,
+        }
+    }
+}
+
+impl<'input> TafForecast<'input> {
+    /// Overrides the elements reported in `change`, as for a `BECMG`, `TEMPO` or `PROB` group
+    ///
+    /// `CAVOK` clears visibility, weather and clouds, and `NSW` clears weather.
+    /// Reporting visibility or clouds cancels a previous `CAVOK`.
+    pub fn apply(&mut self, change: &TafForecast<'input>) {
+        if change.wind.is_some() {
+            self.wind = change.wind;
+        }
+        if change.cavok {
+            self.cavok = true;
+            self.visibility = None;
+            self.weather.clear();
+            self.cloud_cover.clear();
+        }
+        if change.visibility.is_some() {
+            self.visibility = change.visibility;
+            self.cavok = false;
+        }
+        if !change.weather.is_empty() {
+            self.weather = change.weather.clone();
+            self.no_significant_weather = false;
+        }
+        if change.no_significant_weather {
+            self.weather.clear();
+            self.no_significant_weather = true;
+        }
+        if !change.cloud_cover.is_empty() {
+            self.cloud_cover = change.cloud_cover.clone();
+            self.cavok = false;
+        }
+        if change.wind_shear.is_some() {
+            self.wind_shear = change.wind_shear.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use uom::si::{length::foot, velocity::knot};
+
+    use super::*;
+    use crate::flight_category::FlightCategory;
+    use crate::taf::parse::taf;
+
+    fn utc(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2021, 8, day, hour, minute, 0).unwrap()
+    }
+
+    const KSEA: &str = "TAF KSEA 251720Z 2518/2624 20008KT P6SM FEW040 BKN250
+      FM252000 22010KT P6SM SCT050 BKN200
+      TEMPO 2522/2602 5SM -SHRA BKN035
+      PROB30 2604/2608 3SM BR OVC008
+      BECMG 2612/2614 NSW SKC
+      FM261800 VRB03KT P6SM FEW100";
+
+    #[test]
+    fn prevailing_conditions() {
+        let report = taf(KSEA).unwrap();
+        let timeline = report.timeline(utc(25, 18, 0)).unwrap();
+        assert_eq!(timeline.validity(), utc(25, 18, 0)..utc(27, 0, 0));
+        assert_eq!(timeline.at(utc(25, 17, 59)), None);
+        assert_eq!(timeline.at(utc(27, 0, 0)), None);
+
+        let conditions = timeline.at(utc(25, 18, 0)).unwrap();
+        assert_eq!(conditions.prevailing, report.forecast);
+        assert!(conditions.alternatives.is_empty());
+
+        // FM replaces the forecast entirely
+        let conditions = timeline.at(utc(25, 21, 0)).unwrap();
+        assert_eq!(conditions.prevailing, report.changes[0].forecast);
+
+        // BECMG is applied once complete, keeping the wind and visibility from FM
+        let prevailing = timeline.at(utc(26, 15, 0)).unwrap().prevailing;
+        assert!(prevailing.is_sky_clear());
+        assert!(prevailing.no_significant_weather);
+        assert_eq!(
+            prevailing
+                .wind
+                .unwrap()
+                .speed
+                .unwrap()
+                .get::<knot>()
+                .round(),
+            10.
+        );
+        assert_eq!(prevailing.visibility, report.changes[0].forecast.visibility);
+
+        let prevailing = timeline.at(utc(26, 23, 59)).unwrap().prevailing;
+        assert_eq!(prevailing, report.changes[4].forecast);
+    }
+
+    #[test]
+    fn alternative_conditions() {
+        let report = taf(KSEA).unwrap();
+        let timeline = report.timeline(utc(25, 18, 0)).unwrap();
+
+        let conditions = timeline.at(utc(26, 0, 0)).unwrap();
+        assert_eq!(conditions.alternatives.len(), 1);
+        let tempo = &conditions.alternatives[0];
+        assert_eq!(tempo.kind, ChangeKind::Temporarily);
+        assert_eq!(tempo.period, utc(25, 22, 0)..utc(26, 2, 0));
+        // Wind is carried over from the prevailing forecast
+        assert_eq!(tempo.forecast.wind, conditions.prevailing.wind);
+        assert_eq!(tempo.forecast.weather.len(), 1);
+        assert_eq!(
+            tempo.forecast.ceiling().unwrap().get::<foot>().round(),
+            3500.
+        );
+        assert_eq!(
+            conditions.worst_flight_category().unwrap().category,
+            FlightCategory::MarginalVisual
+        );
+
+        let conditions = timeline.at(utc(26, 5, 0)).unwrap();
+        assert_eq!(conditions.alternatives[0].kind, ChangeKind::Probability(30));
+        assert_eq!(
+            conditions.worst_flight_category().unwrap().category,
+            FlightCategory::Instrument
+        );
+
+        // While becoming, both the old and new conditions are possible
+        let conditions = timeline.at(utc(26, 13, 0)).unwrap();
+        assert!(!conditions.prevailing.is_sky_clear());
+        assert_eq!(conditions.alternatives[0].kind, ChangeKind::Becoming);
+        assert!(conditions.alternatives[0].forecast.is_sky_clear());
+    }
+
+    #[test]
+    fn conditions_around_eta() {
+        let report = taf(KSEA).unwrap();
+        let timeline = report.timeline(utc(25, 18, 0)).unwrap();
+        let eta = utc(25, 20, 30);
+        let conditions = timeline
+            .during(eta - Duration::hours(1)..eta + Duration::hours(1))
+            .unwrap();
+        assert_eq!(conditions.prevailing, report.forecast);
+        let kinds = conditions
+            .alternatives
+            .iter()
+            .map(|alternative| alternative.kind)
+            .collect::<Vec<_>>();
+        assert_eq!(kinds, vec![ChangeKind::From]);
+        assert_eq!(
+            conditions.alternatives[0].forecast,
+            report.changes[0].forecast
+        );
+
+        // Clamped to the validity period
+        assert!(timeline
+            .during(utc(25, 12, 0)..utc(25, 19, 0))
+            .unwrap()
+            .alternatives
+            .is_empty());
+        assert_eq!(timeline.during(utc(27, 0, 0)..utc(27, 2, 0)), None);
+    }
+
+    #[test]
+    fn hourly_periods() {
+        let report = taf(KSEA).unwrap();
+        let timeline = report.timeline(utc(25, 18, 0)).unwrap();
+        let periods = timeline.hourly().collect::<Vec<_>>();
+        assert_eq!(periods.len(), 30);
+        assert!(periods
+            .windows(2)
+            .all(|pair| pair[0].0.end == pair[1].0.start));
+        assert_eq!(periods[0].0, utc(25, 18, 0)..utc(25, 19, 0));
+        assert_eq!(periods[29].0.end, utc(27, 0, 0));
+        // FM252000 takes effect at the start of the third hour
+        assert_eq!(periods[2].1.prevailing, report.changes[0].forecast);
+        assert!(periods[2].1.alternatives.is_empty());
+    }
+
+    #[test]
+    fn apply_cavok() {
+        let mut forecast = taf("TAF EGLL 251058Z 2512/2618 24010KT 4000 RA BKN010")
+            .unwrap()
+            .forecast;
+        forecast.apply(&taf("TAF EGLL 251058Z 2512/2618 CAVOK").unwrap().forecast);
+        assert!(forecast.cavok);
+        assert_eq!(forecast.visibility, None);
+        assert!(forecast.weather.is_empty());
+        assert!(forecast.cloud_cover.is_empty());
+        assert!(forecast.wind.is_some());
+    }
+}
@@ -0,0 +1,389 @@
+//! Verifies a TAF against the METAR and SPECI observations made during its validity period
+//!
+//! An observation hits an element when it falls within the envelope spanned by the prevailing
+//! forecast and any alternatives at the time of the observation (see [super::timeline]).
+//! Thresholds follow the TAF amendment criteria in ICAO Annex 3:
+//!
+//! * Ceiling and visibility are compared by [FlightCategory] band
+//! * Wind misses on a mean speed difference of 10 knots, a direction difference of 60° at 10 knots or more,
+//!   or gusts 10 knots above the forecast
+//! * Weather is compared by significant phenomena: thunderstorms, freezing precipitation or fog,
+//!   moderate or heavy precipitation, squalls, duststorms, sandstorms, and funnel clouds
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use uom::si::{angle::degree, velocity::knot};
+
+use super::timeline::TafTimeline;
+use crate::clouds;
+use crate::flight_category::{ceiling_category, lowest_base, visibility_category, FlightCategory};
+use crate::tokens::*;
+
+const WIND_SPEED_TOLERANCE_KNOTS: f64 = 10.;
+const WIND_DIRECTION_TOLERANCE_DEGREES: f64 = 60.;
+/// Direction is only verified when both the observed and forecasted speeds are at least this
+const WIND_DIRECTION_MINIMUM_SPEED_KNOTS: f64 = 10.;
+const WIND_GUST_TOLERANCE_KNOTS: f64 = 10.;
+
+/// Hit and miss counts for an element
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Tally {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+impl Tally {
+    pub fn total(&self) -> usize {
+        self.hits + self.misses
+    }
+
+    /// Fraction of verified observations that were hits
+    ///
+    /// Returns [None] if no observations were verified.
+    pub fn hit_rate(&self) -> Option<f64> {
+        if self.total() == 0 {
+            None
+        } else {
+            Some(self.hits as f64 / self.total() as f64)
+        }
+    }
+
+    fn record(&mut self, hit: Option<bool>) {
+        match hit {
+            Some(true) => self.hits += 1,
+            Some(false) => self.misses += 1,
+            None => {}
+        }
+    }
+}
+
+/// Outcome of verifying a single observation
+///
+/// Each element is [None] if it was not observed or could not be derived from the forecast.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ObservationVerification {
+    pub time: DateTime<Utc>,
+    pub observed_category: Option<FlightCategory>,
+    pub ceiling: Option<bool>,
+    pub visibility: Option<bool>,
+    pub wind: Option<bool>,
+    pub weather: Option<bool>,
+    pub flight_category: Option<bool>,
+}
+
+/// Hit and miss statistics for a TAF
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Verification {
+    /// In the order they were given
+    pub observations: Vec<ObservationVerification>,
+    /// Observations that are `NIL`, have no valid time, or fall outside of the validity period
+    pub skipped: usize,
+    pub ceiling: Tally,
+    pub visibility: Tally,
+    pub wind: Tally,
+    pub weather: Tally,
+    pub flight_category: Tally,
+    /// Flight category hits and misses, keyed by the observed category
+    pub by_flight_category: BTreeMap<FlightCategory, Tally>,
+}
+
+impl<'report, 'input> TafTimeline<'report, 'input> {
+    /// Verifies the forecast against observations from the same station
+    ///
+    /// Observation times are resolved against the beginning of the validity period.
+    pub fn verify(&self, observations: &[MetarReport<'_>]) -> Verification {
+        let mut verification = Verification::default();
+        for observation in observations {
+            match self.verify_observation(observation) {
+                Some(outcome) => {
+                    verification.ceiling.record(outcome.ceiling);
+                    verification.visibility.record(outcome.visibility);
+                    verification.wind.record(outcome.wind);
+                    verification.weather.record(outcome.weather);
+                    verification.flight_category.record(outcome.flight_category);
+                    if let Some(category) = outcome.observed_category {
+                        verification
+                            .by_flight_category
+                            .entry(category)
+                            .or_default()
+                            .record(outcome.flight_category);
+                    }
+                    verification.observations.push(outcome);
+                }
+                None => verification.skipped += 1,
+            }
+        }
+        verification
+    }
+
+    fn verify_observation(&self, observation: &MetarReport<'_>) -> Option<ObservationVerification> {
+        if observation
+            .observation_flags
+            .contains(&ObservationFlag::Nil)
+        {
+            return None;
+        }
+        let time = observation
+            .observation_time?
+            .resolve(self.validity().start)?;
+        let conditions = self.at(time)?;
+        let forecasts = conditions.forecasts().collect::<Vec<_>>();
+
+        let observed_category = observation
+            .flight_category()
+            .map(|conditions| conditions.category);
+        Some(ObservationVerification {
+            time,
+            observed_category,
+            ceiling: within_bands(
+                ceiling_band(&observation.cloud_cover, observation.cavok),
+                forecasts
+                    .iter()
+                    .filter_map(|forecast| ceiling_band(&forecast.cloud_cover, forecast.cavok)),
+            ),
+            visibility: within_bands(
+                visibility_band(observation.visibility.as_ref(), observation.cavok),
+                forecasts.iter().filter_map(|forecast| {
+                    visibility_band(forecast.visibility.as_ref(), forecast.cavok)
+                }),
+            ),
+            wind: observation.wind.as_ref().and_then(|observed| {
+                any_hit(
+                    forecasts
+                        .iter()
+                        .filter_map(|forecast| forecast.wind.as_ref())
+                        .map(|forecasted| wind_hit(observed, forecasted)),
+                )
+            }),
+            weather: {
+                let observed = SignificantWeather::from(observation.weather.as_slice());
+                Some(forecasts.iter().any(|forecast| {
+                    SignificantWeather::from(forecast.weather.as_slice()) == observed
+                }))
+            },
+            flight_category: within_bands(
+                observed_category,
+                forecasts
+                    .iter()
+                    .filter_map(|forecast| forecast.flight_category())
+                    .map(|conditions| conditions.category),
+            ),
+        })
+    }
+}
+
+fn ceiling_band(cloud_cover: &[CloudCover], cavok: bool) -> Option<FlightCategory> {
+    if cavok {
+        Some(FlightCategory::Visual)
+    } else {
+        lowest_base(cloud_cover, clouds::is_ceiling).map(ceiling_category)
+    }
+}
+
+fn visibility_band(visibility: Option<&Visibility>, cavok: bool) -> Option<FlightCategory> {
+    if cavok {
+        Some(FlightCategory::Visual)
+    } else {
+        visibility?.prevailing.map(visibility_category)
+    }
+}
+
+/// Whether `observed` lies between the worst and best of `forecasted`
+fn within_bands(
+    observed: Option<FlightCategory>,
+    forecasted: impl Iterator<Item = FlightCategory>,
+) -> Option<bool> {
+    let observed = observed?;
+    let (worst, best) = forecasted.fold(None, |range, category| match range {
+        None => Some((category, category)),
+        Some((worst, best)) => Some((category.min(worst), category.max(best))),
+    })?;
+    Some(worst <= observed && observed <= best)
+}
+
+/// A hit if any forecast hits, a miss if any can be verified, otherwise [None]
+fn any_hit(outcomes: impl Iterator<Item = Option<bool>>) -> Option<bool> {
+    outcomes.fold(None, |acc, outcome| match (acc, outcome) {
+        (Some(true), _) | (_, Some(true)) => Some(true),
+        (Some(false), _) | (_, Some(false)) => Some(false),
+        (None, None) => None,
+    })
+}
+
+fn wind_hit(observed: &Wind, forecasted: &Wind) -> Option<bool> {
+    let observed_speed = observed.speed?.get::<knot>();
+    let forecasted_speed = forecasted.speed?.get::<knot>();
+    if (observed_speed - forecasted_speed).abs() >= WIND_SPEED_TOLERANCE_KNOTS {
+        return Some(false);
+    }
+    if let (Some(observed_direction), Some(forecasted_direction)) =
+        (observed.direction, forecasted.direction)
+    {
+        let difference = (observed_direction.get::<degree>()
+            - forecasted_direction.get::<degree>())
+        .rem_euclid(360.);
+        if observed_speed >= WIND_DIRECTION_MINIMUM_SPEED_KNOTS
+            && forecasted_speed >= WIND_DIRECTION_MINIMUM_SPEED_KNOTS
+            && difference.min(360. - difference) >= WIND_DIRECTION_TOLERANCE_DEGREES
+        {
+            return Some(false);
+        }
+    }
+    if let Some(gust) = observed.peak_gust {
+        let forecasted_maximum = forecasted
+            .peak_gust
+            .map(|gust| gust.get::<knot>())
+            .unwrap_or(forecasted_speed);
+        if gust.get::<knot>() - forecasted_maximum >= WIND_GUST_TOLERANCE_KNOTS {
+            return Some(false);
+        }
+    }
+    Some(true)
+}
+
+/// Phenomena whose onset or cessation warrants a TAF amendment, ignoring weather in the vicinity
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+struct SignificantWeather {
+    thunderstorm: bool,
+    freezing: bool,
+    precipitation: bool,
+    squall: bool,
+    duststorm_or_sandstorm: bool,
+    funnel_cloud: bool,
+}
+
+impl From<&[Weather]> for SignificantWeather {
+    fn from(weather: &[Weather]) -> Self {
+        let mut significant = Self::default();
+        for weather in weather.iter().filter(|weather| !weather.vicinity) {
+            match weather.descriptor {
+                Some(Descriptor::Thunderstorm) => significant.thunderstorm = true,
+                Some(Descriptor::Freezing) => significant.freezing = true,
+                _ => {}
+            }
+            match &weather.condition {
+                Some(Condition::Precipitation(_)) if weather.intensity != Intensity::Light => {
+                    significant.precipitation = true
+                }
+                Some(Condition::Other(Other::Squall)) => significant.squall = true,
+                Some(Condition::Other(Other::Duststorm | Other::Sandstorm)) => {
+                    significant.duststorm_or_sandstorm = true
+                }
+                Some(Condition::Other(Other::FunnelCloud)) => significant.funnel_cloud = true,
+                _ => {}
+            }
+        }
+        significant
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::metar::parse::metar;
+    use crate::taf::parse::taf;
+
+    #[test]
+    fn verify_observations() {
+        let report = taf("TAF KSEA 251720Z 2518/2624 20008KT P6SM FEW040 BKN250
+      FM252000 22010KT P6SM SCT050 BKN200
+      TEMPO 2522/2602 5SM -SHRA BKN035
+      PROB30 2604/2608 3SM BR OVC008")
+        .unwrap();
+        let timeline = report
+            .timeline(Utc.with_ymd_and_hms(2021, 8, 25, 18, 0, 0).unwrap())
+            .unwrap();
+        let observations = [
+            "KSEA 251853Z 19009KT 10SM FEW040 BKN250 19/09 A3004",
+            // Within the TEMPO envelope
+            "KSEA 252353Z 22012KT 5SM -SHRA BKN035 17/11 A3002",
+            // Ceiling and visibility worse than forecast, thunderstorm not forecast
+            "KSEA 260053Z 22012KT 2SM TSRA OVC006 16/14 A2998",
+            // Wind veered and gusting
+            "KSEA 260153Z 31025G40KT 10SM SCT050 BKN200 15/10 A2999",
+            // Within the PROB30 envelope
+            "KSEA 260553Z 22008KT 3SM BR OVC008 13/12 A3001",
+            "KSEA 251853Z NIL",
+            // Outside of the validity period
+            "KSEA 270053Z 22008KT 10SM FEW040 19/09 A3004",
+        ]
+        .iter()
+        .map(|val| metar(val).unwrap())
+        .collect::<Vec<_>>();
+
+        let verification = timeline.verify(&observations);
+        assert_eq!(verification.observations.len(), 5);
+        assert_eq!(verification.skipped, 2);
+        assert_eq!(verification.ceiling, Tally { hits: 4, misses: 1 });
+        assert_eq!(verification.visibility, Tally { hits: 4, misses: 1 });
+        assert_eq!(verification.wind, Tally { hits: 4, misses: 1 });
+        assert_eq!(verification.weather, Tally { hits: 4, misses: 1 });
+        assert_eq!(verification.flight_category.hit_rate(), Some(0.8));
+        assert_eq!(
+            verification.by_flight_category[&FlightCategory::Instrument],
+            Tally { hits: 1, misses: 1 }
+        );
+        assert_eq!(
+            verification.by_flight_category[&FlightCategory::Visual],
+            Tally { hits: 2, misses: 0 }
+        );
+
+        let thunderstorm = verification.observations[2];
+        assert_eq!(
+            thunderstorm.time,
+            Utc.with_ymd_and_hms(2021, 8, 26, 0, 53, 0).unwrap()
+        );
+        assert_eq!(
+            thunderstorm.observed_category,
+            Some(FlightCategory::Instrument)
+        );
+        assert_eq!(
+            (
+                thunderstorm.ceiling,
+                thunderstorm.visibility,
+                thunderstorm.wind,
+                thunderstorm.weather
+            ),
+            (Some(false), Some(false), Some(true), Some(false))
+        );
+        assert_eq!(verification.observations[3].wind, Some(false));
+    }
+
+    #[test]
+    fn wind_tolerances() {
+        let wind = |direction: f64, speed: f64, gust: Option<f64>| Wind {
+            direction: Some(uom::si::f64::Angle::new::<degree>(direction)),
+            speed: Some(uom::si::f64::Velocity::new::<knot>(speed)),
+            peak_gust: gust.map(uom::si::f64::Velocity::new::<knot>),
+            variance: None,
+        };
+        assert_eq!(
+            wind_hit(&wind(350., 12., None), &wind(20., 15., None)),
+            Some(true)
+        );
+        assert_eq!(
+            wind_hit(&wind(90., 12., None), &wind(20., 15., None)),
+            Some(false)
+        );
+        // Direction is not verified in light winds
+        assert_eq!(
+            wind_hit(&wind(90., 5., None), &wind(20., 8., None)),
+            Some(true)
+        );
+        assert_eq!(
+            wind_hit(&wind(20., 25., None), &wind(20., 15., None)),
+            Some(false)
+        );
+        assert_eq!(
+            wind_hit(&wind(20., 15., Some(30.)), &wind(20., 15., Some(25.))),
+            Some(true)
+        );
+        assert_eq!(
+            wind_hit(&wind(20., 15., Some(30.)), &wind(20., 15., None)),
+            Some(false)
+        );
+    }
+}
@@ -1,5 +1,5 @@
-use uom::si::f64::{Angle, Length, Pressure, ThermodynamicTemperature, Velocity};
-use chrono::{DateTime, Utc};
+use std::borrow::Cow;
+use uom::si::f64::{Angle, Length, Pressure, ThermodynamicTemperature, Time, Velocity};
 
 macro_rules! enum_with_str_repr {
     (
@@ -11,6 +11,7 @@ macro_rules! enum_with_str_repr {
             )*
     }) => {
         #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         $(#[$enum_attr])*
         pub enum $ident {
             $(
@@ -84,6 +85,7 @@ enum_with_str_repr! {
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ZuluDateTime {
     pub day_of_month: u8,
     pub time: ZuluTime,
@@ -95,16 +97,30 @@ pub struct ZuluDateTime {
 impl ZuluDateTime {
     #[cfg(feature = "chrono_helpers")]
     pub fn as_datetime(&self, year: i32, month: u32) -> chrono::DateTime<chrono_tz::Tz> {
-        self.time.as_datetime(chrono::TimeZone::ymd(
-            &chrono_tz::Greenwich,
-            year,
-            month,
-            self.day_of_month as u32,
-        ))
+        self.time.as_datetime(
+            chrono::NaiveDate::from_ymd_opt(year, month, self.day_of_month as u32).unwrap(),
+        )
+    }
+
+    /// Resolves the month and year from the closest match to `reference`, i.e. the time a report was received or archived
+    ///
+    /// Returns [None] if the timestamp is invalid.
+    #[cfg(feature = "chrono_helpers")]
+    pub fn resolve(
+        &self,
+        reference: chrono::DateTime<chrono::Utc>,
+    ) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::utils::times::resolve_day_hour_minute(
+            self.day_of_month,
+            self.time.hour,
+            self.time.minute,
+            reference,
+        )
     }
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ZuluTime {
     pub hour: u8,
     pub minute: u8,
@@ -112,15 +128,29 @@ pub struct ZuluTime {
 
 impl ZuluTime {
     #[cfg(feature = "chrono_helpers")]
-    pub fn as_datetime(
+    pub fn as_datetime(&self, date: chrono::NaiveDate) -> chrono::DateTime<chrono_tz::Tz> {
+        chrono::TimeZone::from_utc_datetime(
+            &chrono_tz::Greenwich,
+            &(date.and_hms_opt(0, 0, 0).unwrap()
+                + chrono::Duration::hours(self.hour as i64)
+                + chrono::Duration::minutes(self.minute as i64)),
+        )
+    }
+
+    /// Resolves the date from the closest match to `reference`
+    ///
+    /// Returns [None] if the time is invalid.
+    #[cfg(feature = "chrono_helpers")]
+    pub fn resolve(
         &self,
-        date: chrono::Date<chrono_tz::Tz>,
-    ) -> chrono::DateTime<chrono_tz::Tz> {
-        date.and_hms(self.hour as u32, self.minute as u32, 0)
+        reference: chrono::DateTime<chrono::Utc>,
+    ) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::utils::times::resolve_hour_minute(self.hour, self.minute, reference)
     }
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ZuluTimeRange {
     pub begin: ZuluTime,
     pub end: ZuluTime,
@@ -130,15 +160,29 @@ impl ZuluTimeRange {
     #[cfg(feature = "chrono_helpers")]
     pub fn as_start_and_duration(
         &self,
-        date: chrono::Date<chrono_tz::Tz>,
+        date: chrono::NaiveDate,
     ) -> (chrono::DateTime<chrono_tz::Tz>, chrono::Duration) {
         let begin = self.begin.as_datetime(date);
         let end = self.end.as_datetime(date);
         (begin, (end - begin))
     }
+
+    /// Resolves the beginning from the closest match to `reference`; the end is the first matching time after it
+    ///
+    /// Returns [None] if either time is invalid.
+    #[cfg(feature = "chrono_helpers")]
+    pub fn resolve(
+        &self,
+        reference: chrono::DateTime<chrono::Utc>,
+    ) -> Option<std::ops::Range<chrono::DateTime<chrono::Utc>>> {
+        let begin = self.begin.resolve(reference)?;
+        let end = crate::utils::times::next_hour_minute(self.end.hour, self.end.minute, begin)?;
+        Some(begin..end)
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Wind {
     /// A lack of direction indicates variable
     pub direction: Option<Angle>,
@@ -164,14 +208,27 @@ impl Wind {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RunwayVisibility<'input> {
-    pub designator: &'input str,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub designator: Cow<'input, str>,
     pub visibility: VisibilityType,
     pub trend: Option<VisibilityTrend>,
 }
 
+impl<'input> RunwayVisibility<'input> {
+    pub fn into_owned(self) -> RunwayVisibility<'static> {
+        RunwayVisibility {
+            designator: Cow::Owned(self.designator.into_owned()),
+            visibility: self.visibility,
+            trend: self.trend,
+        }
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VisibilityType {
     Varying {
         lower: RawVisibility,
@@ -181,6 +238,7 @@ pub enum VisibilityType {
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RawVisibility {
     /// If present, visibility is out of the observable range
     pub out_of_range: Option<OutOfRange>,
@@ -202,19 +260,130 @@ enum_with_str_repr! {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Debug)]
+/// Runway state group, i.e. `R24/290155`, `88290155` or `R/SNOCLO`
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RunwayReport<'input> {
-    pub designator: &'input str,
+    /// `88` applies to all runways and `99` repeats the previous report because no new information is available
+    ///
+    /// Empty for [RunwayReportInfo::ClosedBySnow], which applies to the whole aerodrome.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub designator: Cow<'input, str>,
     pub report_info: RunwayReportInfo,
 }
 
+impl<'input> RunwayReport<'input> {
+    pub fn into_owned(self) -> RunwayReport<'static> {
+        RunwayReport {
+            designator: Cow::Owned(self.designator.into_owned()),
+            report_info: self.report_info,
+        }
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RunwayReportInfo {
     /// Runway has been cleared of any deposits
-    Cleared { friction: Option<f64> },
+    Cleared { friction: Option<RunwayFriction> },
+    /// Deposit, extent of contamination, depth of deposit and friction, where `/` means not reported
+    Contaminated {
+        deposit: Option<RunwayDeposit>,
+        extent: Option<RunwayContaminationExtent>,
+        depth: Option<DepositDepth>,
+        friction: Option<RunwayFriction>,
+    },
+    /// `SNOCLO`: the aerodrome is closed due to snow
+    ClosedBySnow,
+}
+
+enum_with_str_repr! {
+    RunwayDeposit {
+        ClearAndDry => "0",
+        Damp => "1",
+        /// Wet or water patches
+        Wet => "2",
+        /// Rime or frost, normally less than 1mm deep
+        RimeOrFrost => "3",
+        DrySnow => "4",
+        WetSnow => "5",
+        Slush => "6",
+        Ice => "7",
+        /// Compacted or rolled snow
+        CompactedSnow => "8",
+        /// Frozen ruts or ridges
+        FrozenRuts => "9",
+    }
+}
+
+enum_with_str_repr! {
+    /// Proportion of the runway that is covered
+    RunwayContaminationExtent {
+        TenPercentOrLess => "1",
+        ElevenToTwentyFivePercent => "2",
+        TwentySixToFiftyPercent => "5",
+        FiftyOneToOneHundredPercent => "9",
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DepositDepth {
+    /// Depths of 10cm and more are reported in 5cm steps.
+    /// `00` is reported as below 1mm and `98` as above 40cm.
+    Depth {
+        depth: Length,
+        out_of_range: Option<OutOfRange>,
+    },
+    /// `99`: runway not operational due to deposits or clearance
+    NotOperational,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RunwayFriction {
+    /// Measured friction coefficient, between 0 and 0.9
+    Coefficient(f64),
+    /// Estimated braking action
+    BrakingAction(BrakingAction),
+    /// `99`: unreliable or unmeasurable
+    Unreliable,
+}
+
+enum_with_str_repr! {
+    BrakingAction {
+        Poor => "91",
+        MediumToPoor => "92",
+        Medium => "93",
+        MediumToGood => "94",
+        Good => "95",
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WindShear<'input> {
+    /// `WS R27` or `WS RWY09L`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    Runway(Cow<'input, str>),
+    /// `WS ALL RWY`
+    AllRunways,
+    /// `WS020/27045KT`: wind shear between the surface and the height, with the wind at that height
+    LowLevel { height: Length, wind: Wind },
+}
+
+impl<'input> WindShear<'input> {
+    pub fn into_owned(self) -> WindShear<'static> {
+        match self {
+            Self::Runway(designator) => WindShear::Runway(Cow::Owned(designator.into_owned())),
+            Self::AllRunways => WindShear::AllRunways,
+            Self::LowLevel { height, wind } => WindShear::LowLevel { height, wind },
+        }
+    }
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Weather {
     pub intensity: Intensity,
     /// If true, the described phenomenon was not observed above the station, but within 8KM of it
@@ -246,6 +415,7 @@ enum_with_str_repr! {
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Condition {
     /// There can be multiple forms of precipitation observed
     Precipitation(Vec<Precipitation>),
@@ -291,6 +461,7 @@ enum_with_str_repr! {
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CloudCover {
     pub coverage: CloudCoverage,
     /// The absence of a base indicates it is below station level or an inability of an automated system to make an assessment
@@ -300,6 +471,7 @@ pub struct CloudCover {
 
 /// If negative, these are rounded up to the more positive whole degree
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Temperatures {
     pub air: ThermodynamicTemperature,
     /// Some stations don't report this, hence it is marked as optional
@@ -307,6 +479,7 @@ pub struct Temperatures {
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AccumulatedRainfall {
     /// In the 10 minutes prior to the report time
     pub recent: Length,
@@ -331,6 +504,7 @@ enum_with_str_repr! {
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Color {
     /// `BLACK` in a METAR indicates the airfield is closed
     pub is_black: bool,
@@ -339,6 +513,7 @@ pub struct Color {
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Visibility {
     pub prevailing: Option<RawVisibility>,
     /// Typically reported when visibility in a particular direction differs significantly from prevailing visibility
@@ -349,6 +524,7 @@ pub struct Visibility {
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DirectionalVisibility {
     pub direction: CompassDirection,
     pub distance: RawVisibility,
@@ -369,6 +545,7 @@ enum_with_str_repr! {
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WaterConditions {
     /// Water temperature at the surface
     pub temperature: Option<ThermodynamicTemperature>,
@@ -393,6 +570,7 @@ enum_with_str_repr! {
 }
 
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Trend {
     /// No significant change in weather expected for the next 2 hours
     NoSignificantChange,
@@ -403,6 +581,7 @@ pub enum Trend {
 }
 
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TrendReport {
     pub time: Option<TrendTime>,
     pub wind: Option<Wind>,
@@ -413,6 +592,7 @@ pub struct TrendReport {
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TrendTime {
     pub time_type: TrendTimeType,
     pub time: ZuluTime,
@@ -426,10 +606,157 @@ enum_with_str_repr! {
     }
 }
 
+enum_with_str_repr! {
+    /// Type of automated station, reported as `AO1` or `AO2` in US remarks
+    AutomatedStationType {
+        WithoutPrecipitationDiscriminator => "AO1" | "A01",
+        WithPrecipitationDiscriminator => "AO2" | "A02",
+    }
+}
+
+enum_with_str_repr! {
+    /// Sensors reported as unavailable in US remarks
+    SensorStatus {
+        PresentWeatherIdentifierNotAvailable => "PWINO",
+        PrecipitationAmountNotAvailable => "PNO",
+        FreezingRainNotAvailable => "FZRANO",
+        LightningNotAvailable => "TSNO",
+        RunwayVisualRangeNotAvailable => "RVRNO",
+        VisibilityNotAvailable => "VISNO",
+        CeilingNotAvailable => "CHINO",
+        SeaLevelPressureNotAvailable => "SLPNO",
+    }
+}
+
+enum_with_str_repr! {
+    /// `PRESRR` or `PRESFR`, indicating pressure is rising or falling rapidly
+    PressureChange {
+        RisingRapidly => "PRESRR",
+        FallingRapidly => "PRESFR",
+    }
+}
+
+enum_with_str_repr! {
+    /// See Table 0200 on page A-255 in the [WMO Manual on Codes](https://library.wmo.int/doc_num.php?explnum_id=10235)
+    PressureTendencyCharacteristic {
+        IncreasingThenDecreasing => "0",
+        IncreasingThenSteady => "1",
+        Increasing => "2",
+        DecreasingOrSteadyThenIncreasing => "3",
+        Steady => "4",
+        DecreasingThenIncreasing => "5",
+        DecreasingThenSteady => "6",
+        Decreasing => "7",
+        SteadyOrIncreasingThenDecreasing => "8",
+    }
+}
+
+/// `5appp`: pressure tendency over the past 3 hours
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PressureTendency {
+    pub characteristic: PressureTendencyCharacteristic,
+    /// Negative if the pressure is lower than 3 hours ago
+    pub change: Pressure,
+}
+
+/// `PK WND dddff/hhmm`: highest instantaneous wind speed since the last report
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PeakWind {
+    pub direction: Angle,
+    pub speed: Velocity,
+    /// Omitted if it is the same hour as the report
+    pub hour: Option<u8>,
+    pub minute: u8,
+}
+
+/// `WSHFT hhmm`: time at which a wind shift began
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindShift {
+    /// Omitted if it is the same hour as the report
+    pub hour: Option<u8>,
+    pub minute: u8,
+    /// `FROPA` indicates the shift was caused by a frontal passage
+    pub frontal_passage: bool,
+}
+
+/// `4snnnsnnn`: 24-hour maximum and minimum temperatures
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TemperatureExtremes {
+    pub maximum: ThermodynamicTemperature,
+    pub minimum: ThermodynamicTemperature,
+}
+
+/// Decoded groups of a remark section, as reported by stations in the United States
+///
+/// See chapter 12 of the [Federal Meteorological Handbook No. 1](https://www.icams-portal.gov/resources/ofcm/fmh/FMH1/fmh1_2019.pdf).
+/// Precipitation groups reported as indeterminate (i.e. `6////`) are treated as absent.
+#[derive(Clone, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemarkSection<'input> {
+    pub station_type: Option<AutomatedStationType>,
+    pub peak_wind: Option<PeakWind>,
+    pub wind_shift: Option<WindShift>,
+    pub pressure_change: Option<PressureChange>,
+    /// `SLPppp`
+    pub sea_level_pressure: Option<Pressure>,
+    /// `Prrrr`: precipitation in the past hour
+    pub hourly_precipitation: Option<Length>,
+    /// `6RRRR`: precipitation in the past 3 or 6 hours, depending on the report time
+    pub precipitation_3_or_6_hour: Option<Length>,
+    /// `7RRRR`: precipitation in the past 24 hours
+    pub precipitation_24_hour: Option<Length>,
+    /// `Tsnnnsnnn`: air temperature and dewpoint to the tenth of a degree
+    pub precise_temperatures: Option<Temperatures>,
+    /// `1snnn`
+    pub maximum_temperature_6_hour: Option<ThermodynamicTemperature>,
+    /// `2snnn`
+    pub minimum_temperature_6_hour: Option<ThermodynamicTemperature>,
+    /// `4snnnsnnn`
+    pub temperature_extremes_24_hour: Option<TemperatureExtremes>,
+    /// `5appp`
+    pub pressure_tendency: Option<PressureTendency>,
+    pub sensor_status: Vec<SensorStatus>,
+    /// Groups that could not be decoded, in the order they were reported
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub unparsed: Vec<Cow<'input, str>>,
+}
+
+impl<'input> RemarkSection<'input> {
+    pub fn into_owned(self) -> RemarkSection<'static> {
+        RemarkSection {
+            station_type: self.station_type,
+            peak_wind: self.peak_wind,
+            wind_shift: self.wind_shift,
+            pressure_change: self.pressure_change,
+            sea_level_pressure: self.sea_level_pressure,
+            hourly_precipitation: self.hourly_precipitation,
+            precipitation_3_or_6_hour: self.precipitation_3_or_6_hour,
+            precipitation_24_hour: self.precipitation_24_hour,
+            precise_temperatures: self.precise_temperatures,
+            maximum_temperature_6_hour: self.maximum_temperature_6_hour,
+            minimum_temperature_6_hour: self.minimum_temperature_6_hour,
+            temperature_extremes_24_hour: self.temperature_extremes_24_hour,
+            pressure_tendency: self.pressure_tendency,
+            sensor_status: self.sensor_status,
+            unparsed: self
+                .unparsed
+                .into_iter()
+                .map(|group| Cow::Owned(group.into_owned()))
+                .collect(),
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MetarReport<'input> {
     /// Station [ICAO identifier](https://en.wikipedia.org/wiki/ICAO_airport_code)
-    pub identifier: &'input str,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub identifier: Cow<'input, str>,
     pub observation_time: Option<ZuluDateTime>,
     /// Usually used by TAFs, but some stations include this
     pub observation_validity_range: Option<ZuluTimeRange>,
@@ -437,8 +764,10 @@ pub struct MetarReport<'input> {
     pub wind: Option<Wind>,
     pub visibility: Option<Visibility>,
     /// Included by some airport stations
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub runway_visibilities: Vec<RunwayVisibility<'input>>,
     /// Included by some airport stations
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub runway_reports: Vec<RunwayReport<'input>>,
     /// Series of active weather conditions
     pub weather: Vec<Weather>,
@@ -457,31 +786,973 @@ pub struct MetarReport<'input> {
     /// Often reported by military stations
     pub color: Option<Color>,
     pub recent_weather: Vec<Weather>,
+    /// Wind shear along take-off or approach paths
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub wind_shear: Vec<WindShear<'input>>,
     /// Often reported by stations at sea
     ///
     /// i.e. [ENQA](https://en.wikipedia.org/wiki/Troll_A_platform), an offshore natural gas platform.
     pub water_conditions: Option<WaterConditions>,
     pub trends: Vec<Trend>,
     /// Additional information outside of the METAR specification
-    pub remark: Option<&'input str>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub remark: Option<Cow<'input, str>>,
+    /// Groups decoded from [MetarReport::remark]
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub remark_section: Option<RemarkSection<'input>>,
     /// Some automated METARs indicate if the system needs maintenance
     ///
     /// This may indicate that measurements are unreliable
     pub maintenance_needed: bool,
 }
 
+impl<'input> MetarReport<'input> {
+    /// Detaches the report from the input it was parsed from, i.e. for caching or sending across threads
+    pub fn into_owned(self) -> MetarReport<'static> {
+        MetarReport {
+            identifier: Cow::Owned(self.identifier.into_owned()),
+            observation_time: self.observation_time,
+            observation_validity_range: self.observation_validity_range,
+            observation_flags: self.observation_flags,
+            wind: self.wind,
+            visibility: self.visibility,
+            runway_visibilities: self
+                .runway_visibilities
+                .into_iter()
+                .map(RunwayVisibility::into_owned)
+                .collect(),
+            runway_reports: self
+                .runway_reports
+                .into_iter()
+                .map(RunwayReport::into_owned)
+                .collect(),
+            weather: self.weather,
+            cloud_cover: self.cloud_cover,
+            cavok: self.cavok,
+            temperatures: self.temperatures,
+            pressure: self.pressure,
+            accumulated_rainfall: self.accumulated_rainfall,
+            color: self.color,
+            recent_weather: self.recent_weather,
+            wind_shear: self
+                .wind_shear
+                .into_iter()
+                .map(WindShear::into_owned)
+                .collect(),
+            water_conditions: self.water_conditions,
+            trends: self.trends,
+            remark: self.remark.map(|remark| Cow::Owned(remark.into_owned())),
+            remark_section: self.remark_section.map(RemarkSection::into_owned),
+            maintenance_needed: self.maintenance_needed,
+        }
+    }
+}
+
+enum_with_str_repr! {
+    TafFlag {
+        Amended => "AMD",
+        Correction => "COR",
+        Cancelled => "CNL",
+        Nil => "NIL",
+    }
+}
+
+/// Day of month and hour, as used by TAF validity periods (i.e. `2518`)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZuluDayHour {
+    pub day_of_month: u8,
+    /// Can be 24 to indicate the end of the day
+    pub hour: u8,
+}
+
+impl ZuluDayHour {
+    /// Resolves the month and year from the closest match to `reference`
+    ///
+    /// Returns [None] if the day or hour is invalid.
+    #[cfg(feature = "chrono_helpers")]
+    pub fn resolve(
+        &self,
+        reference: chrono::DateTime<chrono::Utc>,
+    ) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::utils::times::resolve_day_hour_minute(self.day_of_month, self.hour, 0, reference)
+    }
+}
+
+/// A TAF validity period (i.e. `2518/2624`)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZuluDayHourRange {
+    pub begin: ZuluDayHour,
+    pub end: ZuluDayHour,
+}
+
+impl ZuluDayHourRange {
+    /// Resolves the beginning from the closest match to `reference`, and the end relative to the beginning
+    ///
+    /// Use the issue time of the TAF as the reference where possible.
+    /// Returns [None] if either bound is invalid or the end precedes the beginning.
+    #[cfg(feature = "chrono_helpers")]
+    pub fn resolve(
+        &self,
+        reference: chrono::DateTime<chrono::Utc>,
+    ) -> Option<std::ops::Range<chrono::DateTime<chrono::Utc>>> {
+        let begin = self.begin.resolve(reference)?;
+        let end = self.end.resolve(begin)?;
+        if end < begin {
+            None
+        } else {
+            Some(begin..end)
+        }
+    }
+}
+
+/// Forecasted conditions, either for the base of a TAF or for one of its change groups
+#[derive(Clone, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TafForecast<'input> {
+    pub wind: Option<Wind>,
+    pub visibility: Option<Visibility>,
+    pub weather: Vec<Weather>,
+    /// `NSW` indicates the end of previously forecasted significant weather
+    pub no_significant_weather: bool,
+    pub cloud_cover: Vec<CloudCover>,
+    /// Indicative of OK ceiling and visibility
+    pub cavok: bool,
+    /// Low level wind shear, i.e. `WS020/27045KT`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub wind_shear: Option<WindShear<'input>>,
+}
+
+impl<'input> TafForecast<'input> {
+    pub fn into_owned(self) -> TafForecast<'static> {
+        TafForecast {
+            wind: self.wind,
+            visibility: self.visibility,
+            weather: self.weather,
+            no_significant_weather: self.no_significant_weather,
+            cloud_cover: self.cloud_cover,
+            cavok: self.cavok,
+            wind_shear: self.wind_shear.map(WindShear::into_owned),
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Debug)]
-pub struct ValidDateTimes {
-    pub start: DateTime<Utc>,
-    pub end: DateTime<Utc>,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TafChangeGroup<'input> {
+    pub indicator: TafChangeIndicator,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub forecast: TafForecast<'input>,
+}
+
+impl<'input> TafChangeGroup<'input> {
+    pub fn into_owned(self) -> TafChangeGroup<'static> {
+        TafChangeGroup {
+            indicator: self.indicator,
+            forecast: self.forecast.into_owned(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TafChangeIndicator {
+    /// `FMddhhmm`: conditions from this time onward replace the previous forecast entirely
+    From(ZuluDateTime),
+    /// `BECMG`: conditions change gradually over the period
+    Becoming(ZuluDayHourRange),
+    /// `TEMPO`: temporary fluctuations during the period
+    Temporarily(ZuluDayHourRange),
+    /// `PROB30`/`PROB40`: chance of the conditions occurring during the period
+    Probability {
+        /// In percent
+        probability: u8,
+        period: ZuluDayHourRange,
+    },
+    /// `PROB30 TEMPO`/`PROB40 TEMPO`: chance of temporary fluctuations during the period
+    ProbabilityTemporarily {
+        /// In percent
+        probability: u8,
+        period: ZuluDayHourRange,
+    },
+}
+
+enum_with_str_repr! {
+    TemperatureForecastType {
+        Maximum => "TX",
+        Minimum => "TN",
+    }
+}
+
+/// Forecasted maximum or minimum temperature (i.e. `TX25/2518Z`)
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TemperatureForecast {
+    pub forecast_type: TemperatureForecastType,
+    pub temperature: ThermodynamicTemperature,
+    pub time: ZuluDayHour,
 }
 
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TafReport<'input> {
     /// Station [ICAO identifier](https://en.wikipedia.org/wiki/ICAO_airport_code)
-    pub station: &'input str,
-    pub issue_time: DateTime<Utc>,
-    pub valid_times: ValidDateTimes,
-    pub conditions: &'input str,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub identifier: Cow<'input, str>,
+    pub flags: Vec<TafFlag>,
+    pub issue_time: Option<ZuluDateTime>,
+    /// Absent for a `NIL` TAF
+    pub validity: Option<ZuluDayHourRange>,
+    /// Prevailing conditions at the start of the validity period
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub forecast: TafForecast<'input>,
+    pub temperature_forecasts: Vec<TemperatureForecast>,
+    /// Changes to the base forecast, in the order they were reported
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub changes: Vec<TafChangeGroup<'input>>,
+    /// Additional information outside of the TAF specification
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub remark: Option<Cow<'input, str>>,
+}
+
+impl<'input> TafReport<'input> {
+    /// Detaches the report from the input it was parsed from, i.e. for caching or sending across threads
+    pub fn into_owned(self) -> TafReport<'static> {
+        TafReport {
+            identifier: Cow::Owned(self.identifier.into_owned()),
+            flags: self.flags,
+            issue_time: self.issue_time,
+            validity: self.validity,
+            forecast: self.forecast.into_owned(),
+            temperature_forecasts: self.temperature_forecasts,
+            changes: self
+                .changes
+                .into_iter()
+                .map(TafChangeGroup::into_owned)
+                .collect(),
+            remark: self.remark.map(|remark| Cow::Owned(remark.into_owned())),
+        }
+    }
+
+    /// Resolves the validity period against the issue time, itself resolved against `reference`
+    ///
+    /// Falls back to `reference` if the issue time is missing or invalid.
+    #[cfg(feature = "chrono_helpers")]
+    pub fn resolve_validity(
+        &self,
+        reference: chrono::DateTime<chrono::Utc>,
+    ) -> Option<std::ops::Range<chrono::DateTime<chrono::Utc>>> {
+        let issued = self
+            .issue_time
+            .and_then(|issue_time| issue_time.resolve(reference))
+            .unwrap_or(reference);
+        self.validity?.resolve(issued)
+    }
+}
+
+enum_with_str_repr! {
+    /// `iw`: unit of the wind speed and whether it was measured, see Table 1855 in the [WMO Manual on Codes](https://library.wmo.int/doc_num.php?explnum_id=10235)
+    WindSpeedSource {
+        EstimatedMetersPerSecond => "0",
+        MeasuredMetersPerSecond => "1",
+        EstimatedKnots => "3",
+        MeasuredKnots => "4",
+    }
+}
+
+enum_with_str_repr! {
+    /// `iR`: which sections include a precipitation group, see Table 1819
+    PrecipitationIndicator {
+        Sections1And3 => "0",
+        Section1 => "1",
+        Section3 => "2",
+        /// Omitted because there was no precipitation
+        NoPrecipitation => "3",
+        /// Omitted because precipitation was not measured
+        NotAvailable => "4",
+    }
+}
+
+enum_with_str_repr! {
+    /// `ix`: station type and whether the weather group is included, see Table 1860
+    StationOperation {
+        MannedWithWeather => "1",
+        MannedNoSignificantWeather => "2",
+        MannedWeatherNotObserved => "3",
+        AutomaticWithWeather => "4",
+        AutomaticNoSignificantWeather => "5",
+        AutomaticWeatherNotObserved => "6",
+        /// Present and past weather are reported with the automatic station tables (4680 and 4531)
+        AutomaticWithAutomaticWeather => "7",
+    }
 }
 
+enum_with_str_repr! {
+    /// The ten [cloud genera](https://cloudatlas.wmo.int/en/cloud-classification-summary.html)
+    CloudGenus {
+        Cirrus => "CI",
+        Cirrocumulus => "CC",
+        Cirrostratus => "CS",
+        Altocumulus => "AC",
+        Altostratus => "AS",
+        Nimbostratus => "NS",
+        Stratocumulus => "SC",
+        Stratus => "ST",
+        Cumulus => "CU",
+        Cumulonimbus => "CB",
+    }
+}
+
+impl CloudGenus {
+    /// The equivalent METAR cloud type, if there is one
+    pub fn cloud_type(&self) -> Option<CloudType> {
+        match self {
+            Self::Cirrus => Some(CloudType::Cirrus),
+            Self::Altocumulus => Some(CloudType::Altocumulus),
+            Self::Stratus => Some(CloudType::Stratus),
+            Self::Cumulus => Some(CloudType::Cumulus),
+            Self::Cumulonimbus => Some(CloudType::Cumulonimbus),
+            _ => None,
+        }
+    }
+}
+
+/// `h`: height of the base of the lowest cloud, reported as a range
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CloudBaseRange {
+    pub lower: Length,
+    /// Absent when the base is above the highest range or there are no clouds
+    pub upper: Option<Length>,
+}
+
+/// `4a3hhh`: reported instead of sea level pressure by high altitude stations
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GeopotentialHeight {
+    pub isobaric_surface: Pressure,
+    pub height: Length,
+}
+
+/// `6RRRtR`: precipitation amount over a period
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrecipitationAmount {
+    pub amount: Length,
+    /// Too little precipitation to measure, the amount is zero
+    pub trace: bool,
+    /// Length of the period ending at the time of observation
+    pub period_hours: Option<u8>,
+}
+
+/// `8NhCLCMCH`: amount of low clouds and the predominant genus at each level
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CloudGenera {
+    /// Oktas of the low clouds, or the middle clouds if there are none; 9 indicates the sky is obscured
+    pub amount: Option<u8>,
+    pub low: Option<CloudGenus>,
+    pub middle: Option<CloudGenus>,
+    pub high: Option<CloudGenus>,
+}
+
+/// `8NsChshs`: an individual cloud layer from section 3
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SynopCloudLayer {
+    pub genus: Option<CloudGenus>,
+    /// The cloud type is set when the genus has a METAR equivalent
+    pub cover: CloudCover,
+}
+
+/// Section 3 (`333`): climatological data for regional exchange
+#[derive(Clone, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SynopClimatologicalSection<'input> {
+    pub maximum_temperature: Option<ThermodynamicTemperature>,
+    pub minimum_temperature: Option<ThermodynamicTemperature>,
+    /// Less than half a centimeter is reported as zero
+    pub snow_depth: Option<Length>,
+    /// Total sunshine of the previous day
+    pub sunshine_duration: Option<Time>,
+    pub hourly_sunshine_duration: Option<Time>,
+    pub precipitation: Option<PrecipitationAmount>,
+    pub precipitation_24_hour: Option<PrecipitationAmount>,
+    pub cloud_layers: Vec<SynopCloudLayer>,
+    /// Groups that are not decoded, i.e. state of the ground or special phenomena
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub unparsed: Vec<Cow<'input, str>>,
+}
+
+impl<'input> SynopClimatologicalSection<'input> {
+    pub fn into_owned(self) -> SynopClimatologicalSection<'static> {
+        SynopClimatologicalSection {
+            maximum_temperature: self.maximum_temperature,
+            minimum_temperature: self.minimum_temperature,
+            snow_depth: self.snow_depth,
+            sunshine_duration: self.sunshine_duration,
+            hourly_sunshine_duration: self.hourly_sunshine_duration,
+            precipitation: self.precipitation,
+            precipitation_24_hour: self.precipitation_24_hour,
+            cloud_layers: self.cloud_layers,
+            unparsed: self
+                .unparsed
+                .into_iter()
+                .map(|group| Cow::Owned(group.into_owned()))
+                .collect(),
+        }
+    }
+}
+
+/// [SYNOP](https://en.wikipedia.org/wiki/SYNOP) report from a land station (FM-12)
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SynopReport<'input> {
+    /// `IIiii`: WMO block and station number
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub station: Cow<'input, str>,
+    /// `YYGG`: nominal time of observation, on the hour
+    pub observation_time: ZuluDateTime,
+    pub wind_speed_source: Option<WindSpeedSource>,
+    pub nil: bool,
+    pub precipitation_indicator: Option<PrecipitationIndicator>,
+    pub station_operation: Option<StationOperation>,
+    pub lowest_cloud_base: Option<CloudBaseRange>,
+    pub visibility: Option<Visibility>,
+    /// `N`: oktas of the sky covered by clouds; 9 indicates the sky is obscured
+    pub total_cloud_cover: Option<u8>,
+    pub wind: Option<Wind>,
+    pub temperatures: Option<Temperatures>,
+    /// Percent, reported by some stations instead of the dewpoint
+    pub relative_humidity: Option<u8>,
+    pub station_pressure: Option<Pressure>,
+    pub sea_level_pressure: Option<Pressure>,
+    pub geopotential_height: Option<GeopotentialHeight>,
+    pub pressure_tendency: Option<PressureTendency>,
+    pub precipitation: Option<PrecipitationAmount>,
+    /// `ww`: see Table 4677, or Table 4680 for [StationOperation::AutomaticWithAutomaticWeather]
+    pub present_weather_code: Option<u8>,
+    /// `W1W2`: see Table 4561, or Table 4531 for [StationOperation::AutomaticWithAutomaticWeather]
+    pub past_weather_codes: [Option<u8>; 2],
+    /// Present weather decoded from Table 4677, where it has a METAR equivalent
+    pub weather: Vec<Weather>,
+    pub cloud_genera: Option<CloudGenera>,
+    /// `9GGgg`: actual time of observation, if it differs from the nominal time
+    pub exact_observation_time: Option<ZuluTime>,
+    /// Section 2 (`222`) as reported by coastal stations; other sea groups are not decoded
+    pub water_conditions: Option<WaterConditions>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub climatological_section: Option<SynopClimatologicalSection<'input>>,
+    /// Sections 4 and 5 (`444` and `555`), which are left undecoded
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub regional_sections: Option<Cow<'input, str>>,
+}
+
+impl<'input> SynopReport<'input> {
+    pub fn into_owned(self) -> SynopReport<'static> {
+        SynopReport {
+            station: Cow::Owned(self.station.into_owned()),
+            observation_time: self.observation_time,
+            wind_speed_source: self.wind_speed_source,
+            nil: self.nil,
+            precipitation_indicator: self.precipitation_indicator,
+            station_operation: self.station_operation,
+            lowest_cloud_base: self.lowest_cloud_base,
+            visibility: self.visibility,
+            total_cloud_cover: self.total_cloud_cover,
+            wind: self.wind,
+            temperatures: self.temperatures,
+            relative_humidity: self.relative_humidity,
+            station_pressure: self.station_pressure,
+            sea_level_pressure: self.sea_level_pressure,
+            geopotential_height: self.geopotential_height,
+            pressure_tendency: self.pressure_tendency,
+            precipitation: self.precipitation,
+            present_weather_code: self.present_weather_code,
+            past_weather_codes: self.past_weather_codes,
+            weather: self.weather,
+            cloud_genera: self.cloud_genera,
+            exact_observation_time: self.exact_observation_time,
+            water_conditions: self.water_conditions,
+            climatological_section: self
+                .climatological_section
+                .map(SynopClimatologicalSection::into_owned),
+            regional_sections: self
+                .regional_sections
+                .map(|sections| Cow::Owned(sections.into_owned())),
+        }
+    }
+}
+
+/// A point given by latitude and longitude, i.e. `5030N02000W`
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Coordinates {
+    /// Positive to the north
+    pub latitude: Angle,
+    /// Positive to the east
+    pub longitude: Angle,
+}
+
+enum_with_str_repr! {
+    /// Reported turbulence, ordered from least to most intense
+    #[derive(PartialOrd, Ord, Hash)]
+    TurbulenceIntensity {
+        Negative => "NEG" | "SMTH" | "NIL",
+        SmoothLight => "SMTH-LGT",
+        Light => "LGT",
+        LightModerate => "LGT-MOD",
+        Moderate => "MOD",
+        ModerateSevere => "MOD-SEV",
+        Severe => "SEV",
+        SevereExtreme => "SEV-EXTRM",
+        Extreme => "EXTRM" | "EXTM",
+    }
+}
+
+enum_with_str_repr! {
+    TurbulenceType {
+        ClearAir => "CAT",
+        Chop => "CHOP",
+        LowLevelWindShear => "LLWS",
+        MountainWave => "MWAVE",
+    }
+}
+
+enum_with_str_repr! {
+    TurbulenceFrequency {
+        Occasional => "OCNL",
+        Intermittent => "INTMT",
+        Continuous => "CONS",
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Turbulence {
+    pub intensity: TurbulenceIntensity,
+    pub frequency: Option<TurbulenceFrequency>,
+    pub turbulence_type: Option<TurbulenceType>,
+    /// Lowest altitude the turbulence was encountered at, if reported
+    pub base: Option<Length>,
+    pub top: Option<Length>,
+}
+
+enum_with_str_repr! {
+    /// Reported airframe icing, ordered from least to most intense
+    #[derive(PartialOrd, Ord, Hash)]
+    IcingIntensity {
+        Negative => "NEG" | "NEGCLR" | "NIL",
+        Trace => "TRACE" | "TRC",
+        TraceLight => "TRACE-LGT" | "TRC-LGT",
+        Light => "LGT",
+        LightModerate => "LGT-MOD",
+        Moderate => "MOD",
+        ModerateSevere => "MOD-SEV",
+        /// Formerly reported as heavy
+        Severe => "SEV" | "HVY",
+    }
+}
+
+enum_with_str_repr! {
+    IcingType {
+        Rime => "RIME",
+        Clear => "CLR",
+        Mixed => "MX" | "MXD" | "MIXED",
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Icing {
+    pub intensity: IcingIntensity,
+    pub icing_type: Option<IcingType>,
+    /// Lowest altitude the icing was encountered at, if reported
+    pub base: Option<Length>,
+    pub top: Option<Length>,
+}
+
+enum_with_str_repr! {
+    FlightPhase {
+        Climb => "DURC",
+        Descent => "DURD",
+    }
+}
+
+/// `/OV`: a fix, optionally followed by a radial and distance from it, i.e. `OKC063015`
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PirepLocation<'input> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub fix: Cow<'input, str>,
+    pub radial: Option<Angle>,
+    pub distance: Option<Length>,
+}
+
+impl<'input> PirepLocation<'input> {
+    pub fn into_owned(self) -> PirepLocation<'static> {
+        PirepLocation {
+            fix: Cow::Owned(self.fix.into_owned()),
+            radial: self.radial,
+            distance: self.distance,
+        }
+    }
+}
+
+/// `/SK`: a cloud layer, which pilots may also report the top of
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PirepCloudLayer {
+    pub cover: CloudCover,
+    pub top: Option<Length>,
+}
+
+/// [Pilot report](https://en.wikipedia.org/wiki/Pilot_report) in the US format
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PirepReport<'input> {
+    /// Station that disseminated the report
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub station: Option<Cow<'input, str>>,
+    /// `UUA` rather than `UA`
+    pub urgent: bool,
+    /// A single location, or the route flown when there are several
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub location: Vec<PirepLocation<'input>>,
+    pub time: Option<ZuluTime>,
+    pub altitude: Option<Length>,
+    pub flight_phase: Option<FlightPhase>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub aircraft_type: Option<Cow<'input, str>>,
+    pub sky_condition: Vec<PirepCloudLayer>,
+    pub flight_visibility: Option<Length>,
+    pub weather: Vec<Weather>,
+    pub temperature: Option<ThermodynamicTemperature>,
+    pub wind: Option<Wind>,
+    pub turbulence: Vec<Turbulence>,
+    pub icing: Vec<Icing>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub remark: Option<Cow<'input, str>>,
+}
+
+impl<'input> PirepReport<'input> {
+    pub fn into_owned(self) -> PirepReport<'static> {
+        PirepReport {
+            station: self.station.map(|station| Cow::Owned(station.into_owned())),
+            urgent: self.urgent,
+            location: self
+                .location
+                .into_iter()
+                .map(PirepLocation::into_owned)
+                .collect(),
+            time: self.time,
+            altitude: self.altitude,
+            flight_phase: self.flight_phase,
+            aircraft_type: self
+                .aircraft_type
+                .map(|aircraft_type| Cow::Owned(aircraft_type.into_owned())),
+            sky_condition: self.sky_condition,
+            flight_visibility: self.flight_visibility,
+            weather: self.weather,
+            temperature: self.temperature,
+            wind: self.wind,
+            turbulence: self.turbulence,
+            icing: self.icing,
+            remark: self.remark.map(|remark| Cow::Owned(remark.into_owned())),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AirepPosition<'input> {
+    Coordinates(Coordinates),
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    Fix(Cow<'input, str>),
+}
+
+impl<'input> AirepPosition<'input> {
+    pub fn into_owned(self) -> AirepPosition<'static> {
+        match self {
+            Self::Coordinates(coordinates) => AirepPosition::Coordinates(coordinates),
+            Self::Fix(fix) => AirepPosition::Fix(Cow::Owned(fix.into_owned())),
+        }
+    }
+}
+
+/// ICAO air report (`ARP`, or `ARS` for special air reports)
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AirepReport<'input> {
+    pub special: bool,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub aircraft_identification: Cow<'input, str>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub position: AirepPosition<'input>,
+    pub time: ZuluTime,
+    /// Flight level or altitude
+    pub level: Length,
+    pub temperature: Option<ThermodynamicTemperature>,
+    pub wind: Option<Wind>,
+    pub turbulence: Option<TurbulenceIntensity>,
+    pub icing: Option<IcingIntensity>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub remark: Option<Cow<'input, str>>,
+}
+
+impl<'input> AirepReport<'input> {
+    pub fn into_owned(self) -> AirepReport<'static> {
+        AirepReport {
+            special: self.special,
+            aircraft_identification: Cow::Owned(self.aircraft_identification.into_owned()),
+            position: self.position.into_owned(),
+            time: self.time,
+            level: self.level,
+            temperature: self.temperature,
+            wind: self.wind,
+            turbulence: self.turbulence,
+            icing: self.icing,
+            remark: self.remark.map(|remark| Cow::Owned(remark.into_owned())),
+        }
+    }
+}
+
+enum_with_str_repr! {
+    AdvisoryType {
+        /// ICAO SIGMET, or a US non-convective SIGMET
+        Sigmet => "SIGMET",
+        /// ICAO AIRMET
+        Airmet => "AIRMET",
+        ConvectiveSigmet => "CONVECTIVE SIGMET",
+        /// IFR and mountain obscuration
+        AirmetSierra => "AIRMET SIERRA",
+        /// Moderate turbulence, strong surface winds and low level wind shear
+        AirmetTango => "AIRMET TANGO",
+        /// Moderate icing and freezing levels
+        AirmetZulu => "AIRMET ZULU",
+    }
+}
+
+enum_with_str_repr! {
+    ThunderstormDistribution {
+        Obscured => "OBSC",
+        Embedded => "EMBD",
+        Frequent => "FRQ",
+        SquallLine => "SQL",
+        Isolated => "ISOL",
+        Occasional => "OCNL",
+        Area => "AREA",
+        Line => "LINE",
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SigmetPhenomenon<'input> {
+    Thunderstorms {
+        distribution: Option<ThunderstormDistribution>,
+        severe: bool,
+        hail: bool,
+    },
+    Turbulence(TurbulenceIntensity),
+    Icing {
+        intensity: IcingIntensity,
+        freezing_rain: bool,
+    },
+    MountainWave,
+    Duststorm,
+    Sandstorm,
+    VolcanicAsh,
+    RadioactiveCloud,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    TropicalCyclone(Cow<'input, str>),
+    /// Ceiling below 1,000 feet and/or visibility below 3 statute miles
+    InstrumentConditions,
+    MountainObscuration,
+    StrongSurfaceWinds,
+    LowLevelWindShear,
+    FreezingLevel,
+}
+
+impl<'input> SigmetPhenomenon<'input> {
+    pub fn into_owned(self) -> SigmetPhenomenon<'static> {
+        use SigmetPhenomenon::*;
+        match self {
+            Thunderstorms {
+                distribution,
+                severe,
+                hail,
+            } => Thunderstorms {
+                distribution,
+                severe,
+                hail,
+            },
+            Turbulence(intensity) => Turbulence(intensity),
+            Icing {
+                intensity,
+                freezing_rain,
+            } => Icing {
+                intensity,
+                freezing_rain,
+            },
+            MountainWave => MountainWave,
+            Duststorm => Duststorm,
+            Sandstorm => Sandstorm,
+            VolcanicAsh => VolcanicAsh,
+            RadioactiveCloud => RadioactiveCloud,
+            TropicalCyclone(name) => TropicalCyclone(Cow::Owned(name.into_owned())),
+            InstrumentConditions => InstrumentConditions,
+            MountainObscuration => MountainObscuration,
+            StrongSurfaceWinds => StrongSurfaceWinds,
+            LowLevelWindShear => LowLevelWindShear,
+            FreezingLevel => FreezingLevel,
+        }
+    }
+}
+
+enum_with_str_repr! {
+    ObservationStatus {
+        Observed => "OBS",
+        Forecast => "FCST",
+    }
+}
+
+/// A navaid, optionally with the distance and compass direction from it, i.e. `30NW YQB`
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SigmetPoint<'input> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub fix: Cow<'input, str>,
+    /// Direction of the point as seen from the navaid
+    pub direction: Option<Angle>,
+    pub distance: Option<Length>,
+}
+
+impl<'input> SigmetPoint<'input> {
+    pub fn into_owned(self) -> SigmetPoint<'static> {
+        SigmetPoint {
+            fix: Cow::Owned(self.fix.into_owned()),
+            direction: self.direction,
+            distance: self.distance,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SigmetArea<'input> {
+    /// `WI N4500 W07000 - N4530 W06900 - ...`
+    Polygon(Vec<Coordinates>),
+    /// US advisories give points relative to navaids, i.e. `FROM 30NW YQB TO 40SE YSC`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    Points(Vec<SigmetPoint<'input>>),
+    /// `ENTIRE FIR`
+    EntireRegion,
+}
+
+impl<'input> SigmetArea<'input> {
+    pub fn into_owned(self) -> SigmetArea<'static> {
+        match self {
+            Self::Polygon(coordinates) => SigmetArea::Polygon(coordinates),
+            Self::Points(points) => {
+                SigmetArea::Points(points.into_iter().map(SigmetPoint::into_owned).collect())
+            }
+            Self::EntireRegion => SigmetArea::EntireRegion,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Movement {
+    /// Direction the phenomenon is moving towards
+    pub direction: Option<Angle>,
+    /// Zero if stationary
+    pub speed: Option<Velocity>,
+}
+
+enum_with_str_repr! {
+    IntensityChange {
+        Intensifying => "INTSF",
+        Weakening => "WKN",
+        NoChange => "NC",
+    }
+}
+
+/// A SIGMET or AIRMET hazard advisory
+///
+/// Bulletins describing several areas are merged: every area is kept, but only the first levels and movement are.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SigmetReport<'input> {
+    pub advisory_type: AdvisoryType,
+    /// ATS unit serving the FIR in ICAO advisories
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub location_indicator: Option<Cow<'input, str>>,
+    /// i.e. `2`, `NOVEMBER 3`, or `25C`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub sequence: Option<Cow<'input, str>>,
+    /// From the `BOSN WS 251455` line preceding US advisories
+    pub issue_time: Option<ZuluDateTime>,
+    /// Beginning and end of validity
+    ///
+    /// US advisories are valid from the time they are issued, so the beginning is the [issue time](Self::issue_time) if known.
+    pub validity: Option<ZuluTimeRange>,
+    pub valid_until: ZuluTime,
+    pub valid_until_day_of_month: Option<u8>,
+    /// Meteorological watch office that issued an ICAO advisory
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub originator: Option<Cow<'input, str>>,
+    /// FIR name, or the list of states for US advisories
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub region: Option<Cow<'input, str>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub phenomena: Vec<SigmetPhenomenon<'input>>,
+    pub observation: Option<ObservationStatus>,
+    pub observation_time: Option<ZuluTime>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub areas: Vec<SigmetArea<'input>>,
+    /// Surface is reported as zero
+    pub base: Option<Length>,
+    pub top: Option<Length>,
+    pub movement: Option<Movement>,
+    pub intensity_change: Option<IntensityChange>,
+    /// Sequence of the advisory cancelled by this one
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub cancelled: Option<Cow<'input, str>>,
+    /// Free text that was not decoded
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub unparsed: Vec<Cow<'input, str>>,
+}
+
+impl<'input> SigmetReport<'input> {
+    pub fn into_owned(self) -> SigmetReport<'static> {
+        let owned = |val: Cow<'input, str>| -> Cow<'static, str> { Cow::Owned(val.into_owned()) };
+        SigmetReport {
+            advisory_type: self.advisory_type,
+            location_indicator: self.location_indicator.map(owned),
+            sequence: self.sequence.map(owned),
+            issue_time: self.issue_time,
+            validity: self.validity,
+            valid_until: self.valid_until,
+            valid_until_day_of_month: self.valid_until_day_of_month,
+            originator: self.originator.map(owned),
+            region: self.region.map(owned),
+            phenomena: self
+                .phenomena
+                .into_iter()
+                .map(SigmetPhenomenon::into_owned)
+                .collect(),
+            observation: self.observation,
+            observation_time: self.observation_time,
+            areas: self.areas.into_iter().map(SigmetArea::into_owned).collect(),
+            base: self.base,
+            top: self.top,
+            movement: self.movement,
+            intensity_change: self.intensity_change,
+            cancelled: self.cancelled.map(owned),
+            unparsed: self.unparsed.into_iter().map(owned).collect(),
+        }
+    }
+}
@@ -1,3 +1,11 @@
+use uom::si::{angle::degree, f64::Angle};
+
+#[cfg(feature = "chrono_helpers")]
 pub mod times;
-pub mod visibility;
-pub mod wind;
+
+/// Combines a whole number of degrees and optional minutes of arc, i.e. `4530` in `N4530`
+pub(crate) fn degrees_and_minutes(degrees: &str, minutes: Option<&str>, negative: bool) -> Angle {
+    let angle = degrees.parse::<f64>().unwrap()
+        + minutes.map_or(0., |minutes| minutes.parse::<f64>().unwrap() / 60.);
+    Angle::new::<degree>(if negative { -angle } else { angle })
+}
@@ -1,43 +1,164 @@
-use chrono::{DateTime, Utc, Duration, Datelike, TimeZone};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc};
 use regex::Regex;
 
-pub fn ddhhmm_to_datetime(ddhhmm: &str) -> DateTime<Utc> {
+/// Resolves a `ddhhmm` timestamp (i.e. `251453Z`) relative to `reference`.
+///
+/// See [resolve_day_hour_minute] for how the month and year are picked.
+pub fn ddhhmm_to_datetime(ddhhmm: &str, reference: DateTime<Utc>) -> Option<DateTime<Utc>> {
     let rg = Regex::new(r"^(?P<dd>[0-9]{2})(?P<hh>[0-9]{2})(?P<mm>[0-9]{2})?Z?$").unwrap();
 
-    match rg.captures(ddhhmm) {
-        Some(timestamp) => {
-            let dd = timestamp.name("dd").unwrap().as_str().parse::<u32>().unwrap();
-            let hh = timestamp.name("hh").unwrap().as_str().parse::<u32>().unwrap();
-            let mm = match timestamp.name("mm") {
-                Some(mm) => mm.as_str().parse::<u32>().unwrap(),
-                None => 0
-            };
-        
-            let now = Utc::now();
-
-            let mut m = now.month();
-            let mut y = now.year();
-
-            if dd != now.day() {
-                let tomorrow = now + Duration::days(1);
-                let yesterday = now + Duration::days(-1);
-        
-                if dd == tomorrow.day() {
-                    m = tomorrow.month();
-                    y = tomorrow.year();
-                } else if dd == yesterday.day() {
-                    m = yesterday.month();
-                    y = yesterday.year();
-                }
-            }
-        
-            if hh >= 24 {
-                Utc.ymd(y, m, dd).and_hms(hh-24, mm, 0) + Duration::days(1)
-            } else {
-                Utc.ymd(y, m, dd).and_hms(hh, mm, 0)
-            }        
-        },
-        None => {Utc::now()}
+    let timestamp = rg.captures(ddhhmm)?;
+    let dd = timestamp.name("dd").unwrap().as_str().parse().unwrap();
+    let hh = timestamp.name("hh").unwrap().as_str().parse().unwrap();
+    let mm = timestamp
+        .name("mm")
+        .map(|mm| mm.as_str().parse().unwrap())
+        .unwrap_or(0);
+    resolve_day_hour_minute(dd, hh, mm, reference)
+}
+
+/// Resolves a day of month and time to the instant closest to `reference`.
+///
+/// Reports only carry the day of month, so the month and year are taken from whichever of
+/// the previous, current, or next month relative to `reference` gives the nearest instant.
+/// This handles month and year rollover, i.e. day `31` resolved against `2021-01-01T00:00Z` is `2020-12-31`.
+///
+/// An hour of `24` is accepted and refers to midnight at the end of the day.
+///
+/// Returns [None] if the values are out of range.
+pub fn resolve_day_hour_minute(
+    day_of_month: u8,
+    hour: u8,
+    minute: u8,
+    reference: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    let (year, month) = (reference.year(), reference.month());
+    let (previous_year, previous_month) = if month == 1 {
+        (year - 1, 12)
+    } else {
+        (year, month - 1)
+    };
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+
+    [
+        (previous_year, previous_month),
+        (year, month),
+        (next_year, next_month),
+    ]
+    .iter()
+    .filter_map(|(year, month)| NaiveDate::from_ymd_opt(*year, *month, day_of_month as u32))
+    .map(|date| at_hour_minute(date, hour, minute))
+    .collect::<Option<Vec<_>>>()?
+    .into_iter()
+    .min_by_key(|candidate| (*candidate - reference).num_seconds().abs())
+}
+
+/// Resolves a time of day to the instant closest to `reference`, on the previous, same, or next day.
+///
+/// Returns [None] if the values are out of range.
+pub fn resolve_hour_minute(
+    hour: u8,
+    minute: u8,
+    reference: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    let date = reference.date_naive();
+    [date.pred_opt(), Some(date), date.succ_opt()]
+        .iter()
+        .flatten()
+        .map(|date| at_hour_minute(*date, hour, minute))
+        .collect::<Option<Vec<_>>>()?
+        .into_iter()
+        .min_by_key(|candidate| (*candidate - reference).num_seconds().abs())
+}
+
+/// Resolves a time of day to the first matching instant at or after `after`.
+///
+/// Returns [None] if the values are out of range.
+pub fn next_hour_minute(hour: u8, minute: u8, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let candidate = at_hour_minute(after.date_naive(), hour, minute)?;
+    if candidate < after {
+        Some(candidate + Duration::days(1))
+    } else {
+        Some(candidate)
+    }
+}
+
+fn at_hour_minute(date: NaiveDate, hour: u8, minute: u8) -> Option<DateTime<Utc>> {
+    if hour > 24 || minute > 59 || (hour == 24 && minute != 0) {
+        return None;
     }
+    Some(
+        Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0)?)
+            + Duration::hours(hour as i64)
+            + Duration::minutes(minute as i64),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
+    fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn resolves_within_month() {
+        assert_eq!(
+            ddhhmm_to_datetime("251453Z", utc(2021, 8, 25, 15, 0)),
+            Some(utc(2021, 8, 25, 14, 53))
+        );
+    }
+
+    #[test]
+    fn resolves_across_month_and_year_rollover() {
+        assert_eq!(
+            resolve_day_hour_minute(31, 23, 50, utc(2021, 1, 1, 0, 5)),
+            Some(utc(2020, 12, 31, 23, 50))
+        );
+        assert_eq!(
+            resolve_day_hour_minute(1, 0, 10, utc(2020, 12, 31, 23, 55)),
+            Some(utc(2021, 1, 1, 0, 10))
+        );
+        // February has no 30th, so this must be January
+        assert_eq!(
+            resolve_day_hour_minute(30, 12, 0, utc(2021, 2, 2, 0, 0)),
+            Some(utc(2021, 1, 30, 12, 0))
+        );
+    }
+
+    #[test]
+    fn resolves_hour_24_as_end_of_day() {
+        assert_eq!(
+            resolve_day_hour_minute(28, 24, 0, utc(2021, 2, 28, 12, 0)),
+            Some(utc(2021, 3, 1, 0, 0))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_values() {
+        let reference = utc(2021, 8, 25, 15, 0);
+        assert_eq!(ddhhmm_to_datetime("garbage", reference), None);
+        assert_eq!(resolve_day_hour_minute(0, 12, 0, reference), None);
+        assert_eq!(resolve_day_hour_minute(12, 25, 0, reference), None);
+        assert_eq!(resolve_day_hour_minute(12, 24, 30, reference), None);
+        assert_eq!(resolve_hour_minute(12, 60, reference), None);
+    }
+
+    #[test]
+    fn resolves_times_of_day() {
+        assert_eq!(
+            resolve_hour_minute(23, 0, utc(2021, 8, 25, 1, 0)),
+            Some(utc(2021, 8, 24, 23, 0))
+        );
+        assert_eq!(
+            next_hour_minute(6, 0, utc(2021, 8, 25, 18, 0)),
+            Some(utc(2021, 8, 26, 6, 0))
+        );
+    }
 }